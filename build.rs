@@ -0,0 +1,29 @@
+use std::process::Command;
+use std::{env, fs};
+
+// 编译时记录rustc和rocket的版本,服务监控接口返回
+fn main() {
+    let rustc = env::var("RUSTC").unwrap_or("rustc".to_string());
+    let rustc_version = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .and_then(|x| String::from_utf8(x.stdout).ok())
+        .map(|x| x.trim().to_string())
+        .unwrap_or_default();
+    println!("cargo:rustc-env=RUSTC_VERSION={}", rustc_version);
+
+    let rocket_version = fs::read_to_string("Cargo.lock")
+        .ok()
+        .and_then(|lock| {
+            lock.split("[[package]]")
+                .find(|x| x.contains("\nname = \"rocket\"\n"))
+                .and_then(|x| x.lines().find_map(|l| l.strip_prefix("version = ")))
+                .map(|x| x.trim_matches('"').to_string())
+        })
+        .unwrap_or_default();
+    println!("cargo:rustc-env=ROCKET_VERSION={}", rocket_version);
+
+    println!("cargo:rerun-if-changed=Cargo.lock");
+    println!("cargo:rerun-if-changed=build.rs");
+}
@@ -0,0 +1,49 @@
+-- 已有数据库升级: 通知公告支持富文本内容、指定通知对象、定时发布/过期和附件(新建数据库直接使用 system/sys_notice.sql)
+
+-- 1.修改公告内容为富文本,增加通知对象类型、发布时间和过期时间
+alter table sys_notice
+    modify notice_content text not null comment '公告内容(富文本,保存前按白名单清洗)',
+    add target_type tinyint default 0 not null comment '通知对象类型（0:全部用户,1:部门,2:角色,3:用户）' after status,
+    add publish_at  datetime null comment '发布时间' after target_type,
+    add expire_at   datetime null comment '过期时间' after publish_at;
+
+create index idx_status_publish_at on sys_notice (status, publish_at);
+
+-- 2.通知公告对象关联表
+create table sys_notice_target
+(
+    notice_id bigint not null comment '公告ID',
+    target_id bigint not null comment '通知对象ID(部门ID、角色ID或用户ID)',
+    primary key (notice_id, target_id)
+) comment = '通知公告对象关联表';
+
+-- 3.通知公告用户关联表
+create table sys_notice_user
+(
+    id            bigint auto_increment comment '主键'
+        primary key,
+    notice_id     bigint                             not null comment '公告ID',
+    user_id       bigint                             not null comment '用户ID',
+    read_flag     tinyint  default 0                 not null comment '阅读状态（0:未读,1:已读）',
+    delivery_time datetime default CURRENT_TIMESTAMP not null comment '送达时间',
+    read_time     datetime                           null comment '阅读时间',
+    constraint uk_notice_user
+        unique (notice_id, user_id)
+) comment = '通知公告用户关联表';
+
+create index idx_user_read on sys_notice_user (user_id, read_flag);
+
+-- 4.通知公告附件表
+create table sys_notice_attachment
+(
+    id          bigint auto_increment comment '主键'
+        primary key,
+    notice_id   bigint                             not null comment '公告ID',
+    file_id     bigint                             null comment '文件ID(上传的文件)',
+    file_name   varchar(255)                       not null comment '文件名称',
+    file_url    varchar(500)                       not null comment '文件地址',
+    sort        int      default 0                 not null comment '排序',
+    create_time datetime default CURRENT_TIMESTAMP not null comment '创建时间'
+) comment = '通知公告附件表';
+
+create index idx_notice_id on sys_notice_attachment (notice_id);
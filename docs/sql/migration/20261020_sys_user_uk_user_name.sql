@@ -0,0 +1,11 @@
+-- 已有数据库升级: sys_user.user_name 增加唯一索引(新建数据库直接使用 system/sys_user.sql)
+
+-- 1.检查重复的用户账号(包括已删除的用户),有结果时先修改或删除重复的数据
+select user_name, count(*) as total
+from sys_user
+group by user_name
+having count(*) > 1;
+
+-- 2.增加唯一索引
+alter table sys_user
+    add unique key uk_user_name (user_name);
@@ -0,0 +1,21 @@
+create table sys_config
+(
+    id           bigint auto_increment comment '参数主键'
+        primary key,
+    config_name  varchar(100)  default ''                not null comment '参数名称',
+    config_key   varchar(100)  default ''                not null comment '参数键名',
+    config_value varchar(2000) default ''                not null comment '参数键值',
+    value_type   varchar(10)   default 'string'          not null comment '值类型(string:字符串,int:整数,bool:布尔,json:JSON)',
+    is_builtin   char(1)       default 'N'               not null comment '是否内置（Y是 N否,内置参数不能删除和修改键名）',
+    remark       varchar(500)  default ''                not null comment '备注',
+    create_time  datetime      default CURRENT_TIMESTAMP not null comment '创建时间',
+    update_time  datetime      default CURRENT_TIMESTAMP not null on update CURRENT_TIMESTAMP comment '修改时间',
+    unique (config_key)
+) comment = '参数配置表';
+
+INSERT INTO sys_config (config_name, config_key, config_value, value_type, is_builtin, remark)
+VALUES ('登录令牌有效期(秒)', 'sys.token.expireSeconds', '1800000', 'int', 'Y', '新签发的token的有效期');
+INSERT INTO sys_config (config_name, config_key, config_value, value_type, is_builtin, remark)
+VALUES ('默认头像样式', 'sys.user.defaultAvatarStyle', 'initials', 'string', 'Y', '用户没有上传头像时生成的默认头像样式(initials:首字母,identicon:对称色块)');
+INSERT INTO sys_config (config_name, config_key, config_value, value_type, is_builtin, remark)
+VALUES ('用户初始密码', 'sys.user.initPassword', '123456', 'string', 'Y', '导入用户时没有填写密码的用户使用该密码');
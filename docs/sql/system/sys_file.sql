@@ -0,0 +1,18 @@
+create table sys_file
+(
+    id           bigint auto_increment comment '文件ID'
+        primary key,
+    file_name    varchar(255)                       not null comment '原始文件名',
+    file_key     varchar(255)                       not null comment '存储路径',
+    file_ext     varchar(20)                        not null comment '文件扩展名',
+    file_size    bigint                             not null comment '文件大小(字节)',
+    content_type varchar(100)                       not null comment '文件类型',
+    file_hash    char(64)                           not null comment '文件内容sha256',
+    storage_type varchar(20)                        not null comment '存储类型(local:本地磁盘,s3:对象存储)',
+    biz_type     varchar(20) default ''             not null comment '业务类型(avatar:头像)',
+    create_by    varchar(50)                        not null comment '上传者',
+    create_time  datetime default CURRENT_TIMESTAMP not null comment '上传时间'
+) comment = '文件信息表';
+
+create index idx_file_hash on sys_file (file_hash, storage_type);
+create index idx_file_key on sys_file (file_key, storage_type);
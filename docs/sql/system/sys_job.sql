@@ -0,0 +1,45 @@
+create table sys_job
+(
+    id              bigint auto_increment comment '任务ID'
+        primary key,
+    job_name        varchar(64)  default ''                not null comment '任务名称',
+    job_group       varchar(64)  default 'DEFAULT'         not null comment '任务组名',
+    invoke_target   varchar(100) default ''                not null comment '任务处理器名称',
+    job_params      varchar(500) default ''                not null comment '任务参数',
+    cron_expression varchar(100) default ''                not null comment 'cron表达式(秒 分 时 日 月 周 [年])',
+    concurrent      tinyint      default 0                 not null comment '是否允许并发执行（0：禁止,1:允许）',
+    status          tinyint      default 0                 not null comment '任务状态（0：暂停，1:正常）',
+    remark          varchar(500) default ''                not null comment '备注',
+    create_time     datetime     default CURRENT_TIMESTAMP not null comment '创建时间',
+    update_time     datetime     default CURRENT_TIMESTAMP not null on update CURRENT_TIMESTAMP comment '修改时间'
+) comment = '定时任务表';
+
+INSERT INTO sys_job (job_name, job_group, invoke_target, job_params, cron_expression, concurrent, status, remark)
+VALUES ('清理登录日志', 'SYSTEM', 'cleanLoginLog', '90', '0 0 2 * * ?', 0, 0, '删除90天前的登录日志');
+INSERT INTO sys_job (job_name, job_group, invoke_target, job_params, cron_expression, concurrent, status, remark)
+VALUES ('清理操作日志', 'SYSTEM', 'cleanOperateLog', '90', '0 10 2 * * ?', 0, 0, '删除90天前的操作日志');
+INSERT INTO sys_job (job_name, job_group, invoke_target, job_params, cron_expression, concurrent, status, remark)
+VALUES ('清理任务日志', 'SYSTEM', 'cleanJobLog', '30', '0 20 2 * * ?', 0, 0, '删除30天前的任务执行日志');
+INSERT INTO sys_job (job_name, job_group, invoke_target, job_params, cron_expression, concurrent, status, remark)
+VALUES ('同步参数配置', 'SYSTEM', 'refreshConfigCache', '', '0 */5 * * * ?', 0, 0, '多实例部署时定时从数据库同步参数配置');
+
+create table sys_job_log
+(
+    id             bigint auto_increment comment '任务日志ID'
+        primary key,
+    job_id         bigint                                 not null comment '任务ID',
+    job_name       varchar(64)  default ''                not null comment '任务名称',
+    job_group      varchar(64)  default ''                not null comment '任务组名',
+    invoke_target  varchar(100) default ''                not null comment '任务处理器名称',
+    job_params     varchar(500) default ''                not null comment '任务参数',
+    trigger_type   tinyint      default 0                 not null comment '触发方式（0：定时,1:手动）',
+    status         tinyint      default 0                 not null comment '执行状态（0：失败,1:成功,2:跳过）',
+    job_message    varchar(500) default ''                not null comment '执行结果',
+    exception_info text                                   null comment '错误信息',
+    start_time     datetime                               not null comment '开始时间',
+    end_time       datetime                               not null comment '结束时间',
+    duration       bigint       default 0                 not null comment '耗时(毫秒)'
+) comment = '定时任务执行日志表';
+
+create index idx_job_id on sys_job_log (job_id, start_time);
+create index idx_start_time on sys_job_log (start_time);
@@ -18,3 +18,12 @@ create table sys_login_log
     msg            varchar(255) default ''                not null comment '提示消息',
     login_time     datetime     default CURRENT_TIMESTAMP not null comment '访问时间'
 ) comment = '系统访问记录';
+
+
+-- 查询索引(时间范围查询、前缀匹配及排序)
+create index idx_login_time on sys_login_log (login_time);
+create index idx_login_name on sys_login_log (login_name, login_time);
+create index idx_ipaddr on sys_login_log (ipaddr, login_time);
+create index idx_status on sys_login_log (status, login_time);
+-- 异地登录统计(按账号和地点查询历史登录)
+create index idx_login_name_location on sys_login_log (login_name, login_location, status, login_time);
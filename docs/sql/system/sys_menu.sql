@@ -168,3 +168,48 @@ INSERT INTO sys_menu (menu_name, menu_type, `status`, sort, parent_id, menu_url,
 INSERT INTO sys_menu (menu_name, menu_type, `status`, sort, parent_id, menu_url, api_url, menu_icon, remark)
 VALUES ('查询菜单资源', 3, 1, 7, 29, '', '/api/system/menu/queryMenuResourceList', '', '查询菜单资源');
 
+
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询登录统计', 3, 1, 7, 73, '', '/api/system/loginLog/queryLoginLogStatistics', '', '查询登录统计');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询我的通知公告', 3, 1, 7, 65, '', '/api/system/notice/queryMyNoticeList', '', '查询我的通知公告');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询我的未读通知数量', 3, 1, 8, 65, '', '/api/system/notice/queryMyUnreadCount', '', '查询我的未读通知数量');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('标记通知公告已读', 3, 1, 9, 65, '', '/api/system/notice/readNotice', '', '标记通知公告已读');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('标记全部通知公告已读', 3, 1, 10, 65, '', '/api/system/notice/readAllNotice', '', '标记全部通知公告已读');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询通知公告阅读统计', 3, 1, 11, 65, '', '/api/system/notice/queryNoticeReadStatistics', '', '查询通知公告阅读统计');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('订阅用户实时事件', 3, 1, 11, 3, '', '/api/system/user/subscribeUserEvent', '', '订阅用户实时事件(sse)');
+
+-- 配置文件管理权限
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('文件管理', 2, 1, 9, 2, '/system/file', '', 'FileOutlined', '文件管理');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('上传文件', 3, 1, 1, 96, '', '/api/system/file/uploadFile', '', '上传文件');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('下载文件', 3, 1, 2, 96, '', '/api/system/file/downloadFile', '', '下载文件');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('删除文件', 3, 1, 3, 96, '', '/api/system/file/deleteFile', '', '删除文件');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询文件列表', 3, 1, 4, 96, '', '/api/system/file/queryFileList', '', '查询文件列表');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('上传我的头像', 3, 1, 12, 3, '', '/api/system/user/updateMyAvatar', '', '上传我的头像');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('根据类型查询字典数据', 3, 1, 7, 58, '', '/api/system/dictData/queryDictDataByType', '', '根据类型查询字典数据');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('批量根据类型查询字典数据', 3, 1, 8, 58, '', '/api/system/dictData/queryDictDataByTypes', '', '批量根据类型查询字典数据');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('导出字典', 3, 1, 7, 51, '', '/api/system/dictType/exportDictType', '', '导出字典');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('导入字典', 3, 1, 8, 51, '', '/api/system/dictType/importDictType', '', '导入字典');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('参数设置', 2, 1, 10, 2, '/system/config', '', 'SettingOutlined', '参数配置管理');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('添加参数', 3, 1, 1, 106, '', '/api/system/config/addConfig', '', '添加参数');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('删除参数', 3, 1, 2, 106, '', '/api/system/config/deleteConfig', '', '删除参数');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('更新参数', 3, 1, 3, 106, '', '/api/system/config/updateConfig', '', '更新参数');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询参数详情', 3, 1, 4, 106, '', '/api/system/config/queryConfigDetail', '', '查询参数详情');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询参数列表', 3, 1, 5, 106, '', '/api/system/config/queryConfigList', '', '查询参数列表');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('刷新参数缓存', 3, 1, 6, 106, '', '/api/system/config/refreshConfigCache', '', '刷新参数缓存');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('定时任务', 2, 1, 11, 2, '/system/job', '', 'FieldTimeOutlined', '定时任务管理');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('添加定时任务', 3, 1, 1, 113, '', '/api/system/job/addJob', '', '添加定时任务');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('删除定时任务', 3, 1, 2, 113, '', '/api/system/job/deleteJob', '', '删除定时任务');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('更新定时任务', 3, 1, 3, 113, '', '/api/system/job/updateJob', '', '更新定时任务');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('暂停恢复定时任务', 3, 1, 4, 113, '', '/api/system/job/updateJobStatus', '', '暂停恢复定时任务');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('立即执行定时任务', 3, 1, 5, 113, '', '/api/system/job/runJob', '', '立即执行定时任务');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询定时任务详情', 3, 1, 6, 113, '', '/api/system/job/queryJobDetail', '', '查询定时任务详情');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询定时任务列表', 3, 1, 7, 113, '', '/api/system/job/queryJobList', '', '查询定时任务列表');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询任务处理器', 3, 1, 8, 113, '', '/api/system/job/queryJobHandlerList', '', '查询任务处理器');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('任务日志', 2, 1, 11, 72, '/log/jobLog', '', 'ScheduleOutlined', '定时任务执行日志');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('删除任务日志', 3, 1, 1, 122, '', '/api/system/jobLog/deleteJobLog', '', '删除任务日志');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询任务日志详情', 3, 1, 2, 122, '', '/api/system/jobLog/queryJobLogDetail', '', '查询任务日志详情');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询任务日志列表', 3, 1, 3, 122, '', '/api/system/jobLog/queryJobLogList', '', '查询任务日志列表');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('系统监控', 1, 1, 3, 0, '/monitor', '', 'DashboardOutlined', '系统监控');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('服务监控', 2, 1, 1, 126, '/monitor/server', '', 'CloudServerOutlined', '服务监控');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询服务信息', 3, 1, 1, 127, '', '/api/monitor/server', '', '查询服务信息');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('下载用户导入模板', 3, 1, 13, 3, '', '/api/system/user/downloadImportTemplate', '', '下载用户导入模板');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('导入用户', 3, 1, 14, 3, '', '/api/system/user/importUser', '', '导入用户(xlsx/csv)');
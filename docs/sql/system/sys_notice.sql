@@ -4,13 +4,18 @@ create table sys_notice
         primary key,
     notice_title   varchar(50)                            not null comment '公告标题',
     notice_type    tinyint      default 1                 not null comment '公告类型（1:通知,2:公告）',
-    notice_content varchar(255) default ''                not null comment '公告内容',
+    notice_content text                                   not null comment '公告内容(富文本,保存前按白名单清洗)',
     status         tinyint      default 0                 not null comment '公告状态（0:关闭,1:正常 ）',
+    target_type    tinyint      default 0                 not null comment '通知对象类型（0:全部用户,1:部门,2:角色,3:用户）',
+    publish_at     datetime                               null comment '发布时间',
+    expire_at      datetime                               null comment '过期时间',
     remark         varchar(255) default ''                not null comment '备注',
     create_time    datetime     default CURRENT_TIMESTAMP not null comment '创建时间',
     update_time    datetime     default CURRENT_TIMESTAMP not null on update CURRENT_TIMESTAMP comment '修改时间'
 ) comment '通知公告表';
 
+create index idx_status_publish_at on sys_notice (status, publish_at);
+
 
 INSERT INTO sys_notice (notice_title, notice_type, notice_content, status)
 VALUES ('测试通知1', 1, '这是一条测试通知内容', 1);
@@ -18,3 +23,42 @@ INSERT INTO sys_notice (notice_title, notice_type, notice_content, status)
 VALUES ('测试公告2', 2, '这是一条测试公告内容', 1);
 
 
+
+
+create table sys_notice_target
+(
+    notice_id bigint not null comment '公告ID',
+    target_id bigint not null comment '通知对象ID(部门ID、角色ID或用户ID)',
+    primary key (notice_id, target_id)
+) comment = '通知公告对象关联表';
+
+
+create table sys_notice_user
+(
+    id            bigint auto_increment comment '主键'
+        primary key,
+    notice_id     bigint                             not null comment '公告ID',
+    user_id       bigint                             not null comment '用户ID',
+    read_flag     tinyint  default 0                 not null comment '阅读状态（0:未读,1:已读）',
+    delivery_time datetime default CURRENT_TIMESTAMP not null comment '送达时间',
+    read_time     datetime                           null comment '阅读时间',
+    constraint uk_notice_user
+        unique (notice_id, user_id)
+) comment = '通知公告用户关联表';
+
+create index idx_user_read on sys_notice_user (user_id, read_flag);
+
+
+create table sys_notice_attachment
+(
+    id          bigint auto_increment comment '主键'
+        primary key,
+    notice_id   bigint                             not null comment '公告ID',
+    file_id     bigint                             null comment '文件ID(上传的文件)',
+    file_name   varchar(255)                       not null comment '文件名称',
+    file_url    varchar(500)                       not null comment '文件地址',
+    sort        int      default 0                 not null comment '排序',
+    create_time datetime default CURRENT_TIMESTAMP not null comment '创建时间'
+) comment = '通知公告附件表';
+
+create index idx_notice_id on sys_notice_attachment (notice_id);
@@ -22,3 +22,11 @@ create table sys_operate_log
 
 ) comment = '操作日志记录';
 
+
+
+-- 查询索引(时间范围查询、前缀匹配及排序)
+create index idx_operate_time on sys_operate_log (operate_time);
+create index idx_operate_name on sys_operate_log (operate_name, operate_time);
+create index idx_operate_url on sys_operate_log (operate_url(100), operate_time);
+create index idx_business_type on sys_operate_log (business_type, operate_time);
+create index idx_status on sys_operate_log (status, operate_time);
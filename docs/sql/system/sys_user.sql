@@ -21,7 +21,9 @@ create table sys_user
     create_time     datetime     default CURRENT_TIMESTAMP not null comment '创建时间',
     update_time     datetime     default CURRENT_TIMESTAMP not null on update CURRENT_TIMESTAMP comment '修改时间',
     constraint AK_phone
-        unique (mobile)
+        unique (mobile),
+    constraint uk_user_name
+        unique (user_name)
 ) comment '用户信息';
 
 
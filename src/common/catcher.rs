@@ -0,0 +1,173 @@
+use crate::common::error_code::{BizError, ErrorCode};
+use crate::common::i18n::Lang;
+use crate::middleware::request_id::RequestId;
+use crate::middleware::validate::FieldErrors;
+use rocket::http::Status;
+use rocket::serde::json::serde_json::json;
+use rocket::serde::json::Value;
+use rocket::{Catcher, Request};
+
+/*
+ *错误响应(和BaseResponse的格式保持一致,code使用错误码目录中的错误码)
+ *author：刘飞华
+ *date：2026/10/19 23:48:05
+ */
+fn error_body(req: &Request, status: Status, error: BizError, data: Value) -> (Status, Value) {
+    let body = json!({
+        "code": error.code.code(),
+        "msg": error.message(Lang::of(req)),
+        "data": data,
+        "requestId": RequestId::of(req),
+    });
+    (status, body)
+}
+
+/*
+ *请求参数格式错误或校验失败
+ *author：刘飞华
+ *date：2026/10/19 23:48:05
+ */
+#[catch(400)]
+fn bad_request(req: &Request) -> (Status, Value) {
+    let errors = FieldErrors::of(req);
+    if errors.is_empty() {
+        return error_body(
+            req,
+            Status::BadRequest,
+            ErrorCode::BadRequest.into(),
+            Value::Null,
+        );
+    }
+    error_body(
+        req,
+        Status::BadRequest,
+        ErrorCode::ValidationFailed.into(),
+        json!(errors),
+    )
+}
+
+#[catch(401)]
+fn unauthorized(req: &Request) -> (Status, Value) {
+    error_body(
+        req,
+        Status::Unauthorized,
+        ErrorCode::Unauthorized.into(),
+        Value::Null,
+    )
+}
+
+#[catch(403)]
+fn forbidden(req: &Request) -> (Status, Value) {
+    let error = ErrorCode::Forbidden.with("path", req.uri());
+    error_body(req, Status::Forbidden, error, Value::Null)
+}
+
+#[catch(404)]
+fn not_found(req: &Request) -> (Status, Value) {
+    let error = ErrorCode::NotFound.with("path", req.uri());
+    error_body(req, Status::NotFound, error, Value::Null)
+}
+
+/*
+ *请求体无法解析(json字段缺失或者类型不匹配)
+ *author：刘飞华
+ *date：2026/10/19 23:48:05
+ */
+#[catch(422)]
+fn unprocessable_entity(req: &Request) -> (Status, Value) {
+    let error = ErrorCode::UnprocessableEntity.into();
+    error_body(req, Status::UnprocessableEntity, error, Value::Null)
+}
+
+/*
+ *服务器内部错误(包括handler发生panic)
+ *author：刘飞华
+ *date：2026/10/19 23:48:05
+ */
+#[catch(500)]
+fn internal_error(req: &Request) -> (Status, Value) {
+    log::error!("internal server error: {} {}", req.method(), req.uri());
+    let error = ErrorCode::InternalError.into();
+    error_body(req, Status::InternalServerError, error, Value::Null)
+}
+
+#[catch(default)]
+fn default_catcher(status: Status, req: &Request) -> (Status, Value) {
+    let body = json!({
+        "code": status.code,
+        "msg": status.reason_lossy(),
+        "data": Value::Null,
+        "requestId": RequestId::of(req),
+    });
+    (status, body)
+}
+
+/*
+ *统一的错误处理(所有错误都返回json)
+ *author：刘飞华
+ *date：2026/10/19 23:48:05
+ */
+pub fn catchers() -> Vec<Catcher> {
+    catchers![
+        bad_request,
+        unauthorized,
+        forbidden,
+        not_found,
+        unprocessable_entity,
+        internal_error,
+        default_catcher
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rocket::http::{ContentType, Header};
+    use rocket::local::blocking::Client;
+    use rocket::serde::json::Json;
+
+    #[post("/echo", data = "<item>")]
+    fn echo(item: Json<Vec<i64>>) -> Value {
+        json!(item.0)
+    }
+
+    #[get("/panic")]
+    fn panic() -> &'static str {
+        panic!("boom")
+    }
+
+    #[test]
+    fn test_catchers_return_json() {
+        let rocket = rocket::build()
+            .mount("/", routes![echo, panic])
+            .register("/", catchers());
+        let client = Client::untracked(rocket).unwrap();
+
+        let cases = [
+            (
+                client.post("/echo").header(ContentType::JSON).body("[bad"),
+                400,
+            ),
+            (
+                client.post("/echo").header(ContentType::JSON).body("{}"),
+                422,
+            ),
+            (client.get("/panic"), 500),
+            (client.get("/none"), 404),
+        ];
+        for (request, code) in cases {
+            let response = request.dispatch();
+            assert_eq!(response.status().code, code);
+            assert_eq!(response.content_type(), Some(ContentType::JSON));
+            let body: Value = response.into_json().unwrap();
+            assert_eq!(body["code"], code);
+        }
+
+        let response = client
+            .get("/none")
+            .header(Header::new("Accept-Language", "en-US,en;q=0.9"))
+            .dispatch();
+        let body: Value = response.into_json().unwrap();
+        assert_eq!(body["msg"], "Path not found: /none");
+    }
+}
@@ -0,0 +1,174 @@
+use crate::common::error::AppResult;
+use crate::common::error_code::ErrorCode;
+use crate::model::system::sys_config_model::SysConfig;
+use crate::RB;
+use rocket::serde::json::{serde_json, Value};
+use rocket::tokio::sync::Mutex;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+//参数值类型：字符串
+pub const VALUE_TYPE_STRING: &str = "string";
+//参数值类型：整数
+pub const VALUE_TYPE_INT: &str = "int";
+//参数值类型：布尔(true/false)
+pub const VALUE_TYPE_BOOL: &str = "bool";
+//参数值类型：JSON
+pub const VALUE_TYPE_JSON: &str = "json";
+
+//登录令牌有效期(秒)
+pub const CONFIG_TOKEN_EXPIRE_SECONDS: &str = "sys.token.expireSeconds";
+//默认头像样式(initials/identicon)
+pub const CONFIG_DEFAULT_AVATAR_STYLE: &str = "sys.user.defaultAvatarStyle";
+//用户初始密码(导入用户时没有填写密码的用户使用)
+pub const CONFIG_USER_INIT_PASSWORD: &str = "sys.user.initPassword";
+
+lazy_static! {
+    pub static ref SYS_CONFIG: ConfigCache = ConfigCache::default();
+}
+
+/*
+ *参数配置缓存(启动时加载,参数有写操作时重新加载,其他模块通过get_*读取参数)
+ *author：刘飞华
+ *date：2026/10/19 19:20:14
+ */
+#[derive(Default)]
+pub struct ConfigCache {
+    cache: RwLock<HashMap<String, String>>,
+    reload_lock: Mutex<()>, //串行加载,避免旧的查询结果覆盖新结果
+}
+
+impl ConfigCache {
+    /*
+     *从数据库重新加载全部参数
+     *author：刘飞华
+     *date：2026/10/19 19:20:14
+     */
+    pub async fn reload(&self) -> AppResult<()> {
+        let _guard = self.reload_lock.lock().await;
+        let list = SysConfig::select_all(&RB.clone()).await?;
+        let map = list
+            .into_iter()
+            .map(|x| (x.config_key, x.config_value))
+            .collect::<HashMap<String, String>>();
+        log::info!("reload sys_config cache: {} items", map.len());
+        if let Ok(mut cache) = self.cache.write() {
+            *cache = map;
+        }
+        Ok(())
+    }
+
+    /*
+     *重新加载参数(失败时只记录日志,保留原有缓存)
+     *author：刘飞华
+     *date：2026/10/19 19:20:14
+     */
+    pub async fn refresh(&self) {
+        if let Err(e) = self.reload().await {
+            log::error!("reload sys_config cache error: {}", e);
+        }
+    }
+
+    /*
+     *读取参数原始值
+     *author：刘飞华
+     *date：2026/10/19 19:20:14
+     */
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.cache.read().ok()?.get(key).cloned()
+    }
+
+    /*
+     *读取字符串参数,参数不存在时返回默认值
+     *author：刘飞华
+     *date：2026/10/19 19:20:14
+     */
+    pub fn get_str(&self, key: &str, default: &str) -> String {
+        self.get(key).unwrap_or_else(|| default.to_string())
+    }
+
+    /*
+     *读取整数参数,参数不存在或格式错误时返回默认值
+     *author：刘飞华
+     *date：2026/10/19 19:20:14
+     */
+    pub fn get_i64(&self, key: &str, default: i64) -> i64 {
+        self.get(key)
+            .and_then(|x| x.trim().parse::<i64>().ok())
+            .unwrap_or(default)
+    }
+
+    /*
+     *读取布尔参数,参数不存在或格式错误时返回默认值
+     *author：刘飞华
+     *date：2026/10/19 19:20:14
+     */
+    pub fn get_bool(&self, key: &str, default: bool) -> bool {
+        self.get(key)
+            .and_then(|x| x.trim().parse::<bool>().ok())
+            .unwrap_or(default)
+    }
+
+    /*
+     *读取JSON参数,参数不存在或格式错误时返回None
+     *author：刘飞华
+     *date：2026/10/19 19:20:14
+     */
+    pub fn get_json(&self, key: &str) -> Option<Value> {
+        self.get(key)
+            .and_then(|x| serde_json::from_str::<Value>(&x).ok())
+    }
+}
+
+/*
+ *按值类型校验参数值
+ *author：刘飞华
+ *date：2026/10/19 19:20:14
+ */
+pub fn check_config_value(value_type: &str, value: &str) -> AppResult<()> {
+    let ok = match value_type {
+        VALUE_TYPE_STRING => true,
+        VALUE_TYPE_INT => value.trim().parse::<i64>().is_ok(),
+        VALUE_TYPE_BOOL => value.trim().parse::<bool>().is_ok(),
+        VALUE_TYPE_JSON => serde_json::from_str::<Value>(value).is_ok(),
+        _ => return Err(ErrorCode::ConfigValueTypeInvalid.into()),
+    };
+
+    if !ok {
+        return Err(ErrorCode::ConfigValueMismatch.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::config_cache::{check_config_value, ConfigCache};
+
+    #[test]
+    fn test_check_config_value() {
+        assert!(check_config_value("string", "abc").is_ok());
+        assert!(check_config_value("int", " 10 ").is_ok());
+        assert!(check_config_value("int", "1.5").is_err());
+        assert!(check_config_value("bool", "true").is_ok());
+        assert!(check_config_value("bool", "1").is_err());
+        assert!(check_config_value("json", r#"{"a": [1, 2]}"#).is_ok());
+        assert!(check_config_value("json", "{a}").is_err());
+        assert!(check_config_value("float", "1.5").is_err());
+    }
+
+    #[test]
+    fn test_get_config() {
+        let config = ConfigCache::default();
+        config.cache.write().unwrap().extend([
+            ("a".to_string(), "10".to_string()),
+            ("b".to_string(), "x".to_string()),
+        ]);
+
+        assert_eq!(config.get_i64("a", 1), 10);
+        assert_eq!(config.get_i64("b", 1), 1);
+        assert_eq!(config.get_i64("c", 1), 1);
+        assert_eq!(config.get_str("b", ""), "x");
+        assert!(!config.get_bool("b", false));
+        assert!(config.get_json("a").is_some());
+    }
+}
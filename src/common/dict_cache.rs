@@ -0,0 +1,67 @@
+use crate::model::system::sys_dict_data_model::DictData;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+
+lazy_static! {
+    pub static ref DICT_CACHE: DictCache = DictCache::default();
+}
+
+/*
+ *字典数据缓存(按字典类型缓存已启用的字典数据,字典类型/数据有写操作时整体失效)
+ *author：刘飞华
+ *date：2026/10/19 17:42:15
+ */
+#[derive(Default)]
+pub struct DictCache {
+    cache: RwLock<HashMap<String, Vec<DictData>>>,
+    version: AtomicU64, //缓存版本(每次失效加1)
+}
+
+impl DictCache {
+    /*
+     *当前缓存版本(查询数据库前获取,写入缓存时校验)
+     *author：刘飞华
+     *date：2026/10/19 17:42:15
+     */
+    pub fn version(&self) -> u64 {
+        self.version.load(Ordering::SeqCst)
+    }
+
+    /*
+     *根据字典类型获取缓存
+     *author：刘飞华
+     *date：2026/10/19 17:42:15
+     */
+    pub fn get(&self, dict_type: &str) -> Option<Vec<DictData>> {
+        self.cache.read().ok()?.get(dict_type).cloned()
+    }
+
+    /*
+     *写入缓存(查询期间缓存已失效则丢弃,避免写入旧数据;查询结果为空时不缓存,避免任意字典类型都占用缓存)
+     *author：刘飞华
+     *date：2026/10/19 17:42:15
+     */
+    pub fn put(&self, dict_type: &str, list: Vec<DictData>, version: u64) {
+        if list.is_empty() {
+            return;
+        }
+        if let Ok(mut cache) = self.cache.write() {
+            if self.version() == version {
+                cache.insert(dict_type.to_string(), list);
+            }
+        }
+    }
+
+    /*
+     *清空缓存
+     *author：刘飞华
+     *date：2026/10/19 17:42:15
+     */
+    pub fn invalidate_all(&self) {
+        if let Ok(mut cache) = self.cache.write() {
+            self.version.fetch_add(1, Ordering::SeqCst);
+            cache.clear();
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use rocket::http::ContentType;
+use crate::common::error_code::{BizError, ErrorCode};
+use crate::common::i18n::Lang;
+use crate::middleware::request_id::RequestId;
+use rocket::http::{ContentType, Status};
 use rocket::response::Responder;
 use rocket::serde::json::serde_json;
 use rocket::{response, Request, Response};
@@ -15,33 +18,201 @@ pub enum AppError {
     //
     // #[error("Failed to update the cache file")]
     // DiskCacheWrite { source: std::io::Error },
-    #[error("")]
+    #[error("token错误: {0}")]
     JwtTokenError(String),
 
-    #[error("数据库错误: {0}")]
-    DbError(#[from] rbatis::Error),
+    #[error("数据库错误(错误编号: {error_id}): {source}")]
+    DbError {
+        error_id: String,      //错误编号(返回给客户端,用于在日志中查找完整的错误信息)
+        source: rbatis::Error, //数据库错误
+    },
+
+    #[error("文件存储错误(错误编号: {error_id}): {detail}")]
+    StorageError {
+        error_id: String, //错误编号(返回给客户端,用于在日志中查找完整的错误信息)
+        detail: String,   //存储错误
+    },
 
     #[error("业务异常: {0}")]
-    BusinessError(&'static str),
+    BusinessError(BizError),
 }
 pub type AppResult<T> = Result<T, AppError>;
 
+/*
+ *生成错误编号
+ *author：刘飞华
+ *date：2026/10/20 10:05:31
+ */
+fn new_error_id() -> String {
+    uuid::Uuid::new_v4().simple().to_string()[..16].to_string()
+}
+
+impl AppError {
+    /*
+     *文件存储错误(详细信息只写日志,返回给客户端的是错误编号)
+     *author：刘飞华
+     *date：2026/10/20 10:05:31
+     */
+    pub fn storage(detail: impl std::fmt::Display) -> Self {
+        AppError::StorageError {
+            error_id: new_error_id(),
+            detail: detail.to_string(),
+        }
+    }
+
+    /*
+     *错误对应的http状态码
+     *author：刘飞华
+     *date：2026/10/19 23:48:05
+     */
+    pub fn status(&self) -> Status {
+        match self {
+            AppError::BusinessError(_) => Status::BadRequest,
+            AppError::JwtTokenError(_) => Status::Unauthorized,
+            _ => Status::InternalServerError,
+        }
+    }
+
+    /*
+     *错误码
+     *author：刘飞华
+     *date：2026/10/20 00:21:36
+     */
+    pub fn code(&self) -> i32 {
+        match self {
+            AppError::BusinessError(e) => e.code.code(),
+            AppError::JwtTokenError(_) => ErrorCode::TokenInvalid.code(),
+            AppError::DbError { .. } => ErrorCode::DbError.code(),
+            AppError::StorageError { .. } => ErrorCode::StorageError.code(),
+            AppError::DiskCacheRead { .. } => ErrorCode::DiskCacheRead.code(),
+        }
+    }
+
+    /*
+     *按语言生成错误信息
+     *author：刘飞华
+     *date：2026/10/20 00:21:36
+     */
+    pub fn message(&self, lang: Lang) -> String {
+        match self {
+            AppError::BusinessError(e) => e.message(lang),
+            AppError::JwtTokenError(_) => ErrorCode::TokenInvalid.message(lang),
+            AppError::DbError { error_id, .. } => {
+                ErrorCode::DbError.with("errorId", error_id).message(lang)
+            }
+            AppError::StorageError { error_id, .. } => ErrorCode::StorageError
+                .with("errorId", error_id)
+                .message(lang),
+            AppError::DiskCacheRead { .. } => ErrorCode::DiskCacheRead.message(lang),
+        }
+    }
+}
+
+/*
+ *唯一索引和业务异常的对应关系(并发插入时,由数据库的唯一索引兜底)
+ *author：刘飞华
+ *date：2026/10/20 01:02:17
+ */
+const UNIQUE_KEYS: &[(&str, ErrorCode)] = &[
+    ("AK_phone", ErrorCode::UserMobileExists),
+    ("uk_user_name", ErrorCode::UserNameExists),
+    ("role_name", ErrorCode::RoleNameExists),
+    ("menu_name", ErrorCode::MenuNameExists),
+    ("dict_type", ErrorCode::DictTypeExists),
+    ("config_key", ErrorCode::ConfigKeyExists),
+];
+
+/*
+ *解析违反唯一索引的错误(mysql: 1062 (23000): Duplicate entry 'x' for key 'table.key')
+ *author：刘飞华
+ *date：2026/10/20 01:02:17
+ */
+fn unique_violation(e: &rbatis::Error) -> Option<ErrorCode> {
+    let message = e.to_string();
+    if !message.contains("Duplicate entry") {
+        return None;
+    }
+    let key = message.rsplit_once("for key '")?.1.split('\'').next()?;
+    let key = key.rsplit('.').next()?;
+    UNIQUE_KEYS
+        .iter()
+        .find(|(name, _)| *name == key)
+        .map(|(_, code)| *code)
+}
+
+impl From<rbatis::Error> for AppError {
+    fn from(source: rbatis::Error) -> Self {
+        if let Some(code) = unique_violation(&source) {
+            log::warn!("unique constraint violation: {}", source);
+            return AppError::from(code);
+        }
+        AppError::DbError {
+            error_id: new_error_id(),
+            source,
+        }
+    }
+}
+
+impl From<ErrorCode> for AppError {
+    fn from(code: ErrorCode) -> Self {
+        AppError::BusinessError(BizError::from(code))
+    }
+}
+
+impl From<BizError> for AppError {
+    fn from(e: BizError) -> Self {
+        AppError::BusinessError(e)
+    }
+}
+
 impl<'r> Responder<'r, 'static> for AppError {
-    fn respond_to(self, _: &'r Request<'_>) -> response::Result<'static> {
-        // let error_msg = format!("{}", self);
+    fn respond_to(self, request: &'r Request<'_>) -> response::Result<'static> {
+        match &self {
+            AppError::JwtTokenError(e) => log::warn!("token error: {}", e),
+            AppError::DbError { .. } | AppError::StorageError { .. } => log::error!("{}", self),
+            _ => {}
+        }
+        let status = self.status();
         let error_msg = serde_json::json!({
-            "msg": self.to_string(),
-            "code": 1,
+            "msg": self.message(Lang::of(request)),
+            "code": self.code(),
+            "data": null,
+            "requestId": RequestId::of(request),
         })
         .to_string();
         Response::build()
             .header(ContentType::JSON)
-            // .status(match self {
-            //     AppError::BusinessError(_) => rocket::http::Status::BadRequest,
-            //     AppError::JwtTokenError(_) => rocket::http::Status::Unauthorized,
-            //     _ => rocket::http::Status::InternalServerError,
-            // })
+            .status(status)
             .sized_body(error_msg.len(), Cursor::new(error_msg))
             .ok()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_db_error() {
+        let e = rbatis::Error::from(
+            "1062 (23000): Duplicate entry 'admin' for key 'sys_user.uk_user_name'",
+        );
+        let e = AppError::from(e);
+        assert_eq!(e.code(), ErrorCode::UserNameExists.code());
+
+        let e = AppError::from(rbatis::Error::from(
+            "1146 (42S02): Table 'x.sys_user' doesn't exist",
+        ));
+        let message = e.message(Lang::ZhCn);
+        assert_eq!(e.code(), ErrorCode::DbError.code());
+        assert!(!message.contains("sys_user"));
+        assert!(message.contains(match &e {
+            AppError::DbError { error_id, .. } => error_id.as_str(),
+            _ => unreachable!(),
+        }));
+
+        let e = AppError::storage("/data/upload/a.png: permission denied");
+        assert_eq!(e.code(), ErrorCode::StorageError.code());
+        assert!(!e.message(Lang::EnUs).contains("/data/upload"));
+    }
+}
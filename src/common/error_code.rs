@@ -0,0 +1,279 @@
+use crate::common::i18n::Lang;
+use std::fmt;
+
+/*
+ *错误码目录(错误码, 中文信息, 英文信息),信息中的{name}为参数占位符
+ *错误码一经发布不能修改,新增错误码时在对应模块的号段内递增
+ *  400-500, 4xxxx: 请求级别的错误(400-500和http状态码一致)
+ *  10xxx: 通用  11xxx: 用户  12xxx: 角色  13xxx: 菜单  14xxx: 部门  15xxx: 岗位
+ *  16xxx: 字典  17xxx: 参数  18xxx: 通知公告  19xxx: 文件  20xxx: 定时任务  21xxx: 日志
+ *  50xxx: 系统错误
+ *author：刘飞华
+ *date：2026/10/20 00:21:36
+ */
+macro_rules! error_codes {
+    ($($name:ident = $code:literal, $zh:literal, $en:literal;)*) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum ErrorCode {
+            $($name,)*
+        }
+
+        impl ErrorCode {
+            pub const ALL: &'static [ErrorCode] = &[$(ErrorCode::$name,)*];
+
+            /*
+             *错误码
+             */
+            pub fn code(&self) -> i32 {
+                match self {
+                    $(ErrorCode::$name => $code,)*
+                }
+            }
+
+            /*
+             *错误信息模板
+             */
+            pub fn template(&self, lang: Lang) -> &'static str {
+                match (self, lang) {
+                    $((ErrorCode::$name, Lang::ZhCn) => $zh,
+                    (ErrorCode::$name, Lang::EnUs) => $en,)*
+                }
+            }
+        }
+    };
+}
+
+error_codes! {
+    //请求
+    BadRequest = 400, "请求参数格式错误", "Malformed request";
+    Unauthorized = 401, "未登录或登录已过期", "Not logged in or login expired";
+    Forbidden = 403, "没有访问权限: {path}", "No permission to access: {path}";
+    NotFound = 404, "请求的地址不存在: {path}", "Path not found: {path}";
+    UnprocessableEntity = 422, "请求参数格式错误", "Request body cannot be parsed";
+    InternalError = 500, "服务器内部错误", "Internal server error";
+    ValidationFailed = 40001, "参数校验失败", "Validation failed";
+    ValidateRequired = 40002, "不能为空", "Must not be empty";
+    ValidateLength = 40003, "长度必须在{min}到{max}个字符之间", "Length must be between {min} and {max} characters";
+    ValidateLengthMax = 40004, "不能超过{max}个字符", "Must not exceed {max} characters";
+    ValidateLengthMin = 40005, "不能少于{min}个字符", "Must be at least {min} characters";
+    ValidateRange = 40006, "只能是{min}到{max}之间的值", "Must be between {min} and {max}";
+    ValidateRangeMin = 40007, "不能小于{min}", "Must not be less than {min}";
+    ValidateRangeMax = 40008, "不能大于{max}", "Must not be greater than {max}";
+    ValidateEmail = 40009, "邮箱格式不正确", "Invalid email address";
+    ValidateMobile = 40010, "手机号码格式不正确", "Invalid mobile number";
+    ValidateYesNo = 40011, "只能是Y或N", "Must be Y or N";
+    ValidateInvalid = 40012, "格式不正确", "Invalid value";
+    TokenInvalid = 40101, "登录状态无效或已过期", "Login token is invalid or expired";
+    //通用
+    TimeRangeInvalid = 10001, "开始时间不能大于结束时间", "Start time must not be later than end time";
+    CursorInvalid = 10002, "分页游标不正确", "Invalid pagination cursor";
+    TimeFormatInvalid = 10003, "时间格式不正确(格式: YYYY-MM-DD或YYYY-MM-DD hh:mm:ss): {time}", "Invalid time (expected YYYY-MM-DD or YYYY-MM-DD hh:mm:ss): {time}";
+    //用户
+    UserNotFound = 11001, "用户不存在", "User does not exist";
+    UserNameExists = 11002, "登录账号已存在", "User name already exists";
+    UserMobileExists = 11003, "手机号码已存在", "Mobile number already exists";
+    UserEmailExists = 11004, "邮箱账号已存在", "Email already exists";
+    UserDeleteSelf = 11005, "当前用户不能删除", "The current user cannot be deleted";
+    UserSuperAdmin = 11006, "不允许操作超级管理员用户", "The super administrator cannot be modified";
+    UserSuperAdminRole = 11007, "不能修改超级管理员的角色", "The roles of the super administrator cannot be modified";
+    OldPasswordIncorrect = 11008, "旧密码不正确", "Old password is incorrect";
+    PasswordIncorrect = 11009, "密码不正确", "Password is incorrect";
+    UserNoPermission = 11011, "用户没有分配角色或者菜单,不能登录", "User has no roles or menus assigned and cannot log in";
+    AvatarEmpty = 11012, "头像图片不能为空", "Avatar image must not be empty";
+    AvatarNotFound = 11013, "头像不存在", "Avatar does not exist";
+    AvatarFormatInvalid = 11014, "头像图片格式不正确", "Unsupported avatar image format";
+    AvatarImageInvalid = 11015, "头像图片格式不正确或尺寸过大", "Avatar image is invalid or too large";
+    UserImportFormatInvalid = 11016, "只支持xlsx或csv格式的文件", "Only xlsx or csv files are supported";
+    UserImportParseFailed = 11017, "导入文件解析失败", "Failed to parse the import file";
+    UserImportEmpty = 11018, "导入文件中没有用户数据", "The import file contains no users";
+    UserImportTooManyRows = 11019, "导入文件最多{max}行用户数据", "The import file can contain at most {max} users";
+    UserImportColumnMissing = 11020, "导入文件缺少列: {column}", "Missing column in the import file: {column}";
+    UserImportDuplicated = 11021, "和第{row}行重复", "Duplicates row {row}";
+    UserImportDeptNotFound = 11022, "部门编号不存在: {deptId}", "Department id does not exist: {deptId}";
+    UserImportPostNotFound = 11023, "岗位编码不存在: {postCode}", "Post code does not exist: {postCode}";
+    UserImportFileFailed = 11024, "生成导入模板或结果文件失败", "Failed to generate the import template or result file";
+    //角色
+    RoleNotFound = 12001, "角色不存在", "Role does not exist";
+    RoleNameExists = 12002, "角色名称已存在", "Role name already exists";
+    RoleKeyExists = 12003, "角色权限已存在", "Role key already exists";
+    RoleSuperAdmin = 12004, "不允许操作超级管理员角色", "The super administrator role cannot be modified";
+    RoleInUse = 12005, "角色已分配,不能删除", "Role is assigned to users and cannot be deleted";
+    //菜单
+    MenuNotFound = 13001, "菜单信息不存在", "Menu does not exist";
+    MenuNameExists = 13002, "菜单名称已存在", "Menu name already exists";
+    MenuUrlExists = 13003, "路由路径已存在", "Route path already exists";
+    MenuHasChildren = 13004, "存在子菜单,不允许删除", "Menu has sub menus and cannot be deleted";
+    MenuInUse = 13005, "菜单已分配,不允许删除", "Menu is assigned to roles and cannot be deleted";
+    //部门
+    DeptNotFound = 14001, "部门不存在", "Department does not exist";
+    DeptNameExists = 14002, "部门名称已存在", "Department name already exists";
+    DeptParentNotFound = 14003, "上级部门不存在", "Parent department does not exist";
+    DeptAddParentNotFound = 14004, "添加失败,上级部门不存在", "Add failed, parent department does not exist";
+    DeptParentDisabled = 14005, "部门停用，不允许添加", "Parent department is disabled";
+    DeptParentSelf = 14006, "上级部门不能是自己", "A department cannot be its own parent";
+    DeptHasChildren = 14007, "存在下级部门,不允许删除", "Department has sub departments and cannot be deleted";
+    DeptHasUsers = 14008, "部门存在用户,不允许删除", "Department has users and cannot be deleted";
+    DeptHasEnabledChildren = 14009, "该部门包含未停用的子部门", "Department has enabled sub departments";
+    //岗位
+    PostNotFound = 15001, "岗位不存在", "Post does not exist";
+    PostNameExists = 15002, "岗位名称已存在", "Post name already exists";
+    PostCodeExists = 15003, "岗位编码已存在", "Post code already exists";
+    PostInUse = 15004, "岗位已分配,不能删除", "Post is assigned to users and cannot be deleted";
+    //字典
+    DictTypeNotFound = 16001, "字典类型不存在", "Dictionary type does not exist";
+    DictTypeExists = 16002, "字典类型已存在", "Dictionary type already exists";
+    DictTypeDeleteNotFound = 16003, "字典类型不存在,不能删除", "Dictionary type does not exist and cannot be deleted";
+    DictTypeInUse = 16004, "字典类型已分配,不能删除", "Dictionary type has data and cannot be deleted";
+    DictTypeEmpty = 16005, "字典类型不能为空", "Dictionary type must not be empty";
+    DictTypeQueryLimit = 16006, "一次最多查询{max}个字典类型", "At most {max} dictionary types can be queried at once";
+    DictDataNotFound = 16007, "字典数据不存在", "Dictionary data does not exist";
+    DictDataUpdateNotFound = 16008, "更新字典数据失败,字典数据不存在", "Update failed, dictionary data does not exist";
+    DictDataAddLabelExists = 16009, "新增字典数据失败,字典标签已存在", "Add failed, dictionary label already exists";
+    DictDataAddValueExists = 16010, "新增字典数据失败,字典键值已存在", "Add failed, dictionary value already exists";
+    DictDataUpdateLabelExists = 16011, "更新字典数据失败,字典标签已存在", "Update failed, dictionary label already exists";
+    DictDataUpdateValueExists = 16012, "更新字典数据失败,字典键值已存在", "Update failed, dictionary value already exists";
+    DictExportFailed = 16013, "导出字典失败", "Failed to export dictionaries";
+    DictExportFormatInvalid = 16014, "导出格式只支持json或yaml", "Export format must be json or yaml";
+    DictImportStrategyInvalid = 16015, "冲突策略只支持skip、merge或overwrite", "Conflict strategy must be skip, merge or overwrite";
+    DictImportFormatInvalid = 16016, "只支持导入json或yaml格式的文件", "Only json or yaml files can be imported";
+    DictImportParseFailed = 16017, "导入文件解析失败", "Failed to parse the import file";
+    DictImportEmpty = 16018, "导入文件中没有字典类型", "The import file contains no dictionary types";
+    DictImportTypeBlank = 16019, "导入文件中字典名称和字典类型不能为空", "Dictionary name and type in the import file must not be empty";
+    DictImportTypeDuplicated = 16020, "导入文件中字典类型重复: {dictType}", "Duplicate dictionary type in the import file: {dictType}";
+    DictImportDataBlank = 16021, "导入文件中字典标签和字典键值不能为空", "Dictionary label and value in the import file must not be empty";
+    DictImportLabelDuplicated = 16022, "导入文件中字典标签重复: {dictLabel}", "Duplicate dictionary label in the import file: {dictLabel}";
+    DictImportValueDuplicated = 16023, "导入文件中字典键值重复: {dictValue}", "Duplicate dictionary value in the import file: {dictValue}";
+    //参数
+    ConfigNotFound = 17001, "参数不存在", "Config does not exist";
+    ConfigKeyExists = 17002, "参数键名已存在", "Config key already exists";
+    ConfigBuiltinDelete = 17003, "内置参数不能删除", "Built-in config cannot be deleted";
+    ConfigBuiltinKey = 17004, "内置参数不能修改键名", "The key of a built-in config cannot be changed";
+    ConfigValueTypeInvalid = 17005, "值类型只支持string、int、bool或json", "Value type must be string, int, bool or json";
+    ConfigValueMismatch = 17006, "参数键值与值类型不匹配", "Config value does not match its value type";
+    //通知公告
+    NoticeNotFound = 18001, "通知公告表不存在", "Notice does not exist";
+    NoticeTitleExists = 18002, "公告标题已存在", "Notice title already exists";
+    NoticeTargetEmpty = 18003, "通知对象不能为空", "Notice targets must not be empty";
+    NoticeTargetTypeInvalid = 18004, "通知对象类型不正确", "Invalid notice target type";
+    NoticePublishAtInvalid = 18005, "发布时间格式不正确", "Invalid publish time format";
+    NoticeExpireAtInvalid = 18006, "过期时间格式不正确", "Invalid expire time format";
+    NoticeExpireBeforePublish = 18007, "过期时间必须晚于发布时间", "Expire time must be later than publish time";
+    NoticeAttachmentNameEmpty = 18008, "附件名称不能为空", "Attachment name must not be empty";
+    NoticeAttachmentUrlInvalid = 18009, "附件地址不正确", "Invalid attachment url";
+    NoticeAttachmentNotFound = 18010, "附件文件不存在", "Attachment file does not exist";
+    //文件
+    FileEmpty = 19001, "上传文件不能为空", "Uploaded file must not be empty";
+    FileTooLarge = 19002, "上传文件超过大小限制({max}MB)", "Uploaded file exceeds the size limit ({max}MB)";
+    FileTypeNotAllowed = 19003, "不支持上传该类型的文件: {ext}", "File type is not allowed: {ext}";
+    FileNotFound = 19004, "文件不存在", "File does not exist";
+    FileStorageMismatch = 19005, "文件不在当前存储中", "File is not in the current storage";
+    FileInUse = 19006, "文件已被通知公告引用,不能删除", "File is referenced by a notice and cannot be deleted";
+    AvatarFileInUse = 19007, "用户头像文件不能删除", "Avatar files cannot be deleted";
+    //定时任务
+    JobNotFound = 20001, "定时任务不存在", "Job does not exist";
+    JobHandlerNotFound = 20002, "任务处理器不存在: {name}", "Job handler does not exist: {name}";
+    JobCronInvalid = 20003, "cron表达式不正确", "Invalid cron expression";
+    JobLogNotFound = 20004, "任务日志不存在", "Job log does not exist";
+    //日志
+    LoginLogNotFound = 21001, "日志不存在", "Login log does not exist";
+    OperateLogNotFound = 21002, "操作日志不存在", "Operation log does not exist";
+    //系统错误
+    DbError = 50001, "数据库操作失败,请联系管理员(错误编号: {errorId})", "Database operation failed, please contact the administrator (reference: {errorId})";
+    StorageError = 50002, "文件存储失败,请联系管理员(错误编号: {errorId})", "File storage failed, please contact the administrator (reference: {errorId})";
+    DiskCacheRead = 50003, "读取缓存文件失败", "Failed to read the cache file";
+}
+
+/*
+ *业务异常(错误码和信息参数)
+ *author：刘飞华
+ *date：2026/10/20 00:21:36
+ */
+#[derive(Debug, Clone)]
+pub struct BizError {
+    pub code: ErrorCode,                     //错误码
+    pub params: Vec<(&'static str, String)>, //信息参数
+}
+
+impl BizError {
+    /*
+     *添加信息参数
+     *author：刘飞华
+     *date：2026/10/20 00:21:36
+     */
+    pub fn with(mut self, name: &'static str, value: impl ToString) -> BizError {
+        self.params.push((name, value.to_string()));
+        self
+    }
+
+    /*
+     *按语言生成错误信息
+     *author：刘飞华
+     *date：2026/10/20 00:21:36
+     */
+    pub fn message(&self, lang: Lang) -> String {
+        let mut message = self.code.template(lang).to_string();
+        for (name, value) in &self.params {
+            message = message.replace(&format!("{{{}}}", name), value);
+        }
+        message
+    }
+}
+
+impl ErrorCode {
+    /*
+     *带参数的业务异常
+     *author：刘飞华
+     *date：2026/10/20 00:21:36
+     */
+    pub fn with(self, name: &'static str, value: impl ToString) -> BizError {
+        BizError::from(self).with(name, value)
+    }
+
+    /*
+     *按语言生成错误信息
+     *author：刘飞华
+     *date：2026/10/20 00:21:36
+     */
+    pub fn message(self, lang: Lang) -> String {
+        BizError::from(self).message(lang)
+    }
+}
+
+impl From<ErrorCode> for BizError {
+    fn from(code: ErrorCode) -> Self {
+        BizError {
+            code,
+            params: vec![],
+        }
+    }
+}
+
+impl fmt::Display for BizError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message(Lang::ZhCn))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_error_codes() {
+        let mut codes = HashSet::new();
+        for code in ErrorCode::ALL {
+            assert!(codes.insert(code.code()), "duplicate code: {:?}", code);
+            for lang in [Lang::ZhCn, Lang::EnUs] {
+                assert!(!code.template(lang).is_empty());
+            }
+        }
+
+        let e = ErrorCode::DictTypeQueryLimit.with("max", 50);
+        assert_eq!(e.message(Lang::ZhCn), "一次最多查询50个字典类型");
+        assert_eq!(
+            e.message(Lang::EnUs),
+            "At most 50 dictionary types can be queried at once"
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use rocket::serde::json::Value;
+use rocket::tokio::sync::broadcast;
+use rocket::tokio::sync::broadcast::Receiver;
+
+//新发布的通知公告
+pub const EVENT_NOTICE: &str = "notice";
+//强制下线(用户被禁用、删除或密码被重置)
+pub const EVENT_FORCE_LOGOUT: &str = "forceLogout";
+//权限变更(用户角色或角色菜单被修改)
+pub const EVENT_PERMISSION_CHANGED: &str = "permissionChanged";
+
+lazy_static! {
+    pub static ref EVENT_HUB: EventHub = EventHub::new(1024);
+}
+
+/*
+ *推送事件
+ *author：刘飞华
+ *date：2026/10/19 16:31:08
+ */
+#[derive(Debug, Clone)]
+pub struct PushEvent {
+    pub user_ids: Option<Vec<i64>>, //接收的用户ID(None表示全部用户)
+    pub event: &'static str,        //事件类型
+    pub data: Value,                //事件内容
+}
+
+impl PushEvent {
+    /*
+     *判断事件是否推送给指定用户
+     *author：刘飞华
+     *date：2026/10/19 16:31:08
+     */
+    pub fn is_for(&self, user_id: i64) -> bool {
+        match &self.user_ids {
+            None => true,
+            Some(ids) => ids.contains(&user_id),
+        }
+    }
+}
+
+/*
+ *进程内事件中心(handler发布事件,sse连接订阅后按用户过滤)
+ *author：刘飞华
+ *date：2026/10/19 16:31:08
+ */
+pub struct EventHub {
+    sender: broadcast::Sender<PushEvent>,
+}
+
+impl EventHub {
+    pub fn new(capacity: usize) -> EventHub {
+        let (sender, _) = broadcast::channel(capacity);
+        EventHub { sender }
+    }
+
+    /*
+     *订阅事件
+     *author：刘飞华
+     *date：2026/10/19 16:31:08
+     */
+    pub fn subscribe(&self) -> Receiver<PushEvent> {
+        self.sender.subscribe()
+    }
+
+    /*
+     *推送事件给全部在线用户
+     *author：刘飞华
+     *date：2026/10/19 16:31:08
+     */
+    pub fn publish_all(&self, event: &'static str, data: Value) {
+        self.publish(PushEvent {
+            user_ids: None,
+            event,
+            data,
+        })
+    }
+
+    /*
+     *推送事件给指定用户
+     *author：刘飞华
+     *date：2026/10/19 16:31:08
+     */
+    pub fn publish_users(&self, user_ids: Vec<i64>, event: &'static str, data: Value) {
+        if user_ids.is_empty() {
+            return;
+        }
+        self.publish(PushEvent {
+            user_ids: Some(user_ids),
+            event,
+            data,
+        })
+    }
+
+    /*
+     *当前订阅数(在线的sse连接数)
+     *author：刘飞华
+     *date：2026/10/19 21:40:05
+     */
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+
+    fn publish(&self, push_event: PushEvent) {
+        //没有在线用户时发送会返回错误,直接忽略
+        match self.sender.send(push_event) {
+            Ok(n) => log::info!("publish event to {} subscribers", n),
+            Err(err) => log::debug!("publish event without subscribers: {:?}", err.0.event),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use rocket::request::{FromRequest, Outcome};
+use rocket::Request;
+
+/*
+ *返回信息的语言(根据Accept-Language请求头选择,默认简体中文)
+ *author：刘飞华
+ *date：2026/10/20 00:21:36
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Lang {
+    #[default]
+    ZhCn, //简体中文
+    EnUs, //英文
+}
+
+impl Lang {
+    /*
+     *解析Accept-Language(按q值选择支持的语言,例如: en-US,en;q=0.9,zh-CN;q=0.8)
+     *author：刘飞华
+     *date：2026/10/20 00:21:36
+     */
+    pub fn parse(accept_language: &str) -> Lang {
+        let mut best: Option<(Lang, f32)> = None;
+        for item in accept_language.split(',') {
+            let mut parts = item.trim().split(';');
+            let tag = parts.next().unwrap_or_default().trim().to_ascii_lowercase();
+            let q = parts
+                .filter_map(|x| x.trim().strip_prefix("q="))
+                .find_map(|x| x.parse::<f32>().ok())
+                .unwrap_or(1.0);
+
+            let lang = match tag.split('-').next().unwrap_or_default() {
+                "zh" => Lang::ZhCn,
+                "en" => Lang::EnUs,
+                _ => continue,
+            };
+            if q > 0.0 && best.is_none_or(|(_, x)| q > x) {
+                best = Some((lang, q));
+            }
+        }
+        best.map(|(lang, _)| lang).unwrap_or_default()
+    }
+
+    /*
+     *获取请求的语言
+     *author：刘飞华
+     *date：2026/10/20 00:21:36
+     */
+    pub fn of(request: &Request<'_>) -> Lang {
+        *request.local_cache(|| {
+            request
+                .headers()
+                .get_one("Accept-Language")
+                .map(Lang::parse)
+                .unwrap_or_default()
+        })
+    }
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Lang {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        Outcome::Success(Lang::of(request))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Lang::parse(""), Lang::ZhCn);
+        assert_eq!(Lang::parse("en-US"), Lang::EnUs);
+        assert_eq!(Lang::parse("zh-CN,zh;q=0.9,en;q=0.8"), Lang::ZhCn);
+        assert_eq!(Lang::parse("fr-FR,en-GB;q=0.7,zh-TW;q=0.5"), Lang::EnUs);
+        assert_eq!(Lang::parse("en;q=0.3, zh-CN;q=0.8"), Lang::ZhCn);
+        assert_eq!(Lang::parse("fr-FR"), Lang::ZhCn);
+    }
+}
@@ -0,0 +1,150 @@
+use crate::common::event_hub::EVENT_HUB;
+use crate::RB;
+use prometheus::{
+    HistogramOpts, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec, Opts, Registry, TextEncoder,
+};
+
+//连接池状态字段(rbdc_pool_fast)
+const DB_POOL_STATES: [&str; 5] = ["max_open", "connections", "in_use", "idle", "waits"];
+
+lazy_static! {
+    pub static ref METRICS: Metrics = Metrics::default();
+}
+
+/*
+ *Prometheus指标(请求指标由fairing采集,其他指标在抓取时更新)
+ *author：刘飞华
+ *date：2026/10/19 21:40:05
+ */
+pub struct Metrics {
+    registry: Registry,
+    http_requests: IntCounterVec,        //请求次数(method,route,status)
+    http_request_duration: HistogramVec, //请求耗时(method,route)
+    login_attempts: IntCounterVec,       //登录次数(result)
+    db_pool: IntGaugeVec,                //数据库连接池(state)
+    active_sessions: IntGauge,           //在线会话数(sse连接数)
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        let http_requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "Total number of HTTP requests"),
+            &["method", "route", "status"],
+        )
+        .unwrap();
+        let http_request_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "HTTP request latency in seconds",
+            ),
+            &["method", "route"],
+        )
+        .unwrap();
+        let login_attempts = IntCounterVec::new(
+            Opts::new("login_attempts_total", "Total number of login attempts"),
+            &["result"],
+        )
+        .unwrap();
+        let db_pool = IntGaugeVec::new(
+            Opts::new("db_pool_connections", "Database connection pool state"),
+            &["state"],
+        )
+        .unwrap();
+        let active_sessions = IntGauge::new(
+            "active_sessions",
+            "Number of online sessions (event stream connections)",
+        )
+        .unwrap();
+
+        let registry = Registry::new();
+        registry.register(Box::new(http_requests.clone())).unwrap();
+        registry
+            .register(Box::new(http_request_duration.clone()))
+            .unwrap();
+        registry.register(Box::new(login_attempts.clone())).unwrap();
+        registry.register(Box::new(db_pool.clone())).unwrap();
+        registry
+            .register(Box::new(active_sessions.clone()))
+            .unwrap();
+
+        Metrics {
+            registry,
+            http_requests,
+            http_request_duration,
+            login_attempts,
+            db_pool,
+            active_sessions,
+        }
+    }
+}
+
+impl Metrics {
+    /*
+     *记录一次请求(route为路由模板,未匹配到路由时为unmatched)
+     *author：刘飞华
+     *date：2026/10/19 21:40:05
+     */
+    pub fn observe_request(&self, method: &str, route: &str, status: u16, seconds: f64) {
+        self.http_requests
+            .with_label_values(&[method, route, &status.to_string()])
+            .inc();
+        self.http_request_duration
+            .with_label_values(&[method, route])
+            .observe(seconds);
+    }
+
+    /*
+     *记录一次登录(success:是否登录成功)
+     *author：刘飞华
+     *date：2026/10/19 21:40:05
+     */
+    pub fn observe_login(&self, success: bool) {
+        let result = if success { "success" } else { "failure" };
+        self.login_attempts.with_label_values(&[result]).inc();
+    }
+
+    /*
+     *更新抓取时才计算的指标,并输出Prometheus文本格式
+     *author：刘飞华
+     *date：2026/10/19 21:40:05
+     */
+    pub async fn render(&self) -> String {
+        if let Ok(pool) = RB.get_pool() {
+            let state = pool.state().await;
+            for x in DB_POOL_STATES {
+                let value = state[x].as_i64().unwrap_or_default();
+                self.db_pool.with_label_values(&[x]).set(value);
+            }
+        }
+        self.active_sessions
+            .set(EVENT_HUB.subscriber_count() as i64);
+
+        TextEncoder::new()
+            .encode_to_string(&self.registry.gather())
+            .unwrap_or_else(|e| {
+                log::error!("encode metrics error: {}", e);
+                String::new()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::metrics::Metrics;
+
+    #[test]
+    fn test_observe_request() {
+        let metrics = Metrics::default();
+        metrics.observe_request("GET", "/api/monitor/server", 200, 0.01);
+        metrics.observe_request("GET", "/api/monitor/server", 200, 0.02);
+        metrics.observe_login(false);
+
+        let text = prometheus::TextEncoder::new()
+            .encode_to_string(&metrics.registry.gather())
+            .unwrap();
+        assert!(text.contains(
+            r#"http_requests_total{method="GET",route="/api/monitor/server",status="200"} 2"#
+        ));
+        assert!(text.contains(r#"login_attempts_total{result="failure"} 1"#));
+    }
+}
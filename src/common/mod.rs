@@ -1,2 +1,13 @@
+pub mod catcher;
+pub mod config_cache;
+pub mod dict_cache;
 pub mod error;
+pub mod error_code;
+pub mod event_hub;
+pub mod i18n;
+pub mod metrics;
+pub mod request_context;
 pub mod result;
+pub mod scheduler;
+pub mod storage;
+pub mod telemetry;
@@ -0,0 +1,171 @@
+use rocket::tokio::task;
+use rocket::Request;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Instant;
+use tracing::Span;
+
+//请求ID请求头/响应头
+pub const REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+lazy_static! {
+    //日志输出时按task id查找当前请求的上下文(只保存弱引用,上下文由请求的local_cache持有,请求释放时移除)
+    static ref REQUEST_CONTEXTS: Mutex<HashMap<task::Id, Weak<Mutex<RequestContext>>>> =
+        Mutex::new(HashMap::new());
+}
+
+/*
+ *请求上下文(日志输出时读取,用于关联同一个请求的日志)
+ *author：刘飞华
+ *date：2026/10/19 22:05:47
+ */
+#[derive(Debug, Clone)]
+pub struct RequestContext {
+    pub request_id: String,       //请求ID
+    pub method: String,           //请求方法
+    pub uri: String,              //请求地址
+    pub route: Option<String>,    //路由模板
+    pub user_id: Option<i64>,     //用户ID(token校验通过后设置)
+    pub status: Option<u16>,      //响应状态码
+    pub latency_ms: Option<u128>, //耗时(毫秒)
+    pub start: Instant,           //开始时间
+    pub span: Option<Span>,       //请求的span(启用OpenTelemetry时设置)
+}
+
+/*
+ *请求上下文的持有者(保存在请求的local_cache中,请求结束或被取消时随请求释放)
+ *author：刘飞华
+ *date：2026/10/20 09:48:12
+ */
+struct RequestContextHolder {
+    task_id: Option<task::Id>,                   //处理请求的task id
+    context: Option<Arc<Mutex<RequestContext>>>, //请求上下文
+}
+
+impl Drop for RequestContextHolder {
+    fn drop(&mut self) {
+        let context = match (self.task_id, self.context.take()) {
+            (Some(id), Some(context)) => (id, context),
+            _ => return,
+        };
+        if let Ok(mut contexts) = REQUEST_CONTEXTS.lock() {
+            //task id对应的已经是其他请求的上下文时不删除
+            if contexts
+                .get(&context.0)
+                .is_some_and(|x| x.as_ptr() == Arc::as_ptr(&context.1))
+            {
+                contexts.remove(&context.0);
+            }
+        }
+    }
+}
+
+fn holder<'r>(request: &'r Request<'_>) -> &'r RequestContextHolder {
+    request.local_cache(|| RequestContextHolder {
+        task_id: None,
+        context: None,
+    })
+}
+
+/*
+ *开始处理请求(在请求的第一个fairing中调用)
+ *author：刘飞华
+ *date：2026/10/19 22:05:47
+ */
+pub fn begin(request: &Request<'_>, context: RequestContext) {
+    let context = Arc::new(Mutex::new(context));
+    let task_id = task::try_id();
+    if let (Some(id), Ok(mut contexts)) = (task_id, REQUEST_CONTEXTS.lock()) {
+        contexts.insert(id, Arc::downgrade(&context));
+    }
+    request.local_cache(|| RequestContextHolder {
+        task_id,
+        context: Some(context),
+    });
+}
+
+/*
+ *修改请求的上下文
+ *author：刘飞华
+ *date：2026/10/19 22:05:47
+ */
+pub fn update(request: &Request<'_>, f: impl FnOnce(&mut RequestContext)) {
+    if let Some(mut context) = holder(request).context.as_ref().and_then(|x| x.lock().ok()) {
+        f(&mut context);
+    }
+}
+
+/*
+ *设置请求的用户ID(token校验通过后调用)
+ *author：刘飞华
+ *date：2026/10/19 22:31:26
+ */
+pub fn set_user_id(request: &Request<'_>, user_id: i64) {
+    update(request, |x| {
+        x.user_id = Some(user_id);
+        if let Some(span) = &x.span {
+            span.record("user.id", user_id);
+        }
+    });
+}
+
+/*
+ *请求的上下文(未经过fairing时返回None)
+ *author：刘飞华
+ *date：2026/10/20 09:48:12
+ */
+pub fn of(request: &Request<'_>) -> Option<RequestContext> {
+    let context = holder(request).context.as_ref()?;
+    context.lock().ok().map(|x| x.clone())
+}
+
+/*
+ *当前task正在处理的请求的上下文(日志输出等拿不到请求的地方使用,不在请求中时返回None)
+ *author：刘飞华
+ *date：2026/10/19 22:05:47
+ */
+pub fn current() -> Option<RequestContext> {
+    let id = task::try_id()?;
+    let context = REQUEST_CONTEXTS.lock().ok()?.get(&id)?.upgrade()?;
+    let context = context.lock().ok()?.clone();
+    Some(context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rocket::local::asynchronous::Client;
+
+    #[rocket::async_test]
+    async fn test_request_context_released_with_request() {
+        //rocket在独立的task中处理每个请求
+        let task = rocket::tokio::spawn(async {
+            let client = Client::untracked(rocket::build()).await.unwrap();
+            let request = client.get("/api/test");
+            begin(
+                request.inner(),
+                RequestContext {
+                    request_id: "r1".to_string(),
+                    method: "GET".to_string(),
+                    uri: "/api/test".to_string(),
+                    route: None,
+                    user_id: None,
+                    status: None,
+                    latency_ms: None,
+                    start: Instant::now(),
+                    span: None,
+                },
+            );
+            set_user_id(request.inner(), 1);
+            assert_eq!(of(request.inner()).and_then(|x| x.user_id), Some(1));
+            assert_eq!(current().map(|x| x.request_id), Some("r1".to_string()));
+
+            //请求被丢弃(例如客户端断开导致请求被取消)时上下文随之释放
+            drop(request);
+            assert!(current().is_none());
+            let id = task::try_id().unwrap();
+            assert!(!REQUEST_CONTEXTS.lock().unwrap().contains_key(&id));
+        });
+        task.await.unwrap();
+    }
+}
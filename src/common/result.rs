@@ -1,11 +1,15 @@
 use crate::common::error::AppResult;
+use crate::middleware::etag::IfNoneMatch;
+use rocket::http::{ContentType, Header, RawStr};
 use rocket::serde::json::serde_json::json;
 use rocket::serde::json::Value;
 use serde::Serialize;
+use sha2::{Digest, Sha256};
 use std::fmt::Debug;
+use utoipa::ToSchema;
 
 // 统一返回vo
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Debug, Clone, ToSchema)]
 pub struct BaseResponse<T>
 where
     T: Serialize + Debug,
@@ -15,7 +19,7 @@ where
     pub data: Option<T>,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Debug, Clone, ToSchema)]
 pub struct ResponsePage<T>
 where
     T: Serialize + Debug,
@@ -27,6 +31,21 @@ where
     pub data: Option<T>,
 }
 
+// 游标分页返回vo
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseCursorPage<T>
+where
+    T: Serialize + Debug,
+{
+    pub code: i32,
+    pub msg: String,
+    pub total: Option<u64>,
+    pub next_cursor: Option<String>,
+    pub success: bool,
+    pub data: Option<T>,
+}
+
 pub fn ok_result() -> AppResult<Value> {
     Ok(json!(BaseResponse {
         msg: "操作成功".to_string(),
@@ -68,3 +87,67 @@ pub fn ok_result_page<T: Serialize + Debug>(data: T, total: u64) -> AppResult<Va
         total,
     }))
 }
+
+pub fn ok_result_cursor_page<T: Serialize + Debug>(
+    data: T,
+    next_cursor: Option<String>,
+    total: Option<u64>,
+) -> AppResult<Value> {
+    Ok(json!(ResponseCursorPage {
+        msg: "操作成功".to_string(),
+        code: 0,
+        success: true,
+        data: Some(data),
+        next_cursor,
+        total,
+    }))
+}
+
+// 文件下载返回
+#[derive(Responder)]
+pub struct FileResponse {
+    pub data: Vec<u8>,
+    pub content_type: ContentType,
+    pub disposition: Header<'static>,
+}
+
+pub fn ok_result_file(
+    data: Vec<u8>,
+    content_type: &str,
+    file_name: &str,
+) -> AppResult<FileResponse> {
+    let disposition = format!(
+        "attachment; filename*=UTF-8''{}",
+        RawStr::new(file_name).percent_encode()
+    );
+    Ok(FileResponse {
+        data,
+        content_type: ContentType::parse_flexible(content_type).unwrap_or(ContentType::Binary),
+        disposition: Header::new("Content-Disposition", disposition),
+    })
+}
+
+// 带ETag的返回(客户端缓存未变化时返回304)
+#[derive(Responder)]
+pub enum EtagResponse {
+    #[response(status = 200)]
+    Ok(Value, Header<'static>),
+    #[response(status = 304)]
+    NotModified((), Header<'static>),
+}
+
+pub fn ok_result_etag<T: Serialize + Debug>(
+    data: T,
+    if_none_match: &IfNoneMatch,
+) -> AppResult<EtagResponse> {
+    let body = json!(BaseResponse {
+        msg: "操作成功".to_string(),
+        code: 0,
+        data: Some(data),
+    });
+    let etag = format!("\"{:x}\"", Sha256::digest(body.to_string().as_bytes()));
+    if if_none_match.matches(&etag) {
+        return Ok(EtagResponse::NotModified((), Header::new("ETag", etag)));
+    }
+    Ok(EtagResponse::Ok(body, Header::new("ETag", etag)))
+}
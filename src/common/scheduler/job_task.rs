@@ -0,0 +1,92 @@
+use crate::common::config_cache::SYS_CONFIG;
+use crate::RB;
+use rbs::value;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::pin::Pin;
+
+//任务执行结果(Ok:执行结果,Err:错误信息)
+pub type JobResult = Result<String, String>;
+//任务处理器(参数为sys_job.job_params)
+pub type JobHandler = fn(String) -> Pin<Box<dyn Future<Output = JobResult> + Send>>;
+
+lazy_static! {
+    //已注册的任务处理器(sys_job.invoke_target -> 处理器),新增任务时在这里注册
+    pub static ref JOB_HANDLERS: BTreeMap<&'static str, JobHandler> = {
+        let mut handlers: BTreeMap<&'static str, JobHandler> = BTreeMap::new();
+        handlers.insert("cleanLoginLog", |x| Box::pin(clean_login_log(x)));
+        handlers.insert("cleanOperateLog", |x| Box::pin(clean_operate_log(x)));
+        handlers.insert("cleanJobLog", |x| Box::pin(clean_job_log(x)));
+        handlers.insert("refreshConfigCache", |x| Box::pin(refresh_config_cache(x)));
+        handlers
+    };
+}
+
+/*
+ *解析保留天数参数(为空时使用默认值)
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+fn parse_days(params: &str, default: i64) -> Result<i64, String> {
+    if params.trim().is_empty() {
+        return Ok(default);
+    }
+    match params.trim().parse::<i64>() {
+        Ok(x) if x > 0 => Ok(x),
+        _ => Err(format!("任务参数必须是正整数(保留天数): {}", params)),
+    }
+}
+
+/*
+ *删除指定天数之前的数据
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+async fn clean_table(table: &str, time_column: &str, days: i64) -> JobResult {
+    let sql = format!(
+        "delete from {} where {} < date_sub(now(), interval ? day)",
+        table, time_column
+    );
+    let result = RB
+        .exec(&sql, vec![value!(days)])
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(format!("删除{}天前的数据{}条", days, result.rows_affected))
+}
+
+/*
+ *清理登录日志(参数：保留天数,默认90)
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+async fn clean_login_log(params: String) -> JobResult {
+    clean_table("sys_login_log", "login_time", parse_days(&params, 90)?).await
+}
+
+/*
+ *清理操作日志(参数：保留天数,默认90)
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+async fn clean_operate_log(params: String) -> JobResult {
+    clean_table("sys_operate_log", "operate_time", parse_days(&params, 90)?).await
+}
+
+/*
+ *清理定时任务执行日志(参数：保留天数,默认30)
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+async fn clean_job_log(params: String) -> JobResult {
+    clean_table("sys_job_log", "start_time", parse_days(&params, 30)?).await
+}
+
+/*
+ *从数据库同步参数配置(多实例部署时使用)
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+async fn refresh_config_cache(_params: String) -> JobResult {
+    SYS_CONFIG.reload().await.map_err(|e| e.to_string())?;
+    Ok("同步参数配置成功".to_string())
+}
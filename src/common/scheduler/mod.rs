@@ -0,0 +1,331 @@
+use crate::common::error::{AppError, AppResult};
+use crate::common::error_code::ErrorCode;
+use crate::common::scheduler::job_task::JOB_HANDLERS;
+use crate::model::system::sys_job_log_model::SysJobLog;
+use crate::model::system::sys_job_model::SysJob;
+use crate::RB;
+use chrono::Local;
+use cron::Schedule;
+use rbatis::rbdc::datetime::DateTime;
+use rocket::tokio::time::{interval, sleep, Duration};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::Instant;
+
+pub mod job_task;
+
+//触发方式：定时
+pub const TRIGGER_CRON: i8 = 0;
+//触发方式：手动
+pub const TRIGGER_MANUAL: i8 = 1;
+
+//执行状态：失败
+pub const JOB_LOG_FAIL: i8 = 0;
+//执行状态：成功
+pub const JOB_LOG_SUCCESS: i8 = 1;
+//执行状态：跳过(禁止并发且上一次执行未结束)
+pub const JOB_LOG_SKIP: i8 = 2;
+
+lazy_static! {
+    pub static ref JOB_SCHEDULER: JobScheduler = JobScheduler::default();
+}
+
+/*
+ *已调度的任务
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+struct ScheduledJob {
+    job: SysJob,
+    schedule: Schedule,
+    next_time: Option<chrono::DateTime<Local>>, //下次执行时间
+}
+
+/*
+ *定时任务调度器(进程内按cron表达式触发,任务增删改后由handler同步到调度器;多实例部署时每个实例都会触发)
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+#[derive(Default)]
+pub struct JobScheduler {
+    jobs: Mutex<HashMap<i64, ScheduledJob>>,
+    running: Mutex<HashSet<i64>>, //本进程内正在执行的禁止并发的任务
+}
+
+impl JobScheduler {
+    /*
+     *从数据库加载全部正常状态的任务
+     *author：刘飞华
+     *date：2026/10/19 20:02:31
+     */
+    pub async fn load_all(&self) -> AppResult<()> {
+        let list = SysJob::select_enabled_job(&RB.clone()).await?;
+        log::info!("load sys_job: {} jobs", list.len());
+        if let Ok(mut jobs) = self.jobs.lock() {
+            jobs.clear();
+        }
+        for job in list {
+            self.schedule_job(job);
+        }
+        Ok(())
+    }
+
+    /*
+     *添加或更新任务调度(任务暂停时移除)
+     *author：刘飞华
+     *date：2026/10/19 20:02:31
+     */
+    pub fn schedule_job(&self, job: SysJob) {
+        let id = job.id.unwrap_or_default();
+        if job.status != 1 {
+            self.unschedule_job(id);
+            return;
+        }
+
+        let schedule = match parse_cron(&job.cron_expression) {
+            Ok(x) => x,
+            Err(_) => {
+                log::error!(
+                    "sys_job {} cron expression error: {}",
+                    id,
+                    job.cron_expression
+                );
+                self.unschedule_job(id);
+                return;
+            }
+        };
+
+        let next_time = schedule.upcoming(Local).next();
+        if let Ok(mut jobs) = self.jobs.lock() {
+            jobs.insert(
+                id,
+                ScheduledJob {
+                    job,
+                    schedule,
+                    next_time,
+                },
+            );
+        }
+    }
+
+    /*
+     *移除任务调度
+     *author：刘飞华
+     *date：2026/10/19 20:02:31
+     */
+    pub fn unschedule_job(&self, id: i64) {
+        if let Ok(mut jobs) = self.jobs.lock() {
+            jobs.remove(&id);
+        }
+    }
+
+    /*
+     *取出到达执行时间的任务,并计算下次执行时间
+     *author：刘飞华
+     *date：2026/10/19 20:02:31
+     */
+    fn take_due_jobs(&self) -> Vec<SysJob> {
+        let now = Local::now();
+        let mut due_jobs = Vec::new();
+        if let Ok(mut jobs) = self.jobs.lock() {
+            for x in jobs.values_mut() {
+                if x.next_time.is_some_and(|t| t <= now) {
+                    due_jobs.push(x.job.clone());
+                    x.next_time = x.schedule.after(&now).next();
+                }
+            }
+        }
+        due_jobs
+    }
+
+    /*
+     *异步执行任务
+     *author：刘飞华
+     *date：2026/10/19 20:02:31
+     */
+    pub fn trigger(&self, job: SysJob, trigger_type: i8) {
+        rocket::tokio::spawn(execute_job(job, trigger_type));
+    }
+
+    /*
+     *标记任务开始执行(禁止并发的任务正在执行时返回false;只检查本进程,不能阻止其他实例同时执行)
+     *author：刘飞华
+     *date：2026/10/19 20:02:31
+     */
+    fn begin_run(&self, job: &SysJob) -> bool {
+        if job.concurrent == 1 {
+            return true;
+        }
+        match self.running.lock() {
+            Ok(mut running) => running.insert(job.id.unwrap_or_default()),
+            Err(_) => false,
+        }
+    }
+
+    /*
+     *标记任务执行结束
+     *author：刘飞华
+     *date：2026/10/19 20:02:31
+     */
+    fn end_run(&self, job: &SysJob) {
+        if job.concurrent == 1 {
+            return;
+        }
+        if let Ok(mut running) = self.running.lock() {
+            running.remove(&job.id.unwrap_or_default());
+        }
+    }
+}
+
+/*
+ *解析cron表达式(秒 分 时 日 月 周 [年])
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+pub fn parse_cron(cron_expression: &str) -> AppResult<Schedule> {
+    Schedule::from_str(cron_expression.trim())
+        .map_err(|_| AppError::from(ErrorCode::JobCronInvalid))
+}
+
+/*
+ *计算cron表达式的下次执行时间
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+pub fn next_valid_time(cron_expression: &str) -> String {
+    parse_cron(cron_expression)
+        .ok()
+        .and_then(|x| x.upcoming(Local).next())
+        .map(|x| x.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_default()
+}
+
+/*
+ *执行任务并记录执行日志
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+async fn execute_job(job: SysJob, trigger_type: i8) {
+    let start_time = DateTime::now();
+    let instant = Instant::now();
+
+    let (status, job_message, exception_info) = if !JOB_SCHEDULER.begin_run(&job) {
+        (
+            JOB_LOG_SKIP,
+            "上一次执行未结束,跳过本次执行".to_string(),
+            None,
+        )
+    } else {
+        let result = match JOB_HANDLERS.get(job.invoke_target.as_str()) {
+            None => Err(format!("任务处理器不存在: {}", job.invoke_target)),
+            //在独立的task中执行,任务panic时也能记录日志
+            Some(handler) => match rocket::tokio::spawn(handler(job.job_params.clone())).await {
+                Ok(x) => x,
+                Err(e) => Err(format!("任务执行异常: {}", e)),
+            },
+        };
+        JOB_SCHEDULER.end_run(&job);
+
+        match result {
+            Ok(msg) => (JOB_LOG_SUCCESS, msg, None),
+            Err(err) => (JOB_LOG_FAIL, "执行失败".to_string(), Some(err)),
+        }
+    };
+
+    let sys_job_log = SysJobLog {
+        id: None,                                             //任务日志ID
+        job_id: job.id.unwrap_or_default(),                   //任务ID
+        job_name: job.job_name,                               //任务名称
+        job_group: job.job_group,                             //任务组名
+        invoke_target: job.invoke_target,                     //任务处理器名称
+        job_params: job.job_params,                           //任务参数
+        trigger_type,                                         //触发方式（0：定时,1:手动）
+        status,                                               //执行状态（0：失败,1:成功,2:跳过）
+        job_message: job_message.chars().take(500).collect(), //执行结果
+        exception_info,                                       //错误信息
+        start_time: Some(start_time),                         //开始时间
+        end_time: Some(DateTime::now()),                      //结束时间
+        duration: instant.elapsed().as_millis() as i64,       //耗时(毫秒)
+    };
+
+    match SysJobLog::insert(&RB.clone(), &sys_job_log).await {
+        Ok(_) => log::info!("execute sys_job finish: {:?}", sys_job_log),
+        Err(err) => log::error!("add sys_job_log error: {:?}, {}", sys_job_log, err),
+    }
+}
+
+/*
+ *定时任务调度(每秒检查一次到达执行时间的任务)
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+pub async fn run_job_scheduler() {
+    //数据库不可用时每30秒重试一次
+    while let Err(err) = JOB_SCHEDULER.load_all().await {
+        log::error!("load sys_job error: {}", err);
+        sleep(Duration::from_secs(30)).await;
+    }
+
+    let mut ticker = interval(Duration::from_secs(1));
+    loop {
+        ticker.tick().await;
+        for job in JOB_SCHEDULER.take_due_jobs() {
+            JOB_SCHEDULER.trigger(job, TRIGGER_CRON);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::scheduler::{parse_cron, JobScheduler};
+    use crate::model::system::sys_job_model::SysJob;
+
+    fn new_job(id: i64, concurrent: i8, status: i8) -> SysJob {
+        SysJob {
+            id: Some(id),
+            job_name: "test".to_string(),
+            job_group: "DEFAULT".to_string(),
+            invoke_target: "cleanJobLog".to_string(),
+            job_params: "".to_string(),
+            cron_expression: "* * * * * ?".to_string(),
+            concurrent,
+            status,
+            remark: "".to_string(),
+            create_time: None,
+            update_time: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_cron() {
+        assert!(parse_cron("0 0 2 * * ?").is_ok());
+        assert!(parse_cron("0/10 * * * * *").is_ok());
+        assert!(parse_cron("* * * *").is_err());
+    }
+
+    #[test]
+    fn test_schedule_job() {
+        let scheduler = JobScheduler::default();
+        scheduler.schedule_job(new_job(1, 0, 1));
+        scheduler.schedule_job(new_job(2, 0, 0));
+        assert_eq!(scheduler.jobs.lock().unwrap().len(), 1);
+
+        scheduler.schedule_job(new_job(1, 0, 0));
+        assert!(scheduler.jobs.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_concurrent() {
+        let scheduler = JobScheduler::default();
+        let job = new_job(1, 0, 1);
+        assert!(scheduler.begin_run(&job));
+        assert!(!scheduler.begin_run(&job));
+        scheduler.end_run(&job);
+        assert!(scheduler.begin_run(&job));
+
+        let job = new_job(2, 1, 1);
+        assert!(scheduler.begin_run(&job));
+        assert!(scheduler.begin_run(&job));
+    }
+}
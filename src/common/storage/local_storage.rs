@@ -0,0 +1,66 @@
+use crate::common::error::{AppError, AppResult};
+use crate::common::storage::FileStorage;
+use rocket::tokio::fs;
+use std::env;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+/*
+ *本地磁盘文件存储
+ *author：刘飞华
+ *date：2026/10/19 18:26:40
+ */
+pub struct LocalStorage {
+    base_path: PathBuf, //文件保存的根目录
+}
+
+impl LocalStorage {
+    pub fn new(base_path: &str) -> LocalStorage {
+        LocalStorage {
+            base_path: PathBuf::from(base_path),
+        }
+    }
+
+    /*
+     *根据环境变量创建(FILE_LOCAL_PATH,默认upload)
+     */
+    pub fn from_env() -> LocalStorage {
+        LocalStorage::new(&env::var("FILE_LOCAL_PATH").unwrap_or("upload".to_string()))
+    }
+
+    fn path(&self, key: &str) -> AppResult<PathBuf> {
+        //key由服务端根据文件hash生成,这里再校验一次防止目录穿越
+        if key.is_empty() || key.starts_with('/') || key.split('/').any(|x| x == "..") {
+            return Err(AppError::storage(format!("非法的文件key: {}", key)));
+        }
+        Ok(self.base_path.join(key))
+    }
+}
+
+#[rocket::async_trait]
+impl FileStorage for LocalStorage {
+    fn storage_type(&self) -> &'static str {
+        "local"
+    }
+
+    async fn put(&self, key: &str, data: &[u8], _content_type: &str) -> AppResult<()> {
+        let path = self.path(key)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .map_err(AppError::storage)?;
+        }
+        fs::write(&path, data).await.map_err(AppError::storage)
+    }
+
+    async fn get(&self, key: &str) -> AppResult<Vec<u8>> {
+        fs::read(self.path(key)?).await.map_err(AppError::storage)
+    }
+
+    async fn delete(&self, key: &str) -> AppResult<()> {
+        match fs::remove_file(self.path(key)?).await {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(AppError::storage(e)),
+            _ => Ok(()),
+        }
+    }
+}
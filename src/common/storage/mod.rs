@@ -0,0 +1,66 @@
+use crate::common::error::AppResult;
+use crate::common::storage::local_storage::LocalStorage;
+use crate::common::storage::s3_storage::S3Storage;
+use std::env;
+
+pub mod local_storage;
+pub mod s3_storage;
+
+lazy_static! {
+    //文件存储(FILE_STORAGE=local:本地磁盘,s3:兼容s3的对象存储,默认local)
+    pub static ref FILE_STORAGE: Box<dyn FileStorage> = new_file_storage();
+    //上传文件大小限制(FILE_MAX_SIZE,单位MB,默认10MB)
+    pub static ref FILE_MAX_SIZE: u64 = env::var("FILE_MAX_SIZE")
+        .ok()
+        .and_then(|x| x.parse::<u64>().ok())
+        .unwrap_or(10)
+        * 1024
+        * 1024;
+    //允许上传的文件类型(FILE_ALLOWED_EXT,逗号分隔的扩展名)
+    pub static ref FILE_ALLOWED_EXT: Vec<String> = env::var("FILE_ALLOWED_EXT")
+        .unwrap_or("jpg,jpeg,png,gif,bmp,webp,pdf,txt,doc,docx,xls,xlsx,ppt,pptx,zip".to_string())
+        .split(',')
+        .map(|x| x.trim().to_lowercase())
+        .filter(|x| !x.is_empty())
+        .collect();
+}
+
+/*
+ *文件存储
+ *author：刘飞华
+ *date：2026/10/19 18:26:40
+ */
+#[rocket::async_trait]
+pub trait FileStorage: Send + Sync {
+    /*
+     *存储类型(local/s3)
+     */
+    fn storage_type(&self) -> &'static str;
+
+    /*
+     *保存文件
+     */
+    async fn put(&self, key: &str, data: &[u8], content_type: &str) -> AppResult<()>;
+
+    /*
+     *读取文件
+     */
+    async fn get(&self, key: &str) -> AppResult<Vec<u8>>;
+
+    /*
+     *删除文件(文件不存在时不报错)
+     */
+    async fn delete(&self, key: &str) -> AppResult<()>;
+}
+
+/*
+ *根据环境变量创建文件存储
+ *author：刘飞华
+ *date：2026/10/19 18:26:40
+ */
+fn new_file_storage() -> Box<dyn FileStorage> {
+    match env::var("FILE_STORAGE").unwrap_or_default().as_str() {
+        "s3" => Box::new(S3Storage::from_env().expect("create s3 file storage error")),
+        _ => Box::new(LocalStorage::from_env()),
+    }
+}
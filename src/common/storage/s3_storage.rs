@@ -0,0 +1,91 @@
+use crate::common::error::{AppError, AppResult};
+use crate::common::storage::FileStorage;
+use s3::creds::Credentials;
+use s3::error::S3Error;
+use s3::{Bucket, Region};
+use std::env;
+
+/*
+ *兼容s3协议的对象存储(aws s3、minio等)
+ *author：刘飞华
+ *date：2026/10/19 18:26:40
+ */
+pub struct S3Storage {
+    bucket: Box<Bucket>,
+}
+
+impl S3Storage {
+    pub fn new(
+        endpoint: &str,
+        region: &str,
+        bucket_name: &str,
+        access_key: &str,
+        secret_key: &str,
+    ) -> AppResult<S3Storage> {
+        let region = Region::Custom {
+            region: region.to_string(),
+            endpoint: endpoint.to_string(),
+        };
+        let credentials = Credentials {
+            access_key: Some(access_key.to_string()),
+            secret_key: Some(secret_key.to_string()),
+            security_token: None,
+            session_token: None,
+            expiration: None,
+        };
+
+        //使用path style访问,兼容minio
+        let bucket = Bucket::new(bucket_name, region, credentials)
+            .map_err(storage_error)?
+            .with_path_style();
+        Ok(S3Storage { bucket })
+    }
+
+    /*
+     *根据环境变量创建(S3_ENDPOINT、S3_REGION、S3_BUCKET、S3_ACCESS_KEY、S3_SECRET_KEY)
+     */
+    pub fn from_env() -> AppResult<S3Storage> {
+        let var = |name: &str| {
+            env::var(name).map_err(|_| AppError::storage(format!("{} is not set", name)))
+        };
+        S3Storage::new(
+            &var("S3_ENDPOINT")?,
+            &env::var("S3_REGION").unwrap_or("us-east-1".to_string()),
+            &var("S3_BUCKET")?,
+            &var("S3_ACCESS_KEY")?,
+            &var("S3_SECRET_KEY")?,
+        )
+    }
+}
+
+fn storage_error(e: S3Error) -> AppError {
+    AppError::storage(e)
+}
+
+#[rocket::async_trait]
+impl FileStorage for S3Storage {
+    fn storage_type(&self) -> &'static str {
+        "s3"
+    }
+
+    async fn put(&self, key: &str, data: &[u8], content_type: &str) -> AppResult<()> {
+        self.bucket
+            .put_object_with_content_type(key, data, content_type)
+            .await
+            .map_err(storage_error)?;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> AppResult<Vec<u8>> {
+        let resp = self.bucket.get_object(key).await.map_err(storage_error)?;
+        Ok(resp.to_vec())
+    }
+
+    async fn delete(&self, key: &str) -> AppResult<()> {
+        self.bucket
+            .delete_object(key)
+            .await
+            .map_err(storage_error)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+use crate::common::request_context;
+use opentelemetry::trace::TracerProvider;
+use opentelemetry::{global, propagation::Extractor};
+use opentelemetry_otlp::SpanExporter;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use opentelemetry_sdk::Resource;
+use rbatis::executor::Executor;
+use rbatis::intercept::{Intercept, ResultType};
+use rbatis::rbdc::db::ExecResult;
+use rbatis::Error;
+use rbs::Value;
+use rocket::http::HeaderMap;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::env;
+use std::sync::Mutex;
+use tracing::field::Empty;
+use tracing::{Level, Span};
+use tracing_subscriber::filter::Targets;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::Layer;
+
+//sql语句在span中的最大长度
+const MAX_STATEMENT_LEN: usize = 2000;
+
+/*
+ *初始化OpenTelemetry(配置了OTEL_EXPORTER_OTLP_ENDPOINT或OTEL_EXPORTER_OTLP_TRACES_ENDPOINT时启用,通过OTLP/HTTP导出)
+ *author：刘飞华
+ *date：2026/10/19 22:31:26
+ */
+pub fn init_telemetry() -> Option<SdkTracerProvider> {
+    if env::var("OTEL_EXPORTER_OTLP_ENDPOINT").is_err()
+        && env::var("OTEL_EXPORTER_OTLP_TRACES_ENDPOINT").is_err()
+    {
+        log::info!("opentelemetry is disabled, OTEL_EXPORTER_OTLP_ENDPOINT is not set");
+        return None;
+    }
+
+    let exporter = match SpanExporter::builder().with_http().build() {
+        Ok(x) => x,
+        Err(e) => {
+            log::error!("create otlp exporter error: {}", e);
+            return None;
+        }
+    };
+    let service_name =
+        env::var("OTEL_SERVICE_NAME").unwrap_or_else(|_| env!("CARGO_PKG_NAME").to_string());
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(Resource::builder().with_service_name(service_name).build())
+        .build();
+
+    let tracer = provider.tracer(env!("CARGO_PKG_NAME"));
+    //只导出本项目的span(hyper等依赖库的span太多)
+    let layer = tracing_opentelemetry::layer()
+        .with_tracer(tracer)
+        .with_filter(Targets::new().with_target(env!("CARGO_CRATE_NAME"), Level::INFO));
+    let subscriber = tracing_subscriber::registry().with(layer);
+    if let Err(e) = tracing::subscriber::set_global_default(subscriber) {
+        log::error!("set tracing subscriber error: {}", e);
+        return None;
+    }
+    global::set_text_map_propagator(TraceContextPropagator::new());
+    global::set_tracer_provider(provider.clone());
+    log::info!("opentelemetry is enabled");
+    Some(provider)
+}
+
+/*
+ *请求头提取器(读取上游传入的traceparent)
+ *author：刘飞华
+ *date：2026/10/19 22:31:26
+ */
+pub struct HeaderExtractor<'a>(pub &'a HeaderMap<'a>);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get_one(key)
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0
+            .iter()
+            .filter_map(|x| match x.name.into_cow() {
+                Cow::Borrowed(name) => Some(name),
+                Cow::Owned(_) => None,
+            })
+            .collect()
+    }
+}
+
+/*
+ *sql执行链路(每条sql一个span,父span为当前请求的span)
+ *author：刘飞华
+ *date：2026/10/19 22:31:26
+ */
+#[derive(Debug, Default)]
+pub struct SqlTraceIntercept {
+    spans: Mutex<HashMap<i64, Span>>, //正在执行的sql(rbatis task_id -> span)
+}
+
+#[rbatis::async_trait]
+impl Intercept for SqlTraceIntercept {
+    async fn before(
+        &self,
+        task_id: i64,
+        _rb: &dyn Executor,
+        sql: &mut String,
+        _args: &mut Vec<Value>,
+        result: ResultType<&mut Result<ExecResult, Error>, &mut Result<Vec<Value>, Error>>,
+    ) -> Result<Option<bool>, Error> {
+        let parent = request_context::current().and_then(|x| x.span);
+        let statement = sql.chars().take(MAX_STATEMENT_LEN).collect::<String>();
+        let span = tracing::info_span!(
+            parent: parent.as_ref().and_then(|x| x.id()),
+            "sql",
+            otel.name = %format!("db {}", result.type_name()),
+            otel.kind = "client",
+            otel.status_code = Empty,
+            db.system = "mysql",
+            db.statement = %statement,
+            db.rows_affected = Empty,
+            error.message = Empty,
+        );
+        if let Ok(mut spans) = self.spans.lock() {
+            spans.insert(task_id, span);
+        }
+        Ok(Some(true))
+    }
+
+    async fn after(
+        &self,
+        task_id: i64,
+        _rb: &dyn Executor,
+        _sql: &mut String,
+        _args: &mut Vec<Value>,
+        result: ResultType<&mut Result<ExecResult, Error>, &mut Result<Vec<Value>, Error>>,
+    ) -> Result<Option<bool>, Error> {
+        let span = match self.spans.lock() {
+            Ok(mut spans) => spans.remove(&task_id),
+            Err(_) => None,
+        };
+        if let Some(span) = span {
+            let error = match result {
+                ResultType::Exec(Ok(x)) => {
+                    span.record("db.rows_affected", x.rows_affected);
+                    None
+                }
+                ResultType::Query(Ok(x)) => {
+                    span.record("db.rows_affected", x.len() as u64);
+                    None
+                }
+                ResultType::Exec(Err(e)) | ResultType::Query(Err(e)) => Some(e.to_string()),
+            };
+            if let Some(e) = error {
+                span.record("otel.status_code", "ERROR");
+                span.record("error.message", e);
+            }
+        }
+        Ok(Some(true))
+    }
+}
@@ -0,0 +1,105 @@
+use rocket::http::Status;
+use rocket::serde::json::serde_json::json;
+use rocket::serde::json::Value;
+use rocket::tokio::time::{timeout, Duration};
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+use crate::vo::monitor::health_vo::*;
+use crate::RB;
+
+//健康状态：正常
+const STATUS_UP: &str = "UP";
+//健康状态：异常
+const STATUS_DOWN: &str = "DOWN";
+//依赖检查超时时间
+const CHECK_TIMEOUT: Duration = Duration::from_secs(3);
+
+/*
+ *存活检查(进程能响应请求即为存活,不检查依赖)
+ *author：刘飞华
+ *date：2026/10/19 21:16:32
+ */
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    tag = "健康检查",
+    summary = "存活检查(进程能响应请求即为存活,不检查依赖)",
+    responses((status = 200, description = "服务存活", body = Object))
+)]
+#[get("/health/live")]
+pub fn health_live() -> Value {
+    json!({"status": STATUS_UP})
+}
+
+/*
+ *就绪检查(检查各依赖是否可用,有依赖不可用时返回503)
+ *author：刘飞华
+ *date：2026/10/19 21:16:32
+ */
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "健康检查",
+    summary = "就绪检查(检查各依赖是否可用,有依赖不可用时返回503)",
+    responses(
+        (status = 200, description = "依赖全部可用", body = HealthResp),
+        (status = 503, description = "有依赖不可用", body = HealthResp),
+    )
+)]
+#[get("/health/ready")]
+pub async fn health_ready() -> (Status, Value) {
+    let mut checks = BTreeMap::new();
+    checks.insert("database".to_string(), check_database().await);
+
+    let up = checks.values().all(|x| x.status == STATUS_UP);
+    if !up {
+        log::error!("health check failed: {:?}", checks);
+    }
+
+    let resp = HealthResp {
+        status: if up { STATUS_UP } else { STATUS_DOWN }.to_string(), //整体状态
+        checks,                                                       //各依赖的检查结果
+    };
+    let status = if up {
+        Status::Ok
+    } else {
+        Status::ServiceUnavailable
+    };
+    (status, json!(resp))
+}
+
+/*
+ *检查数据库(执行一条简单查询)
+ *author：刘飞华
+ *date：2026/10/19 21:16:32
+ */
+async fn check_database() -> DependencyHealth {
+    let instant = Instant::now();
+    let error = match timeout(CHECK_TIMEOUT, RB.query("select 1", vec![])).await {
+        Ok(Ok(_)) => None,
+        //接口不需要登录,详细的错误信息只写日志
+        Ok(Err(e)) => {
+            log::error!("health check database error: {}", e);
+            Some("unavailable".to_string())
+        }
+        Err(_) => {
+            log::error!(
+                "health check database timeout after {}s",
+                CHECK_TIMEOUT.as_secs()
+            );
+            Some("timeout".to_string())
+        }
+    };
+
+    DependencyHealth {
+        status: if error.is_none() {
+            STATUS_UP
+        } else {
+            STATUS_DOWN
+        }
+        .to_string(), //状态
+        latency_ms: instant.elapsed().as_millis() as u64, //耗时(毫秒)
+        error,                                            //错误信息
+    }
+}
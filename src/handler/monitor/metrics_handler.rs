@@ -0,0 +1,22 @@
+use rocket::http::ContentType;
+
+use crate::common::metrics::METRICS;
+
+/*
+ *Prometheus指标(文本格式)
+ *author：刘飞华
+ *date：2026/10/19 21:40:05
+ */
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "健康检查",
+    summary = "Prometheus指标(文本格式)",
+    responses((status = 200, description = "Prometheus文本格式", content_type = "text/plain", body = String))
+)]
+#[get("/metrics")]
+pub async fn query_metrics() -> (ContentType, String) {
+    let content_type =
+        ContentType::new("text", "plain").with_params([("version", "0.0.4"), ("charset", "utf-8")]);
+    (content_type, METRICS.render().await)
+}
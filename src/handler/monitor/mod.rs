@@ -0,0 +1,3 @@
+pub mod health_handler;
+pub mod metrics_handler;
+pub mod server_handler;
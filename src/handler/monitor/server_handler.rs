@@ -0,0 +1,157 @@
+use chrono::{Local, TimeZone};
+use rbs::Value as RbsValue;
+use rocket::serde::json::Value;
+use rocket::tokio::time::sleep;
+use sysinfo::{Disks, ProcessesToUpdate, System, MINIMUM_CPU_UPDATE_INTERVAL};
+
+use crate::common::error::AppResult;
+use crate::common::result::{ok_result_data, BaseResponse};
+use crate::middleware::auth::Token;
+use crate::vo::monitor::server_vo::*;
+use crate::RB;
+
+/*
+ *查询服务监控信息(主机、进程、运行环境和数据库连接池)
+ *author：刘飞华
+ *date：2026/10/19 20:48:10
+ */
+#[utoipa::path(
+    get,
+    path = "/api/monitor/server",
+    tag = "服务监控",
+    summary = "查询服务监控信息(主机、进程、运行环境和数据库连接池)",
+    responses((status = 200, description = "操作成功", body = BaseResponse<ServerInfoResp>)),
+    security(("token" = []))
+)]
+#[get("/monitor/server")]
+pub async fn query_server_info(_auth: Token) -> AppResult<Value> {
+    log::info!("query server info");
+
+    let pid = sysinfo::get_current_pid().ok();
+    let mut sys = System::new();
+    //cpu使用率需要间隔一段时间采样两次
+    sys.refresh_cpu_usage();
+    if let Some(pid) = pid {
+        sys.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+    }
+    sleep(MINIMUM_CPU_UPDATE_INTERVAL).await;
+    sys.refresh_cpu_usage();
+    sys.refresh_memory();
+    if let Some(pid) = pid {
+        sys.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+    }
+
+    let cpu = CpuInfo {
+        brand: sys
+            .cpus()
+            .first()
+            .map(|x| x.brand().to_string())
+            .unwrap_or_default(), //CPU型号
+        cpu_num: sys.cpus().len(), //逻辑核数
+        physical_num: System::physical_core_count().unwrap_or_default(), //物理核数
+        usage: (sys.global_cpu_usage() * 100.0).round() / 100.0, //使用率(%)
+    };
+
+    let memory = MemoryInfo {
+        total: sys.total_memory(),                             //总内存
+        used: sys.used_memory(),                               //已用内存
+        free: sys.available_memory(),                          //可用内存
+        usage: percent(sys.used_memory(), sys.total_memory()), //使用率(%)
+        swap_total: sys.total_swap(),                          //交换区总量
+        swap_used: sys.used_swap(),                            //交换区已用
+    };
+
+    let load_avg = System::load_average();
+    let load = LoadInfo {
+        one: load_avg.one,         //1分钟平均负载
+        five: load_avg.five,       //5分钟平均负载
+        fifteen: load_avg.fifteen, //15分钟平均负载
+    };
+
+    let disks = Disks::new_with_refreshed_list()
+        .iter()
+        .map(|x| {
+            let used = x.total_space().saturating_sub(x.available_space());
+            DiskInfo {
+                name: x.name().to_string_lossy().to_string(), //磁盘名称
+                mount_point: x.mount_point().to_string_lossy().to_string(), //挂载点
+                file_system: x.file_system().to_string_lossy().to_string(), //文件系统
+                total: x.total_space(),                       //总大小
+                used,                                         //已用大小
+                free: x.available_space(),                    //可用大小
+                usage: percent(used, x.total_space()),        //使用率(%)
+            }
+        })
+        .collect::<Vec<DiskInfo>>();
+
+    let host = HostInfo {
+        host_name: System::host_name().unwrap_or_default(), //主机名
+        os_name: System::name().unwrap_or_default(),        //操作系统
+        os_version: System::os_version().unwrap_or_default(), //系统版本
+        kernel_version: System::kernel_version().unwrap_or_default(), //内核版本
+        arch: System::cpu_arch(),                           //系统架构
+        uptime: System::uptime(),                           //系统运行时长(秒)
+    };
+
+    let current = pid.and_then(|x| sys.process(x));
+    let process = ProcessInfo {
+        pid: pid.map(|x| x.as_u32()).unwrap_or_default(), //进程ID
+        start_time: current
+            .and_then(|x| Local.timestamp_opt(x.start_time() as i64, 0).single())
+            .map(|x| x.format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_default(), //启动时间
+        run_time: current.map(|x| x.run_time()).unwrap_or_default(), //运行时长(秒)
+        memory: current.map(|x| x.memory()).unwrap_or_default(), //占用内存(字节)
+        virtual_memory: current.map(|x| x.virtual_memory()).unwrap_or_default(), //虚拟内存(字节)
+        cpu_usage: current.map(|x| x.cpu_usage()).unwrap_or_default(), //CPU使用率(%)
+    };
+
+    let runtime = RuntimeInfo {
+        app_version: env!("CARGO_PKG_VERSION").to_string(), //应用版本
+        rust_version: env!("RUSTC_VERSION").to_string(),    //rustc版本
+        rocket_version: env!("ROCKET_VERSION").to_string(), //rocket版本
+    };
+
+    let state = RB.get_pool()?.state().await;
+    let db_pool = DbPoolInfo {
+        max_open: pool_state(&state, "max_open"),       //最大连接数
+        connections: pool_state(&state, "connections"), //当前连接数
+        in_use: pool_state(&state, "in_use"),           //使用中的连接数
+        idle: pool_state(&state, "idle"),               //空闲连接数
+        waits: pool_state(&state, "waits"),             //等待获取连接的数量
+        connecting: pool_state(&state, "connecting"),   //正在建立的连接数
+        checking: pool_state(&state, "checking"),       //正在检查的连接数
+    };
+
+    ok_result_data(ServerInfoResp {
+        cpu,     //CPU
+        memory,  //内存
+        load,    //系统负载
+        disks,   //磁盘
+        host,    //主机
+        process, //当前进程
+        runtime, //运行环境
+        db_pool, //数据库连接池
+    })
+}
+
+/*
+ *计算百分比(保留两位小数)
+ *author：刘飞华
+ *date：2026/10/19 20:48:10
+ */
+fn percent(used: u64, total: u64) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    (used as f64 * 10000.0 / total as f64).round() / 100.0
+}
+
+/*
+ *读取连接池状态字段
+ *author：刘飞华
+ *date：2026/10/19 20:48:10
+ */
+fn pool_state(state: &RbsValue, key: &str) -> u64 {
+    state[key].as_u64().unwrap_or_default()
+}
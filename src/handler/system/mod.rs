@@ -1,6 +1,10 @@
+pub mod sys_config_handler;
 pub mod sys_dept_handler;
 pub mod sys_dict_data_handler;
 pub mod sys_dict_type_handler;
+pub mod sys_file_handler;
+pub mod sys_job_handler;
+pub mod sys_job_log_handler;
 pub mod sys_login_log_handler;
 pub mod sys_menu_handler;
 pub mod sys_notice_handler;
@@ -8,5 +12,6 @@ pub mod sys_operate_log_handler;
 pub mod sys_post_handler;
 pub mod sys_role_handler;
 pub mod sys_user_handler;
+pub mod sys_user_import_handler;
 
 
@@ -0,0 +1,288 @@
+use rbatis::plugin::page::PageRequest;
+use rocket::serde::json::{Json, Value};
+
+use crate::common::config_cache::{check_config_value, SYS_CONFIG};
+use crate::common::error::AppResult;
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{
+    ok_result, ok_result_data, ok_result_page, BaseResponse, ResponsePage,
+};
+use crate::middleware::auth::Token;
+use crate::model::system::sys_config_model::SysConfig;
+use crate::utils::sort_util::order_by;
+use crate::utils::time_util::{check_time_range, time_to_string};
+use crate::vo::system::sys_config_vo::*;
+use crate::RB;
+use rbs::value;
+/*
+ *添加参数配置表
+ *author：刘飞华
+ *date：2026/10/19 19:20:14
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/config/addConfig",
+    tag = "参数设置",
+    summary = "添加参数配置表",
+    request_body = AddConfigReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
+#[post("/system/config/addConfig", data = "<item>")]
+pub async fn add_sys_config(item: Json<AddConfigReq>, _auth: Token) -> AppResult<Value> {
+    log::info!("add sys_config params: {:?}", &item);
+    let rb = &mut RB.clone();
+    let req = item.0;
+
+    check_config_value(&req.value_type, &req.config_value)?;
+
+    if SysConfig::select_by_config_key(rb, &req.config_key)
+        .await?
+        .is_some()
+    {
+        return Err(ErrorCode::ConfigKeyExists.into());
+    }
+
+    let sys_config = SysConfig {
+        id: None,                               //参数主键
+        config_name: req.config_name,           //参数名称
+        config_key: req.config_key,             //参数键名
+        config_value: req.config_value,         //参数键值
+        value_type: req.value_type,             //值类型
+        is_builtin: "N".to_string(),            //是否内置（Y是 N否）
+        remark: req.remark.unwrap_or_default(), //备注
+        create_time: None,                      //创建时间
+        update_time: None,                      //修改时间
+    };
+
+    SysConfig::insert(rb, &sys_config).await?;
+    SYS_CONFIG.refresh().await;
+
+    ok_result()
+}
+
+/*
+ *删除参数配置表
+ *author：刘飞华
+ *date：2026/10/19 19:20:14
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/config/deleteConfig",
+    tag = "参数设置",
+    summary = "删除参数配置表",
+    request_body = DeleteConfigReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
+#[post("/system/config/deleteConfig", data = "<item>")]
+pub async fn delete_sys_config(item: Json<DeleteConfigReq>, _auth: Token) -> AppResult<Value> {
+    log::info!("delete sys_config params: {:?}", &item);
+    let rb = &mut RB.clone();
+
+    for x in SysConfig::select_by_map(rb, value! {"id": &item.ids}).await? {
+        if x.is_builtin == "Y" {
+            return Err(ErrorCode::ConfigBuiltinDelete.into());
+        }
+    }
+
+    SysConfig::delete_by_map(rb, value! {"id": &item.ids}).await?;
+    SYS_CONFIG.refresh().await;
+
+    ok_result()
+}
+
+/*
+ *更新参数配置表
+ *author：刘飞华
+ *date：2026/10/19 19:20:14
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/config/updateConfig",
+    tag = "参数设置",
+    summary = "更新参数配置表",
+    request_body = UpdateConfigReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
+#[post("/system/config/updateConfig", data = "<item>")]
+pub async fn update_sys_config(item: Json<UpdateConfigReq>, _auth: Token) -> AppResult<Value> {
+    log::info!("update sys_config params: {:?}", &item);
+    let rb = &mut RB.clone();
+    let req = item.0;
+
+    check_config_value(&req.value_type, &req.config_value)?;
+
+    let old = match SysConfig::select_by_id(rb, &req.id).await? {
+        None => return Err(ErrorCode::ConfigNotFound.into()),
+        Some(x) => x,
+    };
+
+    if old.is_builtin == "Y" && old.config_key != req.config_key {
+        return Err(ErrorCode::ConfigBuiltinKey.into());
+    }
+
+    if let Some(x) = SysConfig::select_by_config_key(rb, &req.config_key).await? {
+        if x.id.unwrap_or_default() != req.id {
+            return Err(ErrorCode::ConfigKeyExists.into());
+        }
+    }
+
+    let sys_config = SysConfig {
+        id: Some(req.id),                       //参数主键
+        config_name: req.config_name,           //参数名称
+        config_key: req.config_key,             //参数键名
+        config_value: req.config_value,         //参数键值
+        value_type: req.value_type,             //值类型
+        is_builtin: old.is_builtin,             //是否内置（Y是 N否）
+        remark: req.remark.unwrap_or_default(), //备注
+        create_time: None,                      //创建时间
+        update_time: None,                      //修改时间
+    };
+
+    SysConfig::update_by_map(rb, &sys_config, value! {"id": &sys_config.id}).await?;
+    SYS_CONFIG.refresh().await;
+
+    ok_result()
+}
+
+/*
+ *查询参数配置表详情
+ *author：刘飞华
+ *date：2026/10/19 19:20:14
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/config/queryConfigDetail",
+    tag = "参数设置",
+    summary = "查询参数配置表详情",
+    request_body = QueryConfigDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<QueryConfigDetailResp>)),
+    security(("token" = []))
+)]
+#[post("/system/config/queryConfigDetail", data = "<item>")]
+pub async fn query_sys_config_detail(
+    item: Json<QueryConfigDetailReq>,
+    _auth: Token,
+) -> AppResult<Value> {
+    log::info!("query sys_config_detail params: {:?}", &item);
+    let rb = &mut RB.clone();
+
+    match SysConfig::select_by_id(rb, &item.id).await? {
+        None => Err(ErrorCode::ConfigNotFound.into()),
+        Some(x) => {
+            let sys_config = QueryConfigDetailResp {
+                id: x.id.unwrap_or_default(),               //参数主键
+                config_name: x.config_name,                 //参数名称
+                config_key: x.config_key,                   //参数键名
+                config_value: x.config_value,               //参数键值
+                value_type: x.value_type,                   //值类型
+                is_builtin: x.is_builtin,                   //是否内置（Y是 N否）
+                remark: x.remark,                           //备注
+                create_time: time_to_string(x.create_time), //创建时间
+                update_time: time_to_string(x.update_time), //修改时间
+            };
+
+            ok_result_data(sys_config)
+        }
+    }
+}
+
+/*
+ *查询参数配置表列表
+ *author：刘飞华
+ *date：2026/10/19 19:20:14
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/config/queryConfigList",
+    tag = "参数设置",
+    summary = "查询参数配置表列表",
+    request_body = QueryConfigListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<ConfigListDataResp>>)),
+    security(("token" = []))
+)]
+#[post("/system/config/queryConfigList", data = "<item>")]
+pub async fn query_sys_config_list(
+    item: Json<QueryConfigListReq>,
+    _auth: Token,
+) -> AppResult<Value> {
+    log::info!("query sys_config_list params: {:?}", &item);
+    let rb = &mut RB.clone();
+
+    let config_name = item.config_name.as_deref().unwrap_or_default(); //参数名称
+    let config_key = item.config_key.as_deref().unwrap_or_default(); //参数键名
+    let value_type = item.value_type.as_deref().unwrap_or_default(); //值类型
+
+    let keyword = item.keyword.as_deref().unwrap_or_default().trim(); //关键字(模糊搜索)
+    let begin_time = item.begin_time.as_deref().unwrap_or_default(); //创建时间(开始)
+    let end_time = item.end_time.as_deref().unwrap_or_default(); //创建时间(结束)
+    check_time_range(begin_time, end_time)?;
+
+    let order_by = order_by(
+        item.sort_field.as_deref(),
+        item.sort_order.as_deref(),
+        &[
+            ("id", "id"),
+            ("configName", "config_name"),
+            ("configKey", "config_key"),
+            ("createTime", "create_time"),
+        ],
+        "create_time desc",
+    );
+
+    let page = &PageRequest::new(item.page_no, item.page_size);
+    let d = SysConfig::select_sys_config_list(
+        rb,
+        page,
+        config_name,
+        config_key,
+        value_type,
+        keyword,
+        begin_time,
+        end_time,
+        &order_by,
+    )
+    .await?;
+
+    let mut list: Vec<ConfigListDataResp> = Vec::new();
+
+    let total = d.total;
+
+    for x in d.records {
+        list.push(ConfigListDataResp {
+            id: x.id.unwrap_or_default(),               //参数主键
+            config_name: x.config_name,                 //参数名称
+            config_key: x.config_key,                   //参数键名
+            config_value: x.config_value,               //参数键值
+            value_type: x.value_type,                   //值类型
+            is_builtin: x.is_builtin,                   //是否内置（Y是 N否）
+            remark: x.remark,                           //备注
+            create_time: time_to_string(x.create_time), //创建时间
+            update_time: time_to_string(x.update_time), //修改时间
+        })
+    }
+
+    ok_result_page(list, total)
+}
+
+/*
+ *刷新参数缓存(直接修改数据库后使用)
+ *author：刘飞华
+ *date：2026/10/19 19:20:14
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/config/refreshConfigCache",
+    tag = "参数设置",
+    summary = "刷新参数缓存(直接修改数据库后使用)",
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
+#[post("/system/config/refreshConfigCache")]
+pub async fn refresh_sys_config_cache(_auth: Token) -> AppResult<Value> {
+    log::info!("refresh sys_config cache");
+    SYS_CONFIG.reload().await?;
+    ok_result()
+}
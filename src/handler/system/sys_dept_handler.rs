@@ -1,6 +1,8 @@
-use crate::common::error::{AppError, AppResult};
-use crate::common::result::{ok_result, ok_result_data};
+use crate::common::error::AppResult;
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{ok_result, ok_result_data, BaseResponse};
 use crate::middleware::auth::Token;
+use crate::middleware::validate::ValidJson;
 use crate::model::system::sys_dept_model::{
     check_dept_exist_user, select_children_dept_by_id, select_dept_count,
     select_normal_children_dept_by_id, Dept,
@@ -16,22 +18,31 @@ use rocket::serde::json::{Json, Value};
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/dept/addDept",
+    tag = "部门管理",
+    summary = "添加部门表",
+    request_body = AddDeptReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/dept/addDept", data = "<item>")]
-pub async fn add_sys_dept(item: Json<AddDeptReq>, _auth: Token) -> AppResult<Value> {
+pub async fn add_sys_dept(item: ValidJson<AddDeptReq>, _auth: Token) -> AppResult<Value> {
     log::info!("add sys_dept params: {:?}", &item);
     let rb = &mut RB.clone();
     let req = item.0;
 
     let res = Dept::select_by_dept_name(rb, &req.dept_name, req.parent_id).await?;
     if res.is_some() {
-        return Err(AppError::BusinessError("部门名称已存在"));
+        return Err(ErrorCode::DeptNameExists.into());
     }
 
     match Dept::select_by_id(rb, &req.parent_id).await? {
-        None => Err(AppError::BusinessError("添加失败,上级部门不存在")),
+        None => Err(ErrorCode::DeptAddParentNotFound.into()),
         Some(dept) => {
             if dept.status == 0 {
-                return Err(AppError::BusinessError("部门停用，不允许添加"));
+                return Err(ErrorCode::DeptParentDisabled.into());
             }
             let ancestors = format!("{},{}", dept.ancestors, &req.parent_id);
             let sys_dept = Dept {
@@ -60,6 +71,15 @@ pub async fn add_sys_dept(item: Json<AddDeptReq>, _auth: Token) -> AppResult<Val
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/dept/deleteDept",
+    tag = "部门管理",
+    summary = "删除部门表",
+    request_body = DeleteDeptReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/dept/deleteDept", data = "<item>")]
 pub async fn delete_sys_dept(item: Json<DeleteDeptReq>, _auth: Token) -> AppResult<Value> {
     log::info!("delete sys_dept params: {:?}", &item);
@@ -68,12 +88,12 @@ pub async fn delete_sys_dept(item: Json<DeleteDeptReq>, _auth: Token) -> AppResu
 
     let res = select_dept_count(rb, &req.id).await.unwrap_or_default();
     if res > 0 {
-        return Err(AppError::BusinessError("存在下级部门,不允许删除"));
+        return Err(ErrorCode::DeptHasChildren.into());
     }
 
     let res1 = check_dept_exist_user(rb, &req.id).await.unwrap_or_default();
     if res1 > 0 {
-        return Err(AppError::BusinessError("部门存在用户,不允许删除"));
+        return Err(ErrorCode::DeptHasUsers.into());
     }
 
     Dept::delete_by_map(rb, value! {"id": &req.id}).await?;
@@ -85,23 +105,32 @@ pub async fn delete_sys_dept(item: Json<DeleteDeptReq>, _auth: Token) -> AppResu
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/dept/updateDept",
+    tag = "部门管理",
+    summary = "更新部门表",
+    request_body = UpdateDeptReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/dept/updateDept", data = "<item>")]
-pub async fn update_sys_dept(item: Json<UpdateDeptReq>, _auth: Token) -> AppResult<Value> {
+pub async fn update_sys_dept(item: ValidJson<UpdateDeptReq>, _auth: Token) -> AppResult<Value> {
     log::info!("update sys_dept params: {:?}", &item);
     let rb = &mut RB.clone();
     let req = item.0;
 
     if req.parent_id == req.id {
-        return Err(AppError::BusinessError("上级部门不能是自己"));
+        return Err(ErrorCode::DeptParentSelf.into());
     }
 
     let old_ancestors = match Dept::select_by_id(rb, &req.id).await? {
-        None => return Err(AppError::BusinessError("部门不存在")),
+        None => return Err(ErrorCode::DeptNotFound.into()),
         Some(dept) => dept.ancestors,
     };
 
     let ancestors = match Dept::select_by_id(rb, &req.parent_id).await? {
-        None => return Err(AppError::BusinessError("上级部门不存在")),
+        None => return Err(ErrorCode::DeptParentNotFound.into()),
         Some(dept) => {
             format!("{},{}", dept.ancestors, &req.parent_id)
         }
@@ -109,12 +138,12 @@ pub async fn update_sys_dept(item: Json<UpdateDeptReq>, _auth: Token) -> AppResu
 
     if let Some(dept) = Dept::select_by_dept_name(rb, &req.dept_name, req.parent_id).await? {
         if dept.id.unwrap_or_default() != req.id {
-            return Err(AppError::BusinessError("部门名称已存在"));
+            return Err(ErrorCode::DeptNameExists.into());
         }
     }
 
     if select_normal_children_dept_by_id(rb, &req.id).await? > 0 && req.status == 0 {
-        return Err(AppError::BusinessError("该部门包含未停用的子部门"));
+        return Err(ErrorCode::DeptHasEnabledChildren.into());
     }
 
     for mut x in select_children_dept_by_id(rb, &req.id).await? {
@@ -164,9 +193,18 @@ pub async fn update_sys_dept(item: Json<UpdateDeptReq>, _auth: Token) -> AppResu
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/dept/updateDeptStatus",
+    tag = "部门管理",
+    summary = "更新部门表状态",
+    request_body = UpdateDeptStatusReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/dept/updateDeptStatus", data = "<item>")]
 pub async fn update_sys_dept_status(
-    item: Json<UpdateDeptStatusReq>,
+    item: ValidJson<UpdateDeptStatusReq>,
     _auth: Token,
 ) -> AppResult<Value> {
     log::info!("update sys_dept_status params: {:?}", &item);
@@ -211,6 +249,15 @@ pub async fn update_sys_dept_status(
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/dept/queryDeptDetail",
+    tag = "部门管理",
+    summary = "查询部门表详情",
+    request_body = QueryDeptDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<QueryDeptDetailResp>)),
+    security(("token" = []))
+)]
 #[post("/system/dept/queryDeptDetail", data = "<item>")]
 pub async fn query_sys_dept_detail(
     item: Json<QueryDeptDetailReq>,
@@ -219,7 +266,7 @@ pub async fn query_sys_dept_detail(
     log::info!("query sys_dept_detail params: {:?}", &item);
 
     match Dept::select_by_id(&mut RB.clone(), &item.id).await? {
-        None => Err(AppError::BusinessError("部门不存在")),
+        None => Err(ErrorCode::DeptNotFound.into()),
         Some(x) => {
             let sys_dept = QueryDeptDetailResp {
                 id: x.id.unwrap_or_default(),               //部门id
@@ -246,6 +293,15 @@ pub async fn query_sys_dept_detail(
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/dept/queryDeptList",
+    tag = "部门管理",
+    summary = "查询部门表列表",
+    request_body = QueryDeptListReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<Vec<DeptListDataResp>>)),
+    security(("token" = []))
+)]
 #[post("/system/dept/queryDeptList", data = "<item>")]
 pub async fn query_sys_dept_list(item: Json<QueryDeptListReq>, _auth: Token) -> AppResult<Value> {
     log::info!("query sys_dept_list params: {:?}", &item);
@@ -1,21 +1,39 @@
 use rbatis::plugin::page::PageRequest;
 use rocket::serde::json::{Json, Value};
 
-use crate::common::error::{AppError, AppResult};
-use crate::common::result::{ok_result, ok_result_data, ok_result_page};
+use crate::common::dict_cache::DICT_CACHE;
+use crate::common::error::AppResult;
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{
+    ok_result, ok_result_data, ok_result_etag, ok_result_page, BaseResponse, EtagResponse,
+    ResponsePage,
+};
 use crate::middleware::auth::Token;
-use crate::model::system::sys_dict_data_model::DictData;
-use crate::utils::time_util::time_to_string;
+use crate::middleware::etag::IfNoneMatch;
+use crate::middleware::validate::ValidJson;
+use crate::model::system::sys_dict_data_model::{select_enabled_by_dict_type, DictData};
+use crate::utils::sort_util::order_by;
+use crate::utils::time_util::{check_time_range, time_to_string};
 use crate::vo::system::sys_dict_data_vo::*;
 use crate::RB;
 use rbs::value;
+use std::collections::BTreeMap;
 /*
  *添加字典数据表
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/dictData/addDictData",
+    tag = "字典数据",
+    summary = "添加字典数据表",
+    request_body = AddDictDataReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/dictData/addDictData", data = "<item>")]
-pub async fn add_sys_dict_data(item: Json<AddDictDataReq>, _auth: Token) -> AppResult<Value> {
+pub async fn add_sys_dict_data(item: ValidJson<AddDictDataReq>, _auth: Token) -> AppResult<Value> {
     log::info!("add sys_dict_data params: {:?}", &item);
     let rb = &mut RB.clone();
     let req = item.0;
@@ -24,14 +42,14 @@ pub async fn add_sys_dict_data(item: Json<AddDictDataReq>, _auth: Token) -> AppR
         .await?
         .is_some()
     {
-        return Err(AppError::BusinessError("新增字典数据失败,字典标签已存在"));
+        return Err(ErrorCode::DictDataAddLabelExists.into());
     }
 
     if DictData::select_by_dict_value(rb, &req.dict_type, &req.dict_value)
         .await?
         .is_some()
     {
-        return Err(AppError::BusinessError("新增字典数据失败,字典键值已存在"));
+        return Err(ErrorCode::DictDataAddValueExists.into());
     }
 
     let sys_dict_data = DictData {
@@ -50,6 +68,7 @@ pub async fn add_sys_dict_data(item: Json<AddDictDataReq>, _auth: Token) -> AppR
     };
 
     DictData::insert(rb, &sys_dict_data).await?;
+    DICT_CACHE.invalidate_all();
     ok_result()
 }
 
@@ -58,12 +77,22 @@ pub async fn add_sys_dict_data(item: Json<AddDictDataReq>, _auth: Token) -> AppR
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/dictData/deleteDictData",
+    tag = "字典数据",
+    summary = "删除字典数据表",
+    request_body = DeleteDictDataReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/dictData/deleteDictData", data = "<item>")]
 pub async fn delete_sys_dict_data(item: Json<DeleteDictDataReq>, _auth: Token) -> AppResult<Value> {
     log::info!("delete sys_dict_data params: {:?}", &item);
     let rb = &mut RB.clone();
 
     DictData::delete_by_map(rb, value! {"id": &item.ids}).await?;
+    DICT_CACHE.invalidate_all();
     ok_result()
 }
 
@@ -72,25 +101,37 @@ pub async fn delete_sys_dict_data(item: Json<DeleteDictDataReq>, _auth: Token) -
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/dictData/updateDictData",
+    tag = "字典数据",
+    summary = "更新字典数据表",
+    request_body = UpdateDictDataReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/dictData/updateDictData", data = "<item>")]
-pub async fn update_sys_dict_data(item: Json<UpdateDictDataReq>, _auth: Token) -> AppResult<Value> {
+pub async fn update_sys_dict_data(
+    item: ValidJson<UpdateDictDataReq>,
+    _auth: Token,
+) -> AppResult<Value> {
     log::info!("update sys_dict_data params: {:?}", &item);
     let rb = &mut RB.clone();
     let req = item.0;
 
     if DictData::select_by_id(rb, &req.id).await?.is_none() {
-        return Err(AppError::BusinessError("更新字典数据失败,字典数据不存在"));
+        return Err(ErrorCode::DictDataUpdateNotFound.into());
     }
 
     if let Some(x) = DictData::select_by_dict_label(rb, &req.dict_type, &req.dict_label).await? {
         if x.id.unwrap_or_default() != req.id {
-            return Err(AppError::BusinessError("更新字典数据失败,字典标签已存在"));
+            return Err(ErrorCode::DictDataUpdateLabelExists.into());
         }
     }
 
     if let Some(x) = DictData::select_by_dict_value(rb, &req.dict_type, &req.dict_value).await? {
         if x.id.unwrap_or_default() != req.id {
-            return Err(AppError::BusinessError("更新字典数据失败,字典键值已存在"));
+            return Err(ErrorCode::DictDataUpdateValueExists.into());
         }
     }
 
@@ -110,6 +151,7 @@ pub async fn update_sys_dict_data(item: Json<UpdateDictDataReq>, _auth: Token) -
     };
 
     DictData::update_by_map(rb, &sys_dict_data, value! {"id": &sys_dict_data.id}).await?;
+    DICT_CACHE.invalidate_all();
     ok_result()
 }
 
@@ -118,9 +160,18 @@ pub async fn update_sys_dict_data(item: Json<UpdateDictDataReq>, _auth: Token) -
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/dictData/updateDictDataStatus",
+    tag = "字典数据",
+    summary = "更新字典数据表状态",
+    request_body = UpdateDictDataStatusReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/dictData/updateDictDataStatus", data = "<item>")]
 pub async fn update_sys_dict_data_status(
-    item: Json<UpdateDictDataStatusReq>,
+    item: ValidJson<UpdateDictDataStatusReq>,
     _auth: Token,
 ) -> AppResult<Value> {
     log::info!("update sys_dict_data_status params: {:?}", &item);
@@ -139,6 +190,7 @@ pub async fn update_sys_dict_data_status(
     let mut param = vec![value!(req.status)];
     param.extend(req.ids.iter().map(|&id| value!(id)));
     rb.exec(&update_sql, param).await?;
+    DICT_CACHE.invalidate_all();
     ok_result()
 }
 
@@ -147,6 +199,15 @@ pub async fn update_sys_dict_data_status(
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/dictData/queryDictDataDetail",
+    tag = "字典数据",
+    summary = "查询字典数据表详情",
+    request_body = QueryDictDataDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<QueryDictDataDetailResp>)),
+    security(("token" = []))
+)]
 #[post("/system/dictData/queryDictDataDetail", data = "<item>")]
 pub async fn query_sys_dict_data_detail(
     item: Json<QueryDictDataDetailReq>,
@@ -156,7 +217,7 @@ pub async fn query_sys_dict_data_detail(
     let rb = &mut RB.clone();
 
     match DictData::select_by_id(rb, &item.id).await? {
-        None => Err(AppError::BusinessError("字典数据不存在")),
+        None => Err(ErrorCode::DictDataNotFound.into()),
         Some(x) => {
             let sys_dict_data = QueryDictDataDetailResp {
                 id: x.id.unwrap_or_default(),               //字典编码
@@ -183,6 +244,15 @@ pub async fn query_sys_dict_data_detail(
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/dictData/queryDictDataList",
+    tag = "字典数据",
+    summary = "查询字典数据表列表",
+    request_body = QueryDictDataListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<DictDataListDataResp>>)),
+    security(("token" = []))
+)]
 #[post("/system/dictData/queryDictDataList", data = "<item>")]
 pub async fn query_sys_dict_data_list(
     item: Json<QueryDictDataListReq>,
@@ -195,8 +265,30 @@ pub async fn query_sys_dict_data_list(
     let dict_type = item.dict_type.as_deref().unwrap_or_default(); //字典类型
     let status = item.status.unwrap_or(2); //状态（0：停用，1:正常）
 
+    let keyword = item.keyword.as_deref().unwrap_or_default().trim(); //关键字(模糊搜索)
+    let begin_time = item.begin_time.as_deref().unwrap_or_default(); //创建时间(开始)
+    let end_time = item.end_time.as_deref().unwrap_or_default(); //创建时间(结束)
+    check_time_range(begin_time, end_time)?;
+
+    let order_by = order_by(
+        item.sort_field.as_deref(),
+        item.sort_order.as_deref(),
+        &[
+            ("id", "id"),
+            ("dictSort", "dict_sort"),
+            ("dictLabel", "dict_label"),
+            ("dictValue", "dict_value"),
+            ("status", "status"),
+            ("createTime", "create_time"),
+        ],
+        "create_time desc",
+    );
+
     let page = &PageRequest::new(item.page_no, item.page_size);
-    let d = DictData::select_dict_data_list(rb, page, dict_label, dict_type, status).await?;
+    let d = DictData::select_dict_data_list(
+        rb, page, dict_label, dict_type, status, keyword, begin_time, end_time, &order_by,
+    )
+    .await?;
 
     let mut list: Vec<DictDataListDataResp> = Vec::new();
 
@@ -221,3 +313,110 @@ pub async fn query_sys_dict_data_list(
 
     ok_result_page(list, total)
 }
+
+/*
+ *根据字典类型查询字典数据(只返回已启用的数据,支持ETag)
+ *author：刘飞华
+ *date：2026/10/19 17:42:15
+ */
+#[utoipa::path(
+    get,
+    path = "/api/system/dictData/queryDictDataByType",
+    tag = "字典数据",
+    summary = "根据字典类型查询字典数据(只返回已启用的数据,支持ETag)",
+    params(
+        ("dict_type" = String, Query, description = "字典类型")
+    ),
+    responses(
+        (status = 200, description = "操作成功", body = BaseResponse<Vec<DictDataByTypeResp>>),
+        (status = 304, description = "数据未变化(If-None-Match与ETag一致)"),
+    ),
+    security(("token" = []))
+)]
+#[get("/system/dictData/queryDictDataByType?<dict_type>")]
+pub async fn query_dict_data_by_type(
+    dict_type: &str,
+    if_none_match: IfNoneMatch,
+    _auth: Token,
+) -> AppResult<EtagResponse> {
+    log::info!("query dict_data_by_type params: {:?}", dict_type);
+    let list = query_enabled_dict_data(dict_type).await?;
+    ok_result_etag(list, &if_none_match)
+}
+
+/*
+ *根据多个字典类型批量查询字典数据(字典类型用逗号分隔,支持ETag)
+ *author：刘飞华
+ *date：2026/10/19 17:42:15
+ */
+#[utoipa::path(
+    get,
+    path = "/api/system/dictData/queryDictDataByTypes",
+    tag = "字典数据",
+    summary = "根据多个字典类型批量查询字典数据(字典类型用逗号分隔,支持ETag)",
+    params(
+        ("dict_types" = String, Query, description = "字典类型(多个用逗号分隔,最多50个)")
+    ),
+    responses(
+        (status = 200, description = "操作成功", body = BaseResponse<BTreeMap<String, Vec<DictDataByTypeResp>>>),
+        (status = 304, description = "数据未变化(If-None-Match与ETag一致)"),
+    ),
+    security(("token" = []))
+)]
+#[get("/system/dictData/queryDictDataByTypes?<dict_types>")]
+pub async fn query_dict_data_by_types(
+    dict_types: &str,
+    if_none_match: IfNoneMatch,
+    _auth: Token,
+) -> AppResult<EtagResponse> {
+    log::info!("query dict_data_by_types params: {:?}", dict_types);
+    let types = dict_types
+        .split(',')
+        .map(|x| x.trim())
+        .filter(|x| !x.is_empty())
+        .collect::<Vec<&str>>();
+
+    if types.is_empty() {
+        return Err(ErrorCode::DictTypeEmpty.into());
+    }
+    if types.len() > 50 {
+        return Err(ErrorCode::DictTypeQueryLimit.with("max", 50).into());
+    }
+
+    let mut map = BTreeMap::new();
+    for dict_type in types {
+        let list = query_enabled_dict_data(dict_type).await?;
+        map.insert(dict_type.to_string(), list);
+    }
+
+    ok_result_etag(map, &if_none_match)
+}
+
+/*
+ *查询已启用的字典数据(优先读取缓存)
+ *author：刘飞华
+ *date：2026/10/19 17:42:15
+ */
+async fn query_enabled_dict_data(dict_type: &str) -> AppResult<Vec<DictDataByTypeResp>> {
+    let list = match DICT_CACHE.get(dict_type) {
+        Some(list) => list,
+        None => {
+            let version = DICT_CACHE.version();
+            let list = select_enabled_by_dict_type(&RB.clone(), dict_type).await?;
+            DICT_CACHE.put(dict_type, list.clone(), version);
+            list
+        }
+    };
+
+    Ok(list
+        .into_iter()
+        .map(|x| DictDataByTypeResp {
+            dict_sort: x.dict_sort,   //字典排序
+            dict_label: x.dict_label, //字典标签
+            dict_value: x.dict_value, //字典键值
+            css_class: x.css_class,   //样式属性（其他样式扩展）
+            list_class: x.list_class, //表格回显样式
+            is_default: x.is_default, //是否默认（Y是 N否）
+        })
+        .collect())
+}
@@ -1,22 +1,61 @@
+use rbatis::executor::Executor;
 use rbatis::plugin::page::PageRequest;
+use rbatis::RBatis;
+use rocket::form::Form;
+use rocket::serde::json::serde_json;
 use rocket::serde::json::{Json, Value};
 
+use crate::common::dict_cache::DICT_CACHE;
 use crate::common::error::{AppError, AppResult};
-use crate::common::result::{ok_result, ok_result_data, ok_result_page};
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{
+    ok_result, ok_result_data, ok_result_file, ok_result_page, BaseResponse, FileResponse,
+    ResponsePage,
+};
+use crate::handler::system::sys_file_handler::read_temp_file;
 use crate::middleware::auth::Token;
-use crate::model::system::sys_dict_data_model::{count_dict_data_by_type, update_dict_data_type};
+use crate::middleware::validate::ValidJson;
+use crate::model::system::sys_dict_data_model::{
+    count_dict_data_by_type, update_dict_data_type, DictData,
+};
 use crate::model::system::sys_dict_type_model::DictType;
-use crate::utils::time_util::time_to_string;
+use crate::utils::sort_util::order_by;
+use crate::utils::time_util::{check_time_range, time_to_string};
 use crate::vo::system::sys_dict_type_vo::*;
 use crate::RB;
 use rbs::value;
+use std::collections::{HashMap, HashSet};
+
+//导入冲突策略：跳过已存在的数据
+const STRATEGY_SKIP: &str = "skip";
+//导入冲突策略：合并(更新已存在的数据,保留导入文件中没有的数据)
+const STRATEGY_MERGE: &str = "merge";
+//导入冲突策略：覆盖(更新已存在的数据,删除导入文件中没有的数据)
+const STRATEGY_OVERWRITE: &str = "overwrite";
+
+const ACTION_CREATE: &str = "create";
+const ACTION_UPDATE: &str = "update";
+const ACTION_DELETE: &str = "delete";
+const ACTION_SKIP: &str = "skip";
+const ACTION_UNCHANGED: &str = "unchanged";
+const ACTION_CONFLICT: &str = "conflict";
+
 /*
  *添加字典类型表
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/dictType/addDictType",
+    tag = "字典类型",
+    summary = "添加字典类型表",
+    request_body = AddDictTypeReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/dictType/addDictType", data = "<item>")]
-pub async fn add_sys_dict_type(item: Json<AddDictTypeReq>, _auth: Token) -> AppResult<Value> {
+pub async fn add_sys_dict_type(item: ValidJson<AddDictTypeReq>, _auth: Token) -> AppResult<Value> {
     log::info!("add sys_dict_type params: {:?}", &item);
     let rb = &mut RB.clone();
     let req = item.0;
@@ -25,7 +64,7 @@ pub async fn add_sys_dict_type(item: Json<AddDictTypeReq>, _auth: Token) -> AppR
         .await?
         .is_some()
     {
-        return Err(AppError::BusinessError("字典类型已存在"));
+        return Err(ErrorCode::DictTypeExists.into());
     }
 
     let sys_dict_type = DictType {
@@ -39,6 +78,7 @@ pub async fn add_sys_dict_type(item: Json<AddDictTypeReq>, _auth: Token) -> AppR
     };
 
     DictType::insert(rb, &sys_dict_type).await?;
+    DICT_CACHE.invalidate_all();
 
     ok_result()
 }
@@ -48,6 +88,15 @@ pub async fn add_sys_dict_type(item: Json<AddDictTypeReq>, _auth: Token) -> AppR
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/dictType/deleteDictType",
+    tag = "字典类型",
+    summary = "删除字典类型表",
+    request_body = DeleteDictTypeReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/dictType/deleteDictType", data = "<item>")]
 pub async fn delete_sys_dict_type(item: Json<DeleteDictTypeReq>, _auth: Token) -> AppResult<Value> {
     log::info!("delete sys_dict_type params: {:?}", &item);
@@ -56,16 +105,17 @@ pub async fn delete_sys_dict_type(item: Json<DeleteDictTypeReq>, _auth: Token) -
     let ids = item.ids.clone();
     for id in ids {
         let p = match DictType::select_by_id(rb, &id).await? {
-            None => return Err(AppError::BusinessError("字典类型不存在,不能删除")),
+            None => return Err(ErrorCode::DictTypeDeleteNotFound.into()),
             Some(p) => p,
         };
 
         if count_dict_data_by_type(rb, &p.dict_type).await? > 0 {
-            return Err(AppError::BusinessError("已分配,不能删除"));
+            return Err(ErrorCode::DictTypeInUse.into());
         }
     }
 
     DictType::delete_by_map(rb, value! {"id": &item.ids}).await?;
+    DICT_CACHE.invalidate_all();
 
     ok_result()
 }
@@ -75,19 +125,31 @@ pub async fn delete_sys_dict_type(item: Json<DeleteDictTypeReq>, _auth: Token) -
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/dictType/updateDictType",
+    tag = "字典类型",
+    summary = "更新字典类型表",
+    request_body = UpdateDictTypeReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/dictType/updateDictType", data = "<item>")]
-pub async fn update_sys_dict_type(item: Json<UpdateDictTypeReq>, _auth: Token) -> AppResult<Value> {
+pub async fn update_sys_dict_type(
+    item: ValidJson<UpdateDictTypeReq>,
+    _auth: Token,
+) -> AppResult<Value> {
     log::info!("update sys_dict_type params: {:?}", &item);
     let rb = &mut RB.clone();
     let req = item.0;
 
     if DictType::select_by_id(rb, &req.id).await?.is_none() {
-        return Err(AppError::BusinessError("字典类型不存在"));
+        return Err(ErrorCode::DictTypeNotFound.into());
     }
 
     if let Some(x) = DictType::select_by_dict_type(rb, &req.dict_type).await? {
         if x.id.unwrap_or_default() != req.id {
-            return Err(AppError::BusinessError("字典类型已存在"));
+            return Err(ErrorCode::DictTypeExists.into());
         }
 
         let dict_type = x.dict_type;
@@ -105,6 +167,7 @@ pub async fn update_sys_dict_type(item: Json<UpdateDictTypeReq>, _auth: Token) -
     };
 
     DictType::update_by_map(rb, &sys_dict_type, value! {"id": &sys_dict_type.id}).await?;
+    DICT_CACHE.invalidate_all();
 
     ok_result()
 }
@@ -114,9 +177,18 @@ pub async fn update_sys_dict_type(item: Json<UpdateDictTypeReq>, _auth: Token) -
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/dictType/updateDictTypeStatus",
+    tag = "字典类型",
+    summary = "更新字典类型表状态",
+    request_body = UpdateDictTypeStatusReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/dictType/updateDictTypeStatus", data = "<item>")]
 pub async fn update_sys_dict_type_status(
-    item: Json<UpdateDictTypeStatusReq>,
+    item: ValidJson<UpdateDictTypeStatusReq>,
     _auth: Token,
 ) -> AppResult<Value> {
     log::info!("update sys_dict_type_status params: {:?}", &item);
@@ -135,6 +207,7 @@ pub async fn update_sys_dict_type_status(
     let mut param = vec![value!(req.status)];
     param.extend(req.ids.iter().map(|&id| value!(id)));
     rb.exec(&update_sql, param).await?;
+    DICT_CACHE.invalidate_all();
 
     ok_result()
 }
@@ -144,6 +217,15 @@ pub async fn update_sys_dict_type_status(
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/dictType/queryDictTypeDetail",
+    tag = "字典类型",
+    summary = "查询字典类型表详情",
+    request_body = QueryDictTypeDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<QueryDictTypeDetailResp>)),
+    security(("token" = []))
+)]
 #[post("/system/dictType/queryDictTypeDetail", data = "<item>")]
 pub async fn query_sys_dict_type_detail(
     item: Json<QueryDictTypeDetailReq>,
@@ -153,7 +235,7 @@ pub async fn query_sys_dict_type_detail(
     let rb = &mut RB.clone();
 
     match DictType::select_by_id(rb, &item.id).await? {
-        None => Err(AppError::BusinessError("字典类型不存在")),
+        None => Err(ErrorCode::DictTypeNotFound.into()),
         Some(x) => {
             let sys_dict_type = QueryDictTypeDetailResp {
                 id: x.id.unwrap_or_default(),               //字典主键
@@ -175,6 +257,15 @@ pub async fn query_sys_dict_type_detail(
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/dictType/queryDictTypeList",
+    tag = "字典类型",
+    summary = "查询字典类型表列表",
+    request_body = QueryDictTypeListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<DictTypeListDataResp>>)),
+    security(("token" = []))
+)]
 #[post("/system/dictType/queryDictTypeList", data = "<item>")]
 pub async fn query_sys_dict_type_list(
     item: Json<QueryDictTypeListReq>,
@@ -187,8 +278,29 @@ pub async fn query_sys_dict_type_list(
     let dict_type = item.dict_type.as_deref().unwrap_or_default(); //字典类型
     let status = item.status.unwrap_or(2); //状态（0：停用，1:正常）
 
+    let keyword = item.keyword.as_deref().unwrap_or_default().trim(); //关键字(模糊搜索)
+    let begin_time = item.begin_time.as_deref().unwrap_or_default(); //创建时间(开始)
+    let end_time = item.end_time.as_deref().unwrap_or_default(); //创建时间(结束)
+    check_time_range(begin_time, end_time)?;
+
+    let order_by = order_by(
+        item.sort_field.as_deref(),
+        item.sort_order.as_deref(),
+        &[
+            ("id", "id"),
+            ("dictName", "dict_name"),
+            ("dictType", "dict_type"),
+            ("status", "status"),
+            ("createTime", "create_time"),
+        ],
+        "create_time desc",
+    );
+
     let page = &PageRequest::new(item.page_no, item.page_size);
-    let d = DictType::select_dict_type_list(rb, page, dict_name, dict_type, status).await?;
+    let d = DictType::select_dict_type_list(
+        rb, page, dict_name, dict_type, status, keyword, begin_time, end_time, &order_by,
+    )
+    .await?;
 
     let mut list: Vec<DictTypeListDataResp> = Vec::new();
 
@@ -208,3 +320,430 @@ pub async fn query_sys_dict_type_list(
 
     ok_result_page(list, total)
 }
+
+/*
+ *导出字典(字典类型及其字典数据,json或yaml格式)
+ *author：刘飞华
+ *date：2026/10/19 18:05:37
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/dictType/exportDictType",
+    tag = "字典类型",
+    summary = "导出字典(字典类型及其字典数据,json或yaml格式)",
+    request_body = ExportDictTypeReq,
+    responses((status = 200, description = "字典文件(json/yaml)", content_type = "application/octet-stream")),
+    security(("token" = []))
+)]
+#[post("/system/dictType/exportDictType", data = "<item>")]
+pub async fn export_sys_dict_type(
+    item: Json<ExportDictTypeReq>,
+    _auth: Token,
+) -> AppResult<FileResponse> {
+    log::info!("export sys_dict_type params: {:?}", &item);
+    let rb = &mut RB.clone();
+    let req = item.0;
+
+    let dict_types = if req.ids.is_empty() {
+        DictType::select_all(rb).await?
+    } else {
+        DictType::select_by_map(rb, value! {"id": &req.ids}).await?
+    };
+
+    let mut bundle = DictBundle {
+        version: 1,
+        dict_types: Vec::new(),
+    };
+    for t in dict_types {
+        let dict_data = DictData::select_by_dict_type(rb, &t.dict_type).await?;
+        bundle.dict_types.push(DictTypeBundle {
+            dict_name: t.dict_name, //字典名称
+            dict_type: t.dict_type, //字典类型
+            status: t.status,       //状态（0：停用，1:正常）
+            remark: t.remark,       //备注
+            dict_data: dict_data
+                .into_iter()
+                .map(|x| DictDataBundle {
+                    dict_sort: x.dict_sort,   //字典排序
+                    dict_label: x.dict_label, //字典标签
+                    dict_value: x.dict_value, //字典键值
+                    css_class: x.css_class,   //样式属性（其他样式扩展）
+                    list_class: x.list_class, //表格回显样式
+                    is_default: x.is_default, //是否默认（Y是 N否）
+                    status: x.status,         //状态（0：停用，1:正常）
+                    remark: x.remark,         //备注
+                })
+                .collect(),
+        });
+    }
+
+    match req.format.as_deref().unwrap_or("json") {
+        "json" => {
+            let data = serde_json::to_vec_pretty(&bundle).map_err(|e| {
+                log::error!("export sys_dict_type error: {}", e);
+                AppError::from(ErrorCode::DictExportFailed)
+            })?;
+            ok_result_file(data, "application/json", "dict_bundle.json")
+        }
+        "yaml" | "yml" => {
+            let data = serde_yaml::to_string(&bundle).map_err(|e| {
+                log::error!("export sys_dict_type error: {}", e);
+                AppError::from(ErrorCode::DictExportFailed)
+            })?;
+            ok_result_file(data.into_bytes(), "application/x-yaml", "dict_bundle.yaml")
+        }
+        _ => Err(ErrorCode::DictExportFormatInvalid.into()),
+    }
+}
+
+/*
+ *导入字典(dryRun为true时只返回差异,否则在事务中写入)
+ *author：刘飞华
+ *date：2026/10/19 18:05:37
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/dictType/importDictType",
+    tag = "字典类型",
+    summary = "导入字典(dryRun为true时只返回差异,否则在事务中写入)",
+    request_body(content = ImportDictTypeReq, content_type = "multipart/form-data"),
+    responses((status = 200, description = "操作成功", body = BaseResponse<ImportDictTypeResp>)),
+    security(("token" = []))
+)]
+#[post("/system/dictType/importDictType", data = "<item>")]
+pub async fn import_sys_dict_type(
+    item: Form<ImportDictTypeReq<'_>>,
+    _auth: Token,
+) -> AppResult<Value> {
+    log::info!("import sys_dict_type params: {:?}", &item);
+    let rb = &mut RB.clone();
+
+    let strategy = item.strategy.as_deref().unwrap_or(STRATEGY_SKIP);
+    if ![STRATEGY_SKIP, STRATEGY_MERGE, STRATEGY_OVERWRITE].contains(&strategy) {
+        return Err(ErrorCode::DictImportStrategyInvalid.into());
+    }
+    let dry_run = item.dry_run.unwrap_or_default();
+
+    let file = &item.file;
+    let file_ext = file
+        .raw_name()
+        .map(|x| x.dangerous_unsafe_unsanitized_raw().as_str())
+        .and_then(|x| x.rsplit_once('.'))
+        .map(|(_, ext)| ext.to_lowercase())
+        .unwrap_or_default();
+
+    let data = read_temp_file(file).await?;
+    let bundle: DictBundle = match file_ext.as_str() {
+        "json" => serde_json::from_slice(&data).map_err(|e| e.to_string()),
+        "yaml" | "yml" => serde_yaml::from_slice(&data).map_err(|e| e.to_string()),
+        _ => return Err(ErrorCode::DictImportFormatInvalid.into()),
+    }
+    .map_err(|e| {
+        log::error!("import sys_dict_type parse error: {}", e);
+        AppError::from(ErrorCode::DictImportParseFailed)
+    })?;
+    check_dict_bundle(&bundle)?;
+
+    let plans = build_dict_import_plan(rb, bundle, strategy).await?;
+
+    if !dry_run {
+        let tx = rb.acquire_begin().await?;
+        if let Err(e) = apply_dict_import_plan(&tx, &plans).await {
+            tx.rollback().await?;
+            return Err(e);
+        }
+        tx.commit().await?;
+        DICT_CACHE.invalidate_all();
+    }
+
+    ok_result_data(dict_import_resp(&plans, strategy, dry_run))
+}
+
+/*
+ *导入计划-字典类型
+ *author：刘飞华
+ *date：2026/10/19 18:05:37
+ */
+struct DictTypeImportPlan {
+    action: &'static str,
+    dict_type: DictType,
+    dict_data: Vec<DictDataImportPlan>,
+}
+
+/*
+ *导入计划-字典数据
+ *author：刘飞华
+ *date：2026/10/19 18:05:37
+ */
+struct DictDataImportPlan {
+    action: &'static str,
+    dict_data: DictData,
+}
+
+/*
+ *校验导入文件(字典类型不能重复,同一字典类型下标签和键值不能重复)
+ *author：刘飞华
+ *date：2026/10/19 18:05:37
+ */
+fn check_dict_bundle(bundle: &DictBundle) -> AppResult<()> {
+    if bundle.dict_types.is_empty() {
+        return Err(ErrorCode::DictImportEmpty.into());
+    }
+
+    let mut types = HashSet::new();
+    for t in &bundle.dict_types {
+        if t.dict_name.trim().is_empty() || t.dict_type.trim().is_empty() {
+            return Err(ErrorCode::DictImportTypeBlank.into());
+        }
+        if !types.insert(t.dict_type.as_str()) {
+            return Err(ErrorCode::DictImportTypeDuplicated
+                .with("dictType", &t.dict_type)
+                .into());
+        }
+
+        let mut labels = HashSet::new();
+        let mut values = HashSet::new();
+        for x in &t.dict_data {
+            if x.dict_label.trim().is_empty() || x.dict_value.trim().is_empty() {
+                return Err(ErrorCode::DictImportDataBlank.into());
+            }
+            if !labels.insert(x.dict_label.as_str()) {
+                return Err(ErrorCode::DictImportLabelDuplicated
+                    .with("dictLabel", &x.dict_label)
+                    .into());
+            }
+            if !values.insert(x.dict_value.as_str()) {
+                return Err(ErrorCode::DictImportValueDuplicated
+                    .with("dictValue", &x.dict_value)
+                    .into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/*
+ *对比数据库生成导入计划(字典数据按键值匹配)
+ *author：刘飞华
+ *date：2026/10/19 18:05:37
+ */
+async fn build_dict_import_plan(
+    rb: &RBatis,
+    bundle: DictBundle,
+    strategy: &str,
+) -> AppResult<Vec<DictTypeImportPlan>> {
+    let mut plans = Vec::new();
+
+    for b in bundle.dict_types {
+        let old_type = DictType::select_by_dict_type(rb, &b.dict_type).await?;
+        let old_data = DictData::select_by_dict_type(rb, &b.dict_type).await?;
+
+        let dict_type = DictType {
+            id: old_type.as_ref().and_then(|x| x.id), //字典主键
+            dict_name: b.dict_name,                   //字典名称
+            dict_type: b.dict_type,                   //字典类型
+            status: b.status,                         //状态（0：停用，1:正常）
+            remark: b.remark,                         //备注
+            create_time: None,                        //创建时间
+            update_time: None,                        //修改时间
+        };
+        let action = match &old_type {
+            None => ACTION_CREATE,
+            Some(x)
+                if x.dict_name == dict_type.dict_name
+                    && x.status == dict_type.status
+                    && x.remark == dict_type.remark =>
+            {
+                ACTION_UNCHANGED
+            }
+            Some(_) if strategy == STRATEGY_SKIP => ACTION_SKIP,
+            Some(_) => ACTION_UPDATE,
+        };
+
+        let values = b
+            .dict_data
+            .iter()
+            .map(|x| x.dict_value.as_str())
+            .collect::<HashSet<&str>>();
+
+        let mut dict_data = Vec::new();
+        //导入后仍保留原标签的数据(标签 -> 键值),用于检查标签冲突
+        let mut kept_labels = HashMap::new();
+        for x in &old_data {
+            if values.contains(x.dict_value.as_str()) {
+                continue;
+            }
+            if strategy == STRATEGY_OVERWRITE {
+                dict_data.push(DictDataImportPlan {
+                    action: ACTION_DELETE,
+                    dict_data: x.clone(),
+                });
+            } else {
+                kept_labels.insert(x.dict_label.clone(), x.dict_value.clone());
+            }
+        }
+
+        let mut new_data = Vec::new();
+        for d in b.dict_data {
+            let old = old_data.iter().find(|x| x.dict_value == d.dict_value);
+            let data = DictData {
+                id: old.and_then(|x| x.id),             //字典编码
+                dict_sort: d.dict_sort,                 //字典排序
+                dict_label: d.dict_label,               //字典标签
+                dict_value: d.dict_value,               //字典键值
+                dict_type: dict_type.dict_type.clone(), //字典类型
+                css_class: d.css_class,                 //样式属性（其他样式扩展）
+                list_class: d.list_class,               //表格回显样式
+                is_default: d.is_default,               //是否默认（Y是 N否）
+                status: d.status,                       //状态（0：停用，1:正常）
+                remark: d.remark,                       //备注
+                create_time: None,                      //创建时间
+                update_time: None,                      //修改时间
+            };
+            let action = match old {
+                None => ACTION_CREATE,
+                Some(x) if is_same_dict_data(x, &data) => ACTION_UNCHANGED,
+                Some(x) if strategy == STRATEGY_SKIP => {
+                    kept_labels.insert(x.dict_label.clone(), x.dict_value.clone());
+                    ACTION_SKIP
+                }
+                Some(_) => ACTION_UPDATE,
+            };
+            new_data.push(DictDataImportPlan {
+                action,
+                dict_data: data,
+            });
+        }
+
+        for x in new_data.iter_mut() {
+            if x.action != ACTION_CREATE && x.action != ACTION_UPDATE {
+                continue;
+            }
+            if let Some(value) = kept_labels.get(&x.dict_data.dict_label) {
+                if value != &x.dict_data.dict_value {
+                    x.action = ACTION_CONFLICT;
+                }
+            }
+        }
+        dict_data.extend(new_data);
+
+        plans.push(DictTypeImportPlan {
+            action,
+            dict_type,
+            dict_data,
+        });
+    }
+
+    Ok(plans)
+}
+
+/*
+ *判断字典数据是否一致
+ *author：刘飞华
+ *date：2026/10/19 18:05:37
+ */
+fn is_same_dict_data(a: &DictData, b: &DictData) -> bool {
+    a.dict_sort == b.dict_sort
+        && a.dict_label == b.dict_label
+        && a.css_class == b.css_class
+        && a.list_class == b.list_class
+        && a.is_default == b.is_default
+        && a.status == b.status
+        && a.remark == b.remark
+}
+
+/*
+ *执行导入计划(先删除再更新和新增,避免标签冲突)
+ *author：刘飞华
+ *date：2026/10/19 18:05:37
+ */
+async fn apply_dict_import_plan(tx: &dyn Executor, plans: &[DictTypeImportPlan]) -> AppResult<()> {
+    for p in plans {
+        match p.action {
+            ACTION_CREATE => {
+                DictType::insert(tx, &p.dict_type).await?;
+            }
+            ACTION_UPDATE => {
+                DictType::update_by_map(tx, &p.dict_type, value! {"id": &p.dict_type.id}).await?;
+            }
+            _ => {}
+        }
+
+        let ids = p
+            .dict_data
+            .iter()
+            .filter(|x| x.action == ACTION_DELETE)
+            .map(|x| x.dict_data.id)
+            .collect::<Vec<Option<i64>>>();
+        if !ids.is_empty() {
+            DictData::delete_by_map(tx, value! {"id": &ids}).await?;
+        }
+
+        for x in &p.dict_data {
+            match x.action {
+                ACTION_CREATE => {
+                    DictData::insert(tx, &x.dict_data).await?;
+                }
+                ACTION_UPDATE => {
+                    DictData::update_by_map(tx, &x.dict_data, value! {"id": &x.dict_data.id})
+                        .await?;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/*
+ *导入计划转换为响应参数
+ *author：刘飞华
+ *date：2026/10/19 18:05:37
+ */
+fn dict_import_resp(
+    plans: &[DictTypeImportPlan],
+    strategy: &str,
+    dry_run: bool,
+) -> ImportDictTypeResp {
+    let mut resp = ImportDictTypeResp {
+        dry_run,                        //是否只预览
+        strategy: strategy.to_string(), //冲突策略
+        created: 0,                     //新增条数
+        updated: 0,                     //更新条数
+        deleted: 0,                     //删除条数
+        skipped: 0,                     //跳过条数(含冲突)
+        dict_types: Vec::new(),         //差异明细
+    };
+
+    for p in plans {
+        let actions = std::iter::once(p.action).chain(p.dict_data.iter().map(|x| x.action));
+        for action in actions {
+            match action {
+                ACTION_CREATE => resp.created += 1,
+                ACTION_UPDATE => resp.updated += 1,
+                ACTION_DELETE => resp.deleted += 1,
+                ACTION_SKIP | ACTION_CONFLICT => resp.skipped += 1,
+                _ => {}
+            }
+        }
+
+        resp.dict_types.push(DictTypeDiffResp {
+            dict_type: p.dict_type.dict_type.clone(), //字典类型
+            dict_name: p.dict_type.dict_name.clone(), //字典名称
+            action: p.action.to_string(),             //操作
+            dict_data: p
+                .dict_data
+                .iter()
+                .map(|x| DictDataDiffResp {
+                    dict_label: x.dict_data.dict_label.clone(), //字典标签
+                    dict_value: x.dict_data.dict_value.clone(), //字典键值
+                    action: x.action.to_string(),               //操作
+                })
+                .collect(),
+        });
+    }
+
+    resp
+}
@@ -0,0 +1,298 @@
+use rbatis::plugin::page::PageRequest;
+use rbatis::rbatis_codegen::ops::AsProxy;
+use rbatis::rbdc::datetime::DateTime;
+use rbatis::RBatis;
+use rocket::form::Form;
+use rocket::fs::TempFile;
+use rocket::http::ContentType;
+use rocket::serde::json::{Json, Value};
+use rocket::tokio::io::AsyncReadExt;
+use sha2::{Digest, Sha256};
+
+use crate::common::error::{AppError, AppResult};
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{
+    ok_result, ok_result_data, ok_result_file, ok_result_page, BaseResponse, FileResponse,
+    ResponsePage,
+};
+use crate::common::storage::{FILE_ALLOWED_EXT, FILE_MAX_SIZE, FILE_STORAGE};
+use crate::middleware::auth::Token;
+use crate::model::system::sys_file_model::{count_by_file_key, SysFile};
+use crate::model::system::sys_notice_attachment_model::count_by_file_id;
+use crate::utils::time_util::time_to_string;
+use crate::vo::system::sys_file_vo::*;
+use crate::RB;
+use rbs::value;
+
+/*
+ *上传文件
+ *author：刘飞华
+ *date：2026/10/19 18:26:40
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/file/uploadFile",
+    tag = "文件管理",
+    summary = "上传文件",
+    request_body(content = UploadFileReq, content_type = "multipart/form-data"),
+    responses((status = 200, description = "操作成功", body = BaseResponse<UploadFileResp>)),
+    security(("token" = []))
+)]
+#[post("/system/file/uploadFile", data = "<item>")]
+pub async fn upload_sys_file(item: Form<UploadFileReq<'_>>, auth: Token) -> AppResult<Value> {
+    log::info!("upload sys_file params: {:?}", &item);
+
+    let file = &item.file;
+    //浏览器上传的文件名可能带路径,只保留最后一段
+    let file_name = file
+        .raw_name()
+        .map(|x| x.dangerous_unsafe_unsanitized_raw().as_str())
+        .and_then(|x| x.rsplit(['/', '\\']).next())
+        .unwrap_or_default()
+        .to_string();
+
+    if file.len() == 0 {
+        return Err(ErrorCode::FileEmpty.into());
+    }
+    if file.len() > *FILE_MAX_SIZE {
+        return Err(ErrorCode::FileTooLarge
+            .with("max", *FILE_MAX_SIZE / 1024 / 1024)
+            .into());
+    }
+
+    let file_ext = file_name
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_lowercase())
+        .unwrap_or_default();
+    if !FILE_ALLOWED_EXT.contains(&file_ext) {
+        return Err(ErrorCode::FileTypeNotAllowed.with("ext", file_ext).into());
+    }
+
+    let data = read_temp_file(file).await?;
+
+    let sys_file = save_file(&file_name, &data, &auth.username, "").await?;
+
+    ok_result_data(UploadFileResp {
+        id: sys_file.id.unwrap_or_default(), //文件ID
+        file_url: file_download_url(sys_file.id.unwrap_or_default()), //下载地址
+        file_name: sys_file.file_name,       //原始文件名
+        file_size: sys_file.file_size,       //文件大小(字节)
+        content_type: sys_file.content_type, //文件类型
+    })
+}
+
+/*
+ *下载文件
+ *author：刘飞华
+ *date：2026/10/19 18:26:40
+ */
+#[utoipa::path(
+    get,
+    path = "/api/system/file/downloadFile",
+    tag = "文件管理",
+    summary = "下载文件",
+    params(
+        ("id" = i64, Query, description = "文件ID")
+    ),
+    responses((status = 200, description = "文件内容", content_type = "application/octet-stream")),
+    security(("token" = []))
+)]
+#[get("/system/file/downloadFile?<id>")]
+pub async fn download_sys_file(id: i64, _auth: Token) -> AppResult<FileResponse> {
+    log::info!("download sys_file params: {:?}", &id);
+    let rb = &RB.clone();
+
+    match SysFile::select_by_id(rb, id).await? {
+        None => Err(ErrorCode::FileNotFound.into()),
+        Some(x) => {
+            if x.storage_type != FILE_STORAGE.storage_type() {
+                return Err(ErrorCode::FileStorageMismatch.into());
+            }
+            let data = FILE_STORAGE.get(&x.file_key).await?;
+            ok_result_file(data, &x.content_type, &x.file_name)
+        }
+    }
+}
+
+/*
+ *删除文件(被通知公告附件引用的文件不能删除;同一内容的文件共用存储,没有引用时才删除存储中的文件)
+ *author：刘飞华
+ *date：2026/10/19 18:26:40
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/file/deleteFile",
+    tag = "文件管理",
+    summary = "删除文件(同一内容的文件共用存储,没有引用时才删除存储中的文件)",
+    request_body = DeleteFileReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
+#[post("/system/file/deleteFile", data = "<item>")]
+pub async fn delete_sys_file(item: Json<DeleteFileReq>, _auth: Token) -> AppResult<Value> {
+    log::info!("delete sys_file params: {:?}", &item);
+    let rb = &RB.clone();
+
+    for &id in &item.ids {
+        if count_by_file_id(rb, id).await? > 0 {
+            return Err(ErrorCode::FileInUse.into());
+        }
+    }
+
+    let list = SysFile::select_by_map(rb, value! {"id": &item.ids}).await?;
+    //头像文件由用户上传头像时维护(替换后自动删除旧头像),这里不能删除
+    if list.iter().any(|x| x.biz_type == "avatar") {
+        return Err(ErrorCode::AvatarFileInUse.into());
+    }
+    delete_files(rb, &list).await?;
+
+    ok_result()
+}
+
+/*
+ *删除文件记录(存储路径没有其他文件引用时同时删除存储的文件)
+ *author：刘飞华
+ *date：2026/10/20 12:08:15
+ */
+pub async fn delete_files(rb: &RBatis, list: &[SysFile]) -> AppResult<()> {
+    let ids = list.iter().filter_map(|x| x.id).collect::<Vec<i64>>();
+    if ids.is_empty() {
+        return Ok(());
+    }
+    SysFile::delete_by_map(rb, value! {"id": &ids}).await?;
+
+    for x in list {
+        if x.storage_type != FILE_STORAGE.storage_type() {
+            continue;
+        }
+        if count_by_file_key(rb, &x.file_key, &x.storage_type).await? == 0 {
+            FILE_STORAGE.delete(&x.file_key).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/*
+ *查询文件列表
+ *author：刘飞华
+ *date：2026/10/19 18:26:40
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/file/queryFileList",
+    tag = "文件管理",
+    summary = "查询文件列表",
+    request_body = QueryFileListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<FileListDataResp>>)),
+    security(("token" = []))
+)]
+#[post("/system/file/queryFileList", data = "<item>")]
+pub async fn query_sys_file_list(item: Json<QueryFileListReq>, _auth: Token) -> AppResult<Value> {
+    log::info!("query sys_file_list params: {:?}", &item);
+    let rb = &RB.clone();
+
+    let file_name = item.file_name.as_deref().unwrap_or_default(); //原始文件名
+    let file_ext = item.file_ext.as_deref().unwrap_or_default(); //文件扩展名
+    let storage_type = item.storage_type.as_deref().unwrap_or_default(); //存储类型
+
+    let page = &PageRequest::new(item.page_no, item.page_size);
+    let d = SysFile::select_sys_file_list(rb, page, file_name, file_ext, storage_type).await?;
+
+    let mut list: Vec<FileListDataResp> = Vec::new();
+
+    let total = d.total;
+
+    for x in d.records {
+        list.push(FileListDataResp {
+            id: x.id.unwrap_or_default(),                          //文件ID
+            file_name: x.file_name,                                //原始文件名
+            file_url: file_download_url(x.id.unwrap_or_default()), //下载地址
+            file_ext: x.file_ext,                                  //文件扩展名
+            file_size: x.file_size,                                //文件大小(字节)
+            content_type: x.content_type,                          //文件类型
+            file_hash: x.file_hash,                                //文件内容sha256
+            storage_type: x.storage_type,                          //存储类型
+            create_by: x.create_by,                                //上传者
+            create_time: time_to_string(x.create_time),            //上传时间
+        })
+    }
+
+    ok_result_page(list, total)
+}
+
+/*
+ *读取上传的文件内容
+ *author：刘飞华
+ *date：2026/10/19 19:12:26
+ */
+pub async fn read_temp_file(file: &TempFile<'_>) -> AppResult<Vec<u8>> {
+    let mut data: Vec<u8> = Vec::new();
+    file.open()
+        .await
+        .map_err(AppError::storage)?
+        .read_to_end(&mut data)
+        .await
+        .map_err(AppError::storage)?;
+    Ok(data)
+}
+
+/*
+ *文件下载地址
+ *author：刘飞华
+ *date：2026/10/19 18:26:40
+ */
+pub fn file_download_url(id: i64) -> String {
+    format!("/api/system/file/downloadFile?id={}", id)
+}
+
+/*
+ *保存文件(按内容sha256去重,相同内容只存储一份)
+ *author：刘飞华
+ *date：2026/10/19 18:26:40
+ */
+pub async fn save_file(
+    file_name: &str,
+    data: &[u8],
+    create_by: &str,
+    biz_type: &str,
+) -> AppResult<SysFile> {
+    let file_ext = file_name
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_lowercase())
+        .unwrap_or_default();
+
+    //文件类型根据扩展名确定,不信任客户端传入的类型
+    let content_type = ContentType::from_extension(&file_ext)
+        .unwrap_or(ContentType::Binary)
+        .to_string();
+    let file_hash = format!("{:x}", Sha256::digest(data));
+    let storage_type = FILE_STORAGE.storage_type();
+
+    let rb = &RB.clone();
+    let file_key = match SysFile::select_by_hash(rb, &file_hash, storage_type).await? {
+        Some(x) => x.file_key,
+        None => {
+            let file_key = format!("{}/{}.{}", &file_hash[0..2], file_hash, file_ext);
+            FILE_STORAGE.put(&file_key, data, &content_type).await?;
+            file_key
+        }
+    };
+
+    let mut sys_file = SysFile {
+        id: None,                               //文件ID
+        file_name: file_name.to_string(),       //原始文件名
+        file_key,                               //存储路径
+        file_ext,                               //文件扩展名
+        file_size: data.len() as i64,           //文件大小(字节)
+        content_type,                           //文件类型
+        file_hash,                              //文件内容sha256
+        storage_type: storage_type.to_string(), //存储类型
+        biz_type: biz_type.to_string(),         //业务类型
+        create_by: create_by.to_string(),       //上传者
+        create_time: Some(DateTime::now()),     //上传时间
+    };
+
+    sys_file.id = Some(SysFile::insert(rb, &sys_file).await?.last_insert_id.i64());
+    Ok(sys_file)
+}
@@ -0,0 +1,341 @@
+use rbatis::plugin::page::PageRequest;
+use rbatis::rbatis_codegen::ops::AsProxy;
+use rocket::serde::json::{Json, Value};
+
+use crate::common::error::AppResult;
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{
+    ok_result, ok_result_data, ok_result_msg, ok_result_page, BaseResponse, ResponsePage,
+};
+use crate::common::scheduler::job_task::JOB_HANDLERS;
+use crate::common::scheduler::{next_valid_time, parse_cron, JOB_SCHEDULER, TRIGGER_MANUAL};
+use crate::middleware::auth::Token;
+use crate::middleware::validate::ValidJson;
+use crate::model::system::sys_job_model::SysJob;
+use crate::utils::time_util::time_to_string;
+use crate::vo::system::sys_job_vo::*;
+use crate::RB;
+use rbs::value;
+/*
+ *添加定时任务表
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/job/addJob",
+    tag = "定时任务",
+    summary = "添加定时任务表",
+    request_body = AddJobReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
+#[post("/system/job/addJob", data = "<item>")]
+pub async fn add_sys_job(item: Json<AddJobReq>, _auth: Token) -> AppResult<Value> {
+    log::info!("add sys_job params: {:?}", &item);
+    let rb = &mut RB.clone();
+    let req = item.0;
+
+    check_job(&req.invoke_target, &req.cron_expression)?;
+
+    let mut sys_job = SysJob {
+        id: None,                                                  //任务ID
+        job_name: req.job_name,                                    //任务名称
+        job_group: req.job_group.unwrap_or("DEFAULT".to_string()), //任务组名
+        invoke_target: req.invoke_target,                          //任务处理器名称
+        job_params: req.job_params.unwrap_or_default(),            //任务参数
+        cron_expression: req.cron_expression.trim().to_string(),   //cron表达式
+        concurrent: req.concurrent, //是否允许并发执行（0：禁止,1:允许）
+        status: req.status,         //任务状态（0：暂停，1:正常）
+        remark: req.remark.unwrap_or_default(), //备注
+        create_time: None,          //创建时间
+        update_time: None,          //修改时间
+    };
+
+    sys_job.id = Some(SysJob::insert(rb, &sys_job).await?.last_insert_id.i64());
+    JOB_SCHEDULER.schedule_job(sys_job);
+
+    ok_result()
+}
+
+/*
+ *删除定时任务表
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/job/deleteJob",
+    tag = "定时任务",
+    summary = "删除定时任务表",
+    request_body = DeleteJobReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
+#[post("/system/job/deleteJob", data = "<item>")]
+pub async fn delete_sys_job(item: Json<DeleteJobReq>, _auth: Token) -> AppResult<Value> {
+    log::info!("delete sys_job params: {:?}", &item);
+    let rb = &mut RB.clone();
+
+    SysJob::delete_by_map(rb, value! {"id": &item.ids}).await?;
+    for id in &item.ids {
+        JOB_SCHEDULER.unschedule_job(*id);
+    }
+
+    ok_result()
+}
+
+/*
+ *更新定时任务表
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/job/updateJob",
+    tag = "定时任务",
+    summary = "更新定时任务表",
+    request_body = UpdateJobReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
+#[post("/system/job/updateJob", data = "<item>")]
+pub async fn update_sys_job(item: Json<UpdateJobReq>, _auth: Token) -> AppResult<Value> {
+    log::info!("update sys_job params: {:?}", &item);
+    let rb = &mut RB.clone();
+    let req = item.0;
+
+    if SysJob::select_by_id(rb, &req.id).await?.is_none() {
+        return Err(ErrorCode::JobNotFound.into());
+    }
+
+    check_job(&req.invoke_target, &req.cron_expression)?;
+
+    let sys_job = SysJob {
+        id: Some(req.id),                                          //任务ID
+        job_name: req.job_name,                                    //任务名称
+        job_group: req.job_group.unwrap_or("DEFAULT".to_string()), //任务组名
+        invoke_target: req.invoke_target,                          //任务处理器名称
+        job_params: req.job_params.unwrap_or_default(),            //任务参数
+        cron_expression: req.cron_expression.trim().to_string(),   //cron表达式
+        concurrent: req.concurrent, //是否允许并发执行（0：禁止,1:允许）
+        status: req.status,         //任务状态（0：暂停，1:正常）
+        remark: req.remark.unwrap_or_default(), //备注
+        create_time: None,          //创建时间
+        update_time: None,          //修改时间
+    };
+
+    SysJob::update_by_map(rb, &sys_job, value! {"id": &sys_job.id}).await?;
+    JOB_SCHEDULER.schedule_job(sys_job);
+
+    ok_result()
+}
+
+/*
+ *更新定时任务表状态(0：暂停,1:恢复)
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/job/updateJobStatus",
+    tag = "定时任务",
+    summary = "更新定时任务表状态(0：暂停,1:恢复)",
+    request_body = UpdateJobStatusReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
+#[post("/system/job/updateJobStatus", data = "<item>")]
+pub async fn update_sys_job_status(
+    item: ValidJson<UpdateJobStatusReq>,
+    _auth: Token,
+) -> AppResult<Value> {
+    log::info!("update sys_job_status params: {:?}", &item);
+    let rb = &mut RB.clone();
+    let req = item.0;
+
+    let update_sql = format!(
+        "update sys_job set status = ? where id in ({})",
+        req.ids
+            .iter()
+            .map(|_| "?")
+            .collect::<Vec<&str>>()
+            .join(", ")
+    );
+
+    let mut param = vec![value!(req.status)];
+    param.extend(req.ids.iter().map(|&id| value!(id)));
+    rb.exec(&update_sql, param).await?;
+
+    for x in SysJob::select_by_map(rb, value! {"id": &req.ids}).await? {
+        JOB_SCHEDULER.schedule_job(x);
+    }
+
+    ok_result()
+}
+
+/*
+ *立即执行一次定时任务(不影响任务的调度)
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/job/runJob",
+    tag = "定时任务",
+    summary = "立即执行一次定时任务(不影响任务的调度)",
+    request_body = RunJobReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
+#[post("/system/job/runJob", data = "<item>")]
+pub async fn run_sys_job(item: Json<RunJobReq>, _auth: Token) -> AppResult<Value> {
+    log::info!("run sys_job params: {:?}", &item);
+    let rb = &mut RB.clone();
+
+    match SysJob::select_by_id(rb, &item.id).await? {
+        None => Err(ErrorCode::JobNotFound.into()),
+        Some(x) => {
+            JOB_SCHEDULER.trigger(x, TRIGGER_MANUAL);
+            ok_result_msg("任务已开始执行,请在任务日志中查看执行结果")
+        }
+    }
+}
+
+/*
+ *查询定时任务表详情
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/job/queryJobDetail",
+    tag = "定时任务",
+    summary = "查询定时任务表详情",
+    request_body = QueryJobDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<QueryJobDetailResp>)),
+    security(("token" = []))
+)]
+#[post("/system/job/queryJobDetail", data = "<item>")]
+pub async fn query_sys_job_detail(item: Json<QueryJobDetailReq>, _auth: Token) -> AppResult<Value> {
+    log::info!("query sys_job_detail params: {:?}", &item);
+    let rb = &mut RB.clone();
+
+    match SysJob::select_by_id(rb, &item.id).await? {
+        None => Err(ErrorCode::JobNotFound.into()),
+        Some(x) => {
+            let sys_job = QueryJobDetailResp {
+                id: x.id.unwrap_or_default(),   //任务ID
+                job_name: x.job_name,           //任务名称
+                job_group: x.job_group,         //任务组名
+                invoke_target: x.invoke_target, //任务处理器名称
+                job_params: x.job_params,       //任务参数
+                next_valid_time: job_next_valid_time(x.status, &x.cron_expression), //下次执行时间
+                cron_expression: x.cron_expression, //cron表达式
+                concurrent: x.concurrent,       //是否允许并发执行（0：禁止,1:允许）
+                status: x.status,               //任务状态（0：暂停，1:正常）
+                remark: x.remark,               //备注
+                create_time: time_to_string(x.create_time), //创建时间
+                update_time: time_to_string(x.update_time), //修改时间
+            };
+
+            ok_result_data(sys_job)
+        }
+    }
+}
+
+/*
+ *查询定时任务表列表
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/job/queryJobList",
+    tag = "定时任务",
+    summary = "查询定时任务表列表",
+    request_body = QueryJobListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<JobListDataResp>>)),
+    security(("token" = []))
+)]
+#[post("/system/job/queryJobList", data = "<item>")]
+pub async fn query_sys_job_list(item: Json<QueryJobListReq>, _auth: Token) -> AppResult<Value> {
+    log::info!("query sys_job_list params: {:?}", &item);
+    let rb = &mut RB.clone();
+
+    let job_name = item.job_name.as_deref().unwrap_or_default(); //任务名称
+    let job_group = item.job_group.as_deref().unwrap_or_default(); //任务组名
+    let status = item.status.unwrap_or(2); //任务状态（0：暂停，1:正常）
+
+    let page = &PageRequest::new(item.page_no, item.page_size);
+    let d = SysJob::select_sys_job_list(rb, page, job_name, job_group, status).await?;
+
+    let mut list: Vec<JobListDataResp> = Vec::new();
+
+    let total = d.total;
+
+    for x in d.records {
+        list.push(JobListDataResp {
+            id: x.id.unwrap_or_default(),   //任务ID
+            job_name: x.job_name,           //任务名称
+            job_group: x.job_group,         //任务组名
+            invoke_target: x.invoke_target, //任务处理器名称
+            job_params: x.job_params,       //任务参数
+            next_valid_time: job_next_valid_time(x.status, &x.cron_expression), //下次执行时间
+            cron_expression: x.cron_expression, //cron表达式
+            concurrent: x.concurrent,       //是否允许并发执行（0：禁止,1:允许）
+            status: x.status,               //任务状态（0：暂停，1:正常）
+            remark: x.remark,               //备注
+            create_time: time_to_string(x.create_time), //创建时间
+            update_time: time_to_string(x.update_time), //修改时间
+        })
+    }
+
+    ok_result_page(list, total)
+}
+
+/*
+ *查询已注册的任务处理器(添加任务时选择)
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/job/queryJobHandlerList",
+    tag = "定时任务",
+    summary = "查询已注册的任务处理器(添加任务时选择)",
+    responses((status = 200, description = "操作成功", body = BaseResponse<Vec<String>>)),
+    security(("token" = []))
+)]
+#[post("/system/job/queryJobHandlerList")]
+pub async fn query_job_handler_list(_auth: Token) -> AppResult<Value> {
+    log::info!("query job_handler_list");
+    ok_result_data(JOB_HANDLERS.keys().collect::<Vec<_>>())
+}
+
+/*
+ *校验任务处理器和cron表达式
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+fn check_job(invoke_target: &str, cron_expression: &str) -> AppResult<()> {
+    if !JOB_HANDLERS.contains_key(invoke_target) {
+        return Err(ErrorCode::JobHandlerNotFound
+            .with("name", invoke_target)
+            .into());
+    }
+    parse_cron(cron_expression)?;
+    Ok(())
+}
+
+/*
+ *下次执行时间(暂停的任务为空)
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+fn job_next_valid_time(status: i8, cron_expression: &str) -> String {
+    if status != 1 {
+        return "".to_string();
+    }
+    next_valid_time(cron_expression)
+}
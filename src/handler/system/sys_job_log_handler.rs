@@ -0,0 +1,138 @@
+use rbatis::plugin::page::PageRequest;
+use rocket::serde::json::{Json, Value};
+
+use crate::common::error::AppResult;
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{
+    ok_result, ok_result_data, ok_result_page, BaseResponse, ResponsePage,
+};
+use crate::middleware::auth::Token;
+use crate::model::system::sys_job_log_model::SysJobLog;
+use crate::utils::time_util::time_to_string;
+use crate::vo::system::sys_job_log_vo::*;
+use crate::RB;
+use rbs::value;
+/*
+ *删除定时任务执行日志表
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/jobLog/deleteJobLog",
+    tag = "任务日志",
+    summary = "删除定时任务执行日志表",
+    request_body = DeleteJobLogReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
+#[post("/system/jobLog/deleteJobLog", data = "<item>")]
+pub async fn delete_sys_job_log(item: Json<DeleteJobLogReq>, _auth: Token) -> AppResult<Value> {
+    log::info!("delete sys_job_log params: {:?}", &item);
+    let rb = &mut RB.clone();
+
+    SysJobLog::delete_by_map(rb, value! {"id": &item.ids}).await?;
+
+    ok_result()
+}
+
+/*
+ *查询定时任务执行日志表详情
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/jobLog/queryJobLogDetail",
+    tag = "任务日志",
+    summary = "查询定时任务执行日志表详情",
+    request_body = QueryJobLogDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<QueryJobLogDetailResp>)),
+    security(("token" = []))
+)]
+#[post("/system/jobLog/queryJobLogDetail", data = "<item>")]
+pub async fn query_sys_job_log_detail(
+    item: Json<QueryJobLogDetailReq>,
+    _auth: Token,
+) -> AppResult<Value> {
+    log::info!("query sys_job_log_detail params: {:?}", &item);
+    let rb = &mut RB.clone();
+
+    match SysJobLog::select_by_id(rb, &item.id).await? {
+        None => Err(ErrorCode::JobLogNotFound.into()),
+        Some(x) => {
+            let sys_job_log = QueryJobLogDetailResp {
+                id: x.id.unwrap_or_default(),                         //任务日志ID
+                job_id: x.job_id,                                     //任务ID
+                job_name: x.job_name,                                 //任务名称
+                job_group: x.job_group,                               //任务组名
+                invoke_target: x.invoke_target,                       //任务处理器名称
+                job_params: x.job_params,                             //任务参数
+                trigger_type: x.trigger_type,                         //触发方式（0：定时,1:手动）
+                status: x.status,           //执行状态（0：失败,1:成功,2:跳过）
+                job_message: x.job_message, //执行结果
+                exception_info: x.exception_info.unwrap_or_default(), //错误信息
+                start_time: time_to_string(x.start_time), //开始时间
+                end_time: time_to_string(x.end_time), //结束时间
+                duration: x.duration,       //耗时(毫秒)
+            };
+
+            ok_result_data(sys_job_log)
+        }
+    }
+}
+
+/*
+ *查询定时任务执行日志表列表
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/jobLog/queryJobLogList",
+    tag = "任务日志",
+    summary = "查询定时任务执行日志表列表",
+    request_body = QueryJobLogListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<JobLogListDataResp>>)),
+    security(("token" = []))
+)]
+#[post("/system/jobLog/queryJobLogList", data = "<item>")]
+pub async fn query_sys_job_log_list(
+    item: Json<QueryJobLogListReq>,
+    _auth: Token,
+) -> AppResult<Value> {
+    log::info!("query sys_job_log_list params: {:?}", &item);
+    let rb = &mut RB.clone();
+
+    let job_id = item.job_id.unwrap_or_default(); //任务ID
+    let job_name = item.job_name.as_deref().unwrap_or_default(); //任务名称
+    let job_group = item.job_group.as_deref().unwrap_or_default(); //任务组名
+    let status = item.status.unwrap_or(3); //执行状态（0：失败,1:成功,2:跳过）
+
+    let page = &PageRequest::new(item.page_no, item.page_size);
+    let d =
+        SysJobLog::select_sys_job_log_list(rb, page, job_id, job_name, job_group, status).await?;
+
+    let mut list: Vec<JobLogListDataResp> = Vec::new();
+
+    let total = d.total;
+
+    for x in d.records {
+        list.push(JobLogListDataResp {
+            id: x.id.unwrap_or_default(),             //任务日志ID
+            job_id: x.job_id,                         //任务ID
+            job_name: x.job_name,                     //任务名称
+            job_group: x.job_group,                   //任务组名
+            invoke_target: x.invoke_target,           //任务处理器名称
+            job_params: x.job_params,                 //任务参数
+            trigger_type: x.trigger_type,             //触发方式（0：定时,1:手动）
+            status: x.status,                         //执行状态（0：失败,1:成功,2:跳过）
+            job_message: x.job_message,               //执行结果
+            start_time: time_to_string(x.start_time), //开始时间
+            end_time: time_to_string(x.end_time),     //结束时间
+            duration: x.duration,                     //耗时(毫秒)
+        })
+    }
+
+    ok_result_page(list, total)
+}
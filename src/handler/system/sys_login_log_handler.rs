@@ -1,19 +1,39 @@
-use crate::common::error::{AppError, AppResult};
-use crate::common::result::{ok_result, ok_result_data, ok_result_page};
+use crate::common::error::AppResult;
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{
+    ok_result, ok_result_cursor_page, ok_result_data, ok_result_page, BaseResponse, ResponsePage,
+};
 use crate::middleware::auth::Token;
-use crate::model::system::sys_login_log_model::{clean_login_log, LoginLog};
-use crate::utils::time_util::time_to_string;
+use crate::model::system::sys_login_log_model::{
+    clean_login_log, count_login_log_by_browser, count_login_log_by_day, count_login_log_by_os,
+    count_login_log_total, count_top_fail_account, count_top_fail_ip, select_new_location_login,
+    LoginLog, LoginLogGroup,
+};
+use crate::utils::cursor_util::{next_cursor, Cursor};
+use crate::utils::sort_util::order_by;
+use crate::utils::time_util::{check_time_range, time_to_string};
 use crate::vo::system::sys_login_log_vo::*;
 use crate::RB;
 use rbatis::plugin::page::PageRequest;
+use rbatis::rbdc::datetime::DateTime;
 use rbs::value;
 use rocket::serde::json::{Json, Value};
+use std::time::Duration;
 
 /*
  *删除系统访问记录
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/loginLog/deleteLoginLog",
+    tag = "登录日志",
+    summary = "删除系统访问记录",
+    request_body = DeleteLoginLogReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/loginLog/deleteLoginLog", data = "<item>")]
 pub async fn delete_sys_login_log(item: Json<DeleteLoginLogReq>, _auth: Token) -> AppResult<Value> {
     log::info!("delete sys_login_log params: {:?}", &item);
@@ -43,6 +63,15 @@ pub async fn clean_sys_login_log(_auth: Token) -> AppResult<Value> {
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/loginLog/queryLoginLogDetail",
+    tag = "登录日志",
+    summary = "查询系统访问记录详情",
+    request_body = QueryLoginLogDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<QueryLoginLogDetailResp>)),
+    security(("token" = []))
+)]
 #[post("/system/loginLog/queryLoginLogDetail", data = "<item>")]
 pub async fn query_sys_login_log_detail(
     item: Json<QueryLoginLogDetailReq>,
@@ -52,7 +81,7 @@ pub async fn query_sys_login_log_detail(
     let rb = &mut RB.clone();
 
     match LoginLog::select_by_id(rb, &item.id).await? {
-        None => Err(AppError::BusinessError("日志不存在")),
+        None => Err(ErrorCode::LoginLogNotFound.into()),
         Some(x) => {
             let sys_login_log = QueryLoginLogDetailResp {
                 id: x.id.unwrap_or_default(),             //访问ID
@@ -82,6 +111,15 @@ pub async fn query_sys_login_log_detail(
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/loginLog/queryLoginLogList",
+    tag = "登录日志",
+    summary = "查询系统访问记录列表",
+    request_body = QueryLoginLogListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<LoginLogListDataResp>>)),
+    security(("token" = []))
+)]
 #[post("/system/loginLog/queryLoginLogList", data = "<item>")]
 pub async fn query_sys_login_log_list(
     item: Json<QueryLoginLogListReq>,
@@ -92,16 +130,70 @@ pub async fn query_sys_login_log_list(
 
     let name = item.login_name.as_deref().unwrap_or_default(); //登录账号
     let ipaddr = item.ipaddr.as_deref().unwrap_or_default(); //登录IP地址
+    let login_location = item.login_location.as_deref().unwrap_or_default(); //登录地点
     let browser = item.browser.as_deref().unwrap_or_default(); //浏览器类型
     let os = item.os.as_deref().unwrap_or_default(); //操作系统
     let status = item.status.unwrap_or(2); //登录状态(0:失败,1:成功)
+    let statuses = item.statuses.clone().filter(|x| !x.is_empty()); //登录状态(多选)
+    let begin_time = item.begin_time.as_deref().unwrap_or_default(); //开始时间
+    let end_time = item.end_time.as_deref().unwrap_or_default(); //结束时间
+    check_time_range(begin_time, end_time)?;
+    let cursor = match item.cursor.as_deref() {
+        Some(x) => Some(Cursor::decode(x)?), //游标分页
+        None => None,                        //页码分页
+    };
+    let with_total = item.with_total.unwrap_or_default(); //游标分页时是否查询总数
 
-    let page = &PageRequest::new(item.page_no, item.page_size);
-    let d = LoginLog::select_login_log_list(rb, page, name, ipaddr, browser, os, &status).await?;
+    let order_by = order_by(
+        item.sort_field.as_deref(),
+        item.sort_order.as_deref(),
+        &[
+            ("id", "id"),
+            ("loginName", "login_name"),
+            ("ipaddr", "ipaddr"),
+            ("browser", "browser"),
+            ("os", "os"),
+            ("status", "status"),
+            ("loginTime", "login_time"),
+        ],
+        "login_time desc",
+    );
+    //排序字段的值可能重复,追加id保证分页结果稳定
+    let order_by = format!("{}, id desc", order_by);
+
+    let (page, order_by) = match cursor {
+        Some(_) => (
+            PageRequest::new(1, item.page_size).set_do_count(with_total),
+            "login_time desc, id desc".to_string(),
+        ),
+        None => (PageRequest::new(item.page_no, item.page_size), order_by),
+    };
+    let (cursor_time, cursor_id) = cursor
+        .as_ref()
+        .map(|x| (x.time.as_str(), x.id))
+        .unwrap_or_default();
+    let d = LoginLog::select_login_log_list(
+        rb,
+        &page,
+        name,
+        ipaddr,
+        login_location,
+        browser,
+        os,
+        &status,
+        &statuses,
+        begin_time,
+        end_time,
+        cursor_time,
+        &cursor_id,
+        &order_by,
+    )
+    .await?;
 
     let mut list: Vec<LoginLogListDataResp> = Vec::new();
 
     let total = d.total;
+    let page_size = d.page_size;
 
     for x in d.records {
         list.push(LoginLogListDataResp {
@@ -123,5 +215,107 @@ pub async fn query_sys_login_log_list(
         })
     }
 
+    if cursor.is_some() {
+        let next_cursor = next_cursor(&list, page_size, |x| Cursor {
+            time: x.login_time.clone(),
+            id: x.id,
+        });
+        return ok_result_cursor_page(list, next_cursor, with_total.then_some(total));
+    }
+
     ok_result_page(list, total)
 }
+
+/*
+ *查询登录统计(每天登录次数、失败排行、浏览器和操作系统分布、异地登录)
+ *author：刘飞华
+ *date：2026/10/19 14:20:05
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/loginLog/queryLoginLogStatistics",
+    tag = "登录日志",
+    summary = "查询登录统计(每天登录次数、失败排行、浏览器和操作系统分布、异地登录)",
+    request_body = QueryLoginLogStatisticsReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<QueryLoginLogStatisticsResp>)),
+    security(("token" = []))
+)]
+#[post("/system/loginLog/queryLoginLogStatistics", data = "<item>")]
+pub async fn query_sys_login_log_statistics(
+    item: Json<QueryLoginLogStatisticsReq>,
+    _auth: Token,
+) -> AppResult<Value> {
+    log::info!("query sys_login_log_statistics params: {:?}", &item);
+    let rb = &mut RB.clone();
+
+    let now = DateTime::now();
+    let begin_time = match item.begin_time.as_deref().unwrap_or_default() {
+        "" => format!(
+            "{} 00:00:00",
+            now.clone()
+                .sub(Duration::from_secs(6 * 24 * 60 * 60))
+                .format("YYYY-MM-DD")
+        ),
+        x => x.to_string(),
+    };
+    let end_time = match item.end_time.as_deref().unwrap_or_default() {
+        "" => now.format("YYYY-MM-DD hh:mm:ss"),
+        x => x.to_string(),
+    };
+    check_time_range(&begin_time, &end_time)?;
+    let top_n = item.top_n.unwrap_or(10).clamp(1, 100);
+
+    let total = count_login_log_total(rb, &begin_time, &end_time).await?;
+
+    let daily_list = count_login_log_by_day(rb, &begin_time, &end_time)
+        .await?
+        .into_iter()
+        .map(|x| LoginDailyResp {
+            login_date: x.login_date,       //登录日期
+            success_count: x.success_count, //登录成功次数
+            fail_count: x.fail_count,       //登录失败次数
+        })
+        .collect::<Vec<LoginDailyResp>>();
+
+    let to_group_resp = |list: Vec<LoginLogGroup>| {
+        list.into_iter()
+            .map(|x| LoginGroupResp {
+                name: x.name,   //分组名称
+                count: x.count, //登录次数
+            })
+            .collect::<Vec<LoginGroupResp>>()
+    };
+
+    let top_fail_account_list =
+        to_group_resp(count_top_fail_account(rb, &begin_time, &end_time, top_n).await?);
+    let top_fail_ip_list =
+        to_group_resp(count_top_fail_ip(rb, &begin_time, &end_time, top_n).await?);
+    let browser_list = to_group_resp(count_login_log_by_browser(rb, &begin_time, &end_time).await?);
+    let os_list = to_group_resp(count_login_log_by_os(rb, &begin_time, &end_time).await?);
+
+    let new_location_list =
+        select_new_location_login(rb, &begin_time, &end_time, &begin_time, top_n)
+            .await?
+            .into_iter()
+            .map(|x| LoginNewLocationResp {
+                login_name: x.login_name,                             //登录账号
+                login_location: x.login_location,                     //登录地点
+                ipaddr: x.ipaddr,                                     //登录IP地址
+                first_login_time: time_to_string(x.first_login_time), //首次登录时间
+            })
+            .collect::<Vec<LoginNewLocationResp>>();
+
+    ok_result_data(QueryLoginLogStatisticsResp {
+        begin_time,
+        end_time,
+        total_count: total.total_count,
+        success_count: total.success_count,
+        fail_count: total.fail_count,
+        daily_list,
+        top_fail_account_list,
+        top_fail_ip_list,
+        browser_list,
+        os_list,
+        new_location_list,
+    })
+}
@@ -1,9 +1,13 @@
 use rbatis::PageRequest;
 use rocket::serde::json::{Json, Value};
 
-use crate::common::error::{AppError, AppResult};
-use crate::common::result::{ok_result, ok_result_data, ok_result_page};
+use crate::common::error::AppResult;
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{
+    ok_result, ok_result_data, ok_result_page, BaseResponse, ResponsePage,
+};
 use crate::middleware::auth::Token;
+use crate::middleware::validate::ValidJson;
 use crate::model::system::sys_menu_model::{select_count_menu_by_parent_id, Menu};
 use crate::model::system::sys_role_menu_model::select_count_menu_by_menu_id;
 use crate::utils::time_util::time_to_string;
@@ -15,15 +19,24 @@ use rbs::value;
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/menu/addMenu",
+    tag = "菜单管理",
+    summary = "添加菜单信息",
+    request_body = AddMenuReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/menu/addMenu", data = "<item>")]
-pub async fn add_sys_menu(item: Json<AddMenuReq>, _auth: Token) -> AppResult<Value> {
+pub async fn add_sys_menu(item: ValidJson<AddMenuReq>, _auth: Token) -> AppResult<Value> {
     log::info!("add sys_menu params: {:?}", &item);
     let rb = &mut RB.clone();
     let req = item.0;
 
     let name = req.menu_name;
     if Menu::select_by_menu_name(rb, &name).await?.is_some() {
-        return Err(AppError::BusinessError("菜单名称已存在"));
+        return Err(ErrorCode::MenuNameExists.into());
     }
 
     let menu_url = req.menu_url.clone();
@@ -32,7 +45,7 @@ pub async fn add_sys_menu(item: Json<AddMenuReq>, _auth: Token) -> AppResult<Val
             .await?
             .is_some()
         {
-            return Err(AppError::BusinessError("路由路径已存在"));
+            return Err(ErrorCode::MenuUrlExists.into());
         }
     }
 
@@ -62,6 +75,15 @@ pub async fn add_sys_menu(item: Json<AddMenuReq>, _auth: Token) -> AppResult<Val
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/menu/deleteMenu",
+    tag = "菜单管理",
+    summary = "删除菜单信息",
+    request_body = DeleteMenuReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/menu/deleteMenu", data = "<item>")]
 pub async fn delete_sys_menu(item: Json<DeleteMenuReq>, _auth: Token) -> AppResult<Value> {
     log::info!("delete sys_menu params: {:?}", &item);
@@ -71,11 +93,11 @@ pub async fn delete_sys_menu(item: Json<DeleteMenuReq>, _auth: Token) -> AppResu
     let ids = req.ids;
     for x in ids.clone() {
         if select_count_menu_by_parent_id(rb, &x).await? > 0 {
-            return Err(AppError::BusinessError("存在子菜单,不允许删除"));
+            return Err(ErrorCode::MenuHasChildren.into());
         }
 
         if select_count_menu_by_menu_id(rb, &x).await? > 0 {
-            return Err(AppError::BusinessError("菜单已分配,不允许删除"));
+            return Err(ErrorCode::MenuInUse.into());
         }
     }
 
@@ -89,19 +111,28 @@ pub async fn delete_sys_menu(item: Json<DeleteMenuReq>, _auth: Token) -> AppResu
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/menu/updateMenu",
+    tag = "菜单管理",
+    summary = "更新菜单信息",
+    request_body = UpdateMenuReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/menu/updateMenu", data = "<item>")]
-pub async fn update_sys_menu(item: Json<UpdateMenuReq>, _auth: Token) -> AppResult<Value> {
+pub async fn update_sys_menu(item: ValidJson<UpdateMenuReq>, _auth: Token) -> AppResult<Value> {
     log::info!("update sys_menu params: {:?}", &item);
     let rb = &mut RB.clone();
     let req = item.0;
 
     if Menu::select_by_id(rb, &req.id).await?.is_none() {
-        return Err(AppError::BusinessError("菜单信息不存在"));
+        return Err(ErrorCode::MenuNotFound.into());
     }
 
     if let Some(x) = Menu::select_by_menu_name(rb, &req.menu_name).await? {
         if x.id.unwrap_or_default() != req.id {
-            return Err(AppError::BusinessError("菜单名称已存在"));
+            return Err(ErrorCode::MenuNameExists.into());
         }
     }
 
@@ -109,7 +140,7 @@ pub async fn update_sys_menu(item: Json<UpdateMenuReq>, _auth: Token) -> AppResu
     if menu_url.is_some() {
         if let Some(x) = Menu::select_by_menu_url(rb, &menu_url.unwrap()).await? {
             if x.id.unwrap_or_default() != req.id {
-                return Err(AppError::BusinessError("路由路径已存在"));
+                return Err(ErrorCode::MenuUrlExists.into());
             }
         }
     }
@@ -140,9 +171,18 @@ pub async fn update_sys_menu(item: Json<UpdateMenuReq>, _auth: Token) -> AppResu
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/menu/updateMenuStatus",
+    tag = "菜单管理",
+    summary = "更新菜单信息状态",
+    request_body = UpdateMenuStatusReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/menu/updateMenuStatus", data = "<item>")]
 pub async fn update_sys_menu_status(
-    item: Json<UpdateMenuStatusReq>,
+    item: ValidJson<UpdateMenuStatusReq>,
     _auth: Token,
 ) -> AppResult<Value> {
     log::info!("update sys_menu_status params: {:?}", &item);
@@ -170,6 +210,15 @@ pub async fn update_sys_menu_status(
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/menu/queryMenuDetail",
+    tag = "菜单管理",
+    summary = "查询菜单信息详情",
+    request_body = QueryMenuDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<QueryMenuDetailResp>)),
+    security(("token" = []))
+)]
 #[post("/system/menu/queryMenuDetail", data = "<item>")]
 pub async fn query_sys_menu_detail(
     item: Json<QueryMenuDetailReq>,
@@ -179,7 +228,7 @@ pub async fn query_sys_menu_detail(
     let rb = &mut RB.clone();
 
     match Menu::select_by_id(rb, &item.id).await? {
-        None => Err(AppError::BusinessError("菜单信息不存在")),
+        None => Err(ErrorCode::MenuNotFound.into()),
         Some(x) => {
             let sys_menu = QueryMenuDetailResp {
                 id: x.id.unwrap_or_default(),               //主键
@@ -207,6 +256,15 @@ pub async fn query_sys_menu_detail(
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/menu/queryMenuList",
+    tag = "菜单管理",
+    summary = "查询菜单信息列表",
+    request_body = QueryMenuListReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<Vec<MenuListDataResp>>)),
+    security(("token" = []))
+)]
 #[post("/system/menu/queryMenuList", data = "<item>")]
 pub async fn query_sys_menu_list(item: Json<QueryMenuListReq>, _auth: Token) -> AppResult<Value> {
     log::info!("query sys_menu_list params: {:?}", &item);
@@ -246,6 +304,14 @@ pub async fn query_sys_menu_list(item: Json<QueryMenuListReq>, _auth: Token) ->
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/menu/queryMenuListSimple",
+    tag = "菜单管理",
+    summary = "查询菜单信息(排除按钮)",
+    responses((status = 200, description = "操作成功", body = BaseResponse<Vec<MenuListSimpleDataResp>>)),
+    security(("token" = []))
+)]
 #[post("/system/menu/queryMenuListSimple")]
 pub async fn query_sys_menu_list_simple(_auth: Token) -> AppResult<Value> {
     let rb = &mut RB.clone();
@@ -269,6 +335,15 @@ pub async fn query_sys_menu_list_simple(_auth: Token) -> AppResult<Value> {
  * author：刘飞华
  * date：2025/10/24 10:16
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/menu/queryMenuResourceList",
+    tag = "菜单管理",
+    summary = "描述：查询菜单资源",
+    request_body = QueryMenuListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<MenuListDataResp>>)),
+    security(("token" = []))
+)]
 #[post("/system/menu/queryMenuResourceList", data = "<item>")]
 pub async fn query_sys_menu_resource_list(
     item: Json<QueryMenuListReq>,
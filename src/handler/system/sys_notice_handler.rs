@@ -1,11 +1,33 @@
+use rbatis::executor::Executor;
 use rbatis::plugin::page::PageRequest;
+use rbatis::rbatis_codegen::ops::AsProxy;
+use rbatis::rbdc::datetime::DateTime;
+use rbatis::RBatis;
+use rocket::serde::json::serde_json::json;
 use rocket::serde::json::{Json, Value};
+use rocket::tokio::time::{interval, Duration};
 
-use crate::common::error::{AppError, AppResult};
-use crate::common::result::{ok_result, ok_result_data, ok_result_page};
+use crate::common::error::AppResult;
+use crate::common::error_code::ErrorCode;
+use crate::common::event_hub::{EVENT_HUB, EVENT_NOTICE};
+use crate::common::result::{
+    ok_result, ok_result_data, ok_result_page, BaseResponse, ResponsePage,
+};
+use crate::handler::system::sys_file_handler::file_download_url;
 use crate::middleware::auth::Token;
-use crate::model::system::sys_notice_model::Notice;
-use crate::utils::time_util::time_to_string;
+use crate::model::system::sys_file_model::SysFile;
+use crate::model::system::sys_notice_attachment_model::NoticeAttachment;
+use crate::model::system::sys_notice_model::{
+    expire_notice, select_db_now, select_due_notice, update_notice_schedule, Notice,
+};
+use crate::model::system::sys_notice_target_model::NoticeTarget;
+use crate::model::system::sys_notice_user_model::{
+    count_my_notice_list, count_notice_read, count_unread_notice, deliver_notice, read_all_notice,
+    read_notice, select_my_notice_list, NoticeUser,
+};
+use crate::utils::html_util::{is_safe_url, sanitize_html};
+use crate::utils::sort_util::order_by;
+use crate::utils::time_util::{check_time_range, string_to_time, time_to_string};
 use crate::vo::system::sys_notice_vo::*;
 use crate::RB;
 use rbs::value;
@@ -14,6 +36,15 @@ use rbs::value;
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/notice/addNotice",
+    tag = "通知公告",
+    summary = "添加通知公告表",
+    request_body = AddNoticeReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/notice/addNotice", data = "<item>")]
 pub async fn add_sys_notice(item: Json<AddNoticeReq>, _auth: Token) -> AppResult<Value> {
     log::info!("add sys_notice params: {:?}", &item);
@@ -22,21 +53,45 @@ pub async fn add_sys_notice(item: Json<AddNoticeReq>, _auth: Token) -> AppResult
 
     let title = req.notice_title;
     if Notice::select_by_title(rb, &title).await?.is_some() {
-        return Err(AppError::BusinessError("公告标题已存在"));
+        return Err(ErrorCode::NoticeTitleExists.into());
     };
 
+    let target_type = req.target_type.unwrap_or_default();
+    let target_ids = req.target_ids.unwrap_or_default();
+    check_notice_target(target_type, &target_ids)?;
+    let attachments = check_notice_attachment(rb, &req.attachments.unwrap_or_default()).await?;
+    let now = select_db_now(rb).await?;
+    let (status, publish_at, expire_at) =
+        check_notice_schedule(req.status, req.publish_at, req.expire_at, &now)?;
+
     let sys_notice = Notice {
-        id: None,                               //公告ID
-        notice_title: title,                    //公告标题
-        notice_type: req.notice_type,           //公告类型（1:通知,2:公告）
-        notice_content: req.notice_content,     //公告内容
-        status: req.status,                     //公告状态（0:关闭,1:正常 ）
+        id: None,                                           //公告ID
+        notice_title: title,                                //公告标题
+        notice_type: req.notice_type,                       //公告类型（1:通知,2:公告）
+        notice_content: sanitize_html(&req.notice_content), //公告内容
+        status,                                             //公告状态（0:关闭,1:正常 ）
+        target_type, //通知对象类型（0:全部用户,1:部门,2:角色,3:用户）
+        publish_at,  //发布时间
+        expire_at,   //过期时间
         remark: req.remark.unwrap_or_default(), //备注
-        create_time: None,                      //创建时间
-        update_time: None,                      //修改时间
+        create_time: None, //创建时间
+        update_time: None, //修改时间
     };
 
-    Notice::insert(rb, &sys_notice).await?;
+    let tx = rb.acquire_begin().await?;
+    let id = match save_notice(&tx, &sys_notice, &target_ids, attachments).await {
+        Ok(id) => id,
+        Err(e) => {
+            tx.rollback().await?;
+            return Err(e);
+        }
+    };
+    tx.commit().await?;
+
+    if sys_notice.status == 1 {
+        push_notice(id, &sys_notice).await?;
+    }
+
     ok_result()
 }
 
@@ -45,12 +100,26 @@ pub async fn add_sys_notice(item: Json<AddNoticeReq>, _auth: Token) -> AppResult
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/notice/deleteNotice",
+    tag = "通知公告",
+    summary = "删除通知公告表",
+    request_body = DeleteNoticeReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/notice/deleteNotice", data = "<item>")]
 pub async fn delete_sys_notice(item: Json<DeleteNoticeReq>, _auth: Token) -> AppResult<Value> {
     log::info!("delete sys_notice params: {:?}", &item);
     let rb = &mut RB.clone();
 
-    Notice::delete_by_map(rb, value! {"id": &item.ids}).await?;
+    let tx = rb.acquire_begin().await?;
+    if let Err(e) = delete_notice(&tx, &item.ids).await {
+        tx.rollback().await?;
+        return Err(e);
+    }
+    tx.commit().await?;
     ok_result()
 }
 
@@ -59,6 +128,15 @@ pub async fn delete_sys_notice(item: Json<DeleteNoticeReq>, _auth: Token) -> App
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/notice/updateNotice",
+    tag = "通知公告",
+    summary = "更新通知公告表",
+    request_body = UpdateNoticeReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/notice/updateNotice", data = "<item>")]
 pub async fn update_sys_notice(item: Json<UpdateNoticeReq>, _auth: Token) -> AppResult<Value> {
     log::info!("update sys_notice params: {:?}", &item);
@@ -66,27 +144,48 @@ pub async fn update_sys_notice(item: Json<UpdateNoticeReq>, _auth: Token) -> App
     let req = item.0;
 
     if Notice::select_by_id(rb, &req.id).await?.is_none() {
-        return Err(AppError::BusinessError("通知公告表不存在"));
+        return Err(ErrorCode::NoticeNotFound.into());
     }
 
     if let Some(x) = Notice::select_by_title(rb, &req.notice_title).await? {
         if x.id.unwrap_or_default() != req.id {
-            return Err(AppError::BusinessError("公告标题已存在"));
+            return Err(ErrorCode::NoticeTitleExists.into());
         }
     }
 
+    let target_type = req.target_type.unwrap_or_default();
+    let target_ids = req.target_ids.unwrap_or_default();
+    check_notice_target(target_type, &target_ids)?;
+    let attachments = check_notice_attachment(rb, &req.attachments.unwrap_or_default()).await?;
+    let now = select_db_now(rb).await?;
+    let (status, publish_at, expire_at) =
+        check_notice_schedule(req.status, req.publish_at, req.expire_at, &now)?;
+
     let sys_notice = Notice {
-        id: Some(req.id),                       //公告ID
-        notice_title: req.notice_title,         //公告标题
-        notice_type: req.notice_type,           //公告类型（1:通知,2:公告）
-        notice_content: req.notice_content,     //公告内容
-        status: req.status,                     //公告状态（0:关闭,1:正常 ）
+        id: Some(req.id),                                   //公告ID
+        notice_title: req.notice_title,                     //公告标题
+        notice_type: req.notice_type,                       //公告类型（1:通知,2:公告）
+        notice_content: sanitize_html(&req.notice_content), //公告内容
+        status,                                             //公告状态（0:关闭,1:正常 ）
+        target_type, //通知对象类型（0:全部用户,1:部门,2:角色,3:用户）
+        publish_at,  //发布时间
+        expire_at,   //过期时间
         remark: req.remark.unwrap_or_default(), //备注
-        create_time: None,                      //创建时间
-        update_time: None,                      //修改时间
+        create_time: None, //创建时间
+        update_time: None, //修改时间
     };
 
-    Notice::update_by_map(rb, &sys_notice, value! {"id": &sys_notice.id}).await?;
+    let tx = rb.acquire_begin().await?;
+    if let Err(e) = save_notice(&tx, &sys_notice, &target_ids, attachments).await {
+        tx.rollback().await?;
+        return Err(e);
+    }
+    tx.commit().await?;
+
+    if sys_notice.status == 1 {
+        push_notice(req.id, &sys_notice).await?;
+    }
+
     ok_result()
 }
 
@@ -95,6 +194,15 @@ pub async fn update_sys_notice(item: Json<UpdateNoticeReq>, _auth: Token) -> App
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/notice/updateNoticeStatus",
+    tag = "通知公告",
+    summary = "更新通知公告表状态",
+    request_body = UpdateNoticeStatusReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/notice/updateNoticeStatus", data = "<item>")]
 pub async fn update_sys_notice_status(
     item: Json<UpdateNoticeStatusReq>,
@@ -104,8 +212,15 @@ pub async fn update_sys_notice_status(
     let rb = &mut RB.clone();
     let req = item.0;
 
+    //手动开启时立即发布(已过期的清空过期时间),手动关闭时取消定时发布
+    let set_sql = if req.status == 1 {
+        "publish_at = if(publish_at is null or publish_at > now(), now(), publish_at), expire_at = if(expire_at <= now(), null, expire_at)"
+    } else {
+        "publish_at = null"
+    };
     let update_sql = format!(
-        "update sys_notice set status = ? where id in ({})",
+        "update sys_notice set status = ?, {} where id in ({})",
+        set_sql,
         req.ids
             .iter()
             .map(|_| "?")
@@ -117,6 +232,17 @@ pub async fn update_sys_notice_status(
     param.extend(req.ids.iter().map(|&id| value!(id)));
 
     rb.exec(&update_sql, param).await?;
+
+    if req.status == 1 {
+        for id in req.ids {
+            if let Some(x) = Notice::select_by_id(rb, &id).await? {
+                let target_ids = query_notice_target_ids(id).await?;
+                deliver_notice(rb, id, x.target_type, &target_ids).await?;
+                push_notice(id, &x).await?;
+            }
+        }
+    }
+
     ok_result()
 }
 
@@ -125,6 +251,15 @@ pub async fn update_sys_notice_status(
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/notice/queryNoticeDetail",
+    tag = "通知公告",
+    summary = "查询通知公告表详情",
+    request_body = QueryNoticeDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<QueryNoticeDetailResp>)),
+    security(("token" = []))
+)]
 #[post("/system/notice/queryNoticeDetail", data = "<item>")]
 pub async fn query_sys_notice_detail(
     item: Json<QueryNoticeDetailReq>,
@@ -134,15 +269,32 @@ pub async fn query_sys_notice_detail(
     let rb = &mut RB.clone();
 
     match Notice::select_by_id(rb, &item.id).await? {
-        None => Err(AppError::BusinessError("通知公告表不存在")),
+        None => Err(ErrorCode::NoticeNotFound.into()),
         Some(x) => {
+            let target_ids = query_notice_target_ids(item.id).await?;
+
+            let mut attachments: Vec<NoticeAttachmentResp> = Vec::new();
+            for a in NoticeAttachment::select_by_notice_id(rb, item.id).await? {
+                attachments.push(NoticeAttachmentResp {
+                    id: a.id.unwrap_or_default(), //附件ID
+                    file_id: a.file_id,           //文件ID
+                    file_name: a.file_name,       //文件名称
+                    file_url: a.file_url,         //文件地址(下载链接)
+                })
+            }
+
             let sys_notice = QueryNoticeDetailResp {
                 id: x.id.unwrap_or_default(),               //公告ID
                 notice_title: x.notice_title,               //公告标题
                 notice_type: x.notice_type,                 //公告类型（1:通知,2:公告）
                 notice_content: x.notice_content,           //公告内容
                 status: x.status,                           //公告状态（0:关闭,1:正常 ）
-                remark: x.remark,                           //备注
+                target_type: x.target_type, //通知对象类型（0:全部用户,1:部门,2:角色,3:用户）
+                target_ids,                 //通知对象ID(部门ID、角色ID或用户ID)
+                publish_at: time_to_string(x.publish_at), //发布时间
+                expire_at: time_to_string(x.expire_at), //过期时间
+                attachments,                //附件
+                remark: x.remark,           //备注
                 create_time: time_to_string(x.create_time), //创建时间
                 update_time: time_to_string(x.update_time), //修改时间
             };
@@ -157,6 +309,15 @@ pub async fn query_sys_notice_detail(
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/notice/queryNoticeList",
+    tag = "通知公告",
+    summary = "查询通知公告表列表",
+    request_body = QueryNoticeListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<NoticeListDataResp>>)),
+    security(("token" = []))
+)]
 #[post("/system/notice/queryNoticeList", data = "<item>")]
 pub async fn query_sys_notice_list(
     item: Json<QueryNoticeListReq>,
@@ -168,11 +329,43 @@ pub async fn query_sys_notice_list(
     let notice_title = item.notice_title.as_deref().unwrap_or_default();
     let notice_type = item.notice_type.unwrap_or(0); //公告类型（1:通知,2:公告）
     let status = item.status.unwrap_or(2); //公告状态（0:关闭,1:正常 ）
+    let publish_state = item.publish_state.unwrap_or(0); //发布状态（1:草稿,2:待发布,3:已发布,4:已过期）
+
+    let keyword = item.keyword.as_deref().unwrap_or_default().trim(); //关键字(模糊搜索)
+    let begin_time = item.begin_time.as_deref().unwrap_or_default(); //创建时间(开始)
+    let end_time = item.end_time.as_deref().unwrap_or_default(); //创建时间(结束)
+    check_time_range(begin_time, end_time)?;
+
+    let order_by = order_by(
+        item.sort_field.as_deref(),
+        item.sort_order.as_deref(),
+        &[
+            ("id", "id"),
+            ("noticeTitle", "notice_title"),
+            ("noticeType", "notice_type"),
+            ("status", "status"),
+            ("publishAt", "publish_at"),
+            ("createTime", "create_time"),
+        ],
+        "create_time desc",
+    );
 
     let page = &PageRequest::new(item.page_no, item.page_size);
 
     let mut data: Vec<NoticeListDataResp> = Vec::new();
-    let d = Notice::select_sys_notice_list(rb, page, notice_title, notice_type, status).await?;
+    let d = Notice::select_sys_notice_list(
+        rb,
+        page,
+        notice_title,
+        notice_type,
+        status,
+        publish_state,
+        keyword,
+        begin_time,
+        end_time,
+        &order_by,
+    )
+    .await?;
     let total = d.total;
 
     for x in d.records {
@@ -182,7 +375,10 @@ pub async fn query_sys_notice_list(
             notice_type: x.notice_type,                 //公告类型（1:通知,2:公告）
             notice_content: x.notice_content,           //公告内容
             status: x.status,                           //公告状态（0:关闭,1:正常 ）
-            remark: x.remark,                           //备注
+            target_type: x.target_type, //通知对象类型（0:全部用户,1:部门,2:角色,3:用户）
+            publish_at: time_to_string(x.publish_at), //发布时间
+            expire_at: time_to_string(x.expire_at), //过期时间
+            remark: x.remark,           //备注
             create_time: time_to_string(x.create_time), //创建时间
             update_time: time_to_string(x.update_time), //修改时间
         })
@@ -190,3 +386,428 @@ pub async fn query_sys_notice_list(
 
     ok_result_page(data, total)
 }
+
+/*
+ *查询我的通知公告列表
+ *author：刘飞华
+ *date：2026/10/19 15:02:47
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/notice/queryMyNoticeList",
+    tag = "通知公告",
+    summary = "查询我的通知公告列表",
+    request_body = QueryMyNoticeListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<MyNoticeListDataResp>>)),
+    security(("token" = []))
+)]
+#[post("/system/notice/queryMyNoticeList", data = "<item>")]
+pub async fn query_my_notice_list(
+    item: Json<QueryMyNoticeListReq>,
+    auth: Token,
+) -> AppResult<Value> {
+    log::info!("query my_notice_list params: {:?}", &item);
+    let rb = &mut RB.clone();
+
+    let user_id = auth.id;
+    let notice_type = item.notice_type.unwrap_or(0); //公告类型（1:通知,2:公告）
+    let read_flag = item.read_flag.unwrap_or(2); //阅读状态（0:未读,1:已读）
+
+    let page_no = (item.page_no.max(1) - 1) * item.page_size;
+    let d =
+        select_my_notice_list(rb, user_id, read_flag, notice_type, page_no, item.page_size).await?;
+
+    let mut data: Vec<MyNoticeListDataResp> = Vec::new();
+    for x in d {
+        data.push(MyNoticeListDataResp {
+            id: x.id,                                       //公告ID
+            notice_title: x.notice_title,                   //公告标题
+            notice_type: x.notice_type,                     //公告类型（1:通知,2:公告）
+            notice_content: x.notice_content,               //公告内容
+            read_flag: x.read_flag,                         //阅读状态（0:未读,1:已读）
+            delivery_time: time_to_string(x.delivery_time), //送达时间
+            read_time: time_to_string(x.read_time),         //阅读时间
+        })
+    }
+
+    let total = count_my_notice_list(rb, user_id, read_flag, notice_type).await?;
+    ok_result_page(data, total)
+}
+
+/*
+ *查询我的未读通知公告数量
+ *author：刘飞华
+ *date：2026/10/19 15:02:47
+ */
+#[utoipa::path(
+    get,
+    path = "/api/system/notice/queryMyUnreadCount",
+    tag = "通知公告",
+    summary = "查询我的未读通知公告数量",
+    responses((status = 200, description = "操作成功", body = BaseResponse<u64>)),
+    security(("token" = []))
+)]
+#[get("/system/notice/queryMyUnreadCount")]
+pub async fn query_my_unread_count(auth: Token) -> AppResult<Value> {
+    log::info!("query my_unread_count user_id: {:?}", auth.id);
+    let rb = &mut RB.clone();
+
+    ok_result_data(count_unread_notice(rb, auth.id).await?)
+}
+
+/*
+ *标记通知公告为已读
+ *author：刘飞华
+ *date：2026/10/19 15:02:47
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/notice/readNotice",
+    tag = "通知公告",
+    summary = "标记通知公告为已读",
+    request_body = ReadNoticeReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
+#[post("/system/notice/readNotice", data = "<item>")]
+pub async fn read_sys_notice(item: Json<ReadNoticeReq>, auth: Token) -> AppResult<Value> {
+    log::info!("read sys_notice params: {:?}", &item);
+    let rb = &mut RB.clone();
+
+    if item.ids.is_empty() {
+        return ok_result();
+    }
+
+    read_notice(rb, auth.id, &item.ids).await?;
+    ok_result()
+}
+
+/*
+ *标记全部通知公告为已读
+ *author：刘飞华
+ *date：2026/10/19 15:02:47
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/notice/readAllNotice",
+    tag = "通知公告",
+    summary = "标记全部通知公告为已读",
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
+#[post("/system/notice/readAllNotice")]
+pub async fn read_all_sys_notice(auth: Token) -> AppResult<Value> {
+    log::info!("read all sys_notice user_id: {:?}", auth.id);
+    let rb = &mut RB.clone();
+
+    read_all_notice(rb, auth.id).await?;
+    ok_result()
+}
+
+/*
+ *查询通知公告阅读统计
+ *author：刘飞华
+ *date：2026/10/19 15:02:47
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/notice/queryNoticeReadStatistics",
+    tag = "通知公告",
+    summary = "查询通知公告阅读统计",
+    request_body = QueryNoticeReadStatisticsReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<QueryNoticeReadStatisticsResp>)),
+    security(("token" = []))
+)]
+#[post("/system/notice/queryNoticeReadStatistics", data = "<item>")]
+pub async fn query_notice_read_statistics(
+    item: Json<QueryNoticeReadStatisticsReq>,
+    _auth: Token,
+) -> AppResult<Value> {
+    log::info!("query notice_read_statistics params: {:?}", &item);
+    let rb = &mut RB.clone();
+
+    if Notice::select_by_id(rb, &item.id).await?.is_none() {
+        return Err(ErrorCode::NoticeNotFound.into());
+    }
+
+    let x = count_notice_read(rb, item.id).await?;
+
+    ok_result_data(QueryNoticeReadStatisticsResp {
+        id: item.id,                  //公告ID
+        total_count: x.total_count,   //送达人数
+        read_count: x.read_count,     //已读人数
+        unread_count: x.unread_count, //未读人数
+    })
+}
+
+/*
+ *校验通知对象
+ *author：刘飞华
+ *date：2026/10/19 15:02:47
+ */
+fn check_notice_target(target_type: i8, target_ids: &[i64]) -> AppResult<()> {
+    match target_type {
+        0 => Ok(()),
+        1..=3 if target_ids.is_empty() => Err(ErrorCode::NoticeTargetEmpty.into()),
+        1..=3 => Ok(()),
+        _ => Err(ErrorCode::NoticeTargetTypeInvalid.into()),
+    }
+}
+
+/*
+ *校验通知公告的发布时间和过期时间,返回实际保存的状态、发布时间和过期时间
+ *发布时间晚于当前时间时先保存为关闭状态,由定时任务到点发布
+ *now: 数据库的当前时间(和定时任务使用同一个时钟)
+ *author：刘飞华
+ *date：2026/10/19 17:05:22
+ */
+fn check_notice_schedule(
+    status: i8,
+    publish_at: Option<String>,
+    expire_at: Option<String>,
+    now: &str,
+) -> AppResult<(i8, Option<DateTime>, Option<DateTime>)> {
+    let publish_at = match publish_at.filter(|x| !x.is_empty()) {
+        None => None,
+        Some(x) => match string_to_time(&x) {
+            None => return Err(ErrorCode::NoticePublishAtInvalid.into()),
+            Some(t) => Some(t),
+        },
+    };
+    let expire_at = match expire_at.filter(|x| !x.is_empty()) {
+        None => None,
+        Some(x) => match string_to_time(&x) {
+            None => return Err(ErrorCode::NoticeExpireAtInvalid.into()),
+            Some(t) => Some(t),
+        },
+    };
+
+    //未指定发布时间的公告开启时立即发布,关闭时为草稿
+    let publish_at = match publish_at {
+        None if status == 1 => string_to_time(now),
+        x => x,
+    };
+
+    //统一按字符串比较,避免时区偏移不同导致比较出错
+    let now = now.to_string();
+    let publish_time = time_to_string(publish_at.clone());
+    let expire_time = time_to_string(expire_at.clone());
+
+    if !expire_time.is_empty() {
+        let begin = if publish_time.is_empty() {
+            &now
+        } else {
+            &publish_time
+        };
+        if expire_time <= *begin {
+            return Err(ErrorCode::NoticeExpireBeforePublish.into());
+        }
+    }
+
+    //发布时间已到时按请求的状态保存,关闭时清空发布时间(和手动关闭一致,避免定时任务重新发布)
+    let due = !publish_time.is_empty() && publish_time <= now;
+    match (due, status) {
+        (true, 1) => Ok((1, publish_at, expire_at)),
+        (true, _) => Ok((0, None, expire_at)),
+        (false, _) => Ok((0, publish_at, expire_at)),
+    }
+}
+
+/*
+ *校验通知公告附件(上传的文件通过文件ID关联,外部链接只允许http、https和站内相对路径),返回待保存的附件
+ *author：刘飞华
+ *date：2026/10/19 17:48:05
+ */
+async fn check_notice_attachment(
+    rb: &RBatis,
+    attachments: &[NoticeAttachmentReq],
+) -> AppResult<Vec<NoticeAttachment>> {
+    let mut list: Vec<NoticeAttachment> = Vec::new();
+    for (i, x) in attachments.iter().enumerate() {
+        let file_name = x.file_name.clone().unwrap_or_default();
+        let (file_name, file_url) = match x.file_id {
+            None => {
+                if file_name.trim().is_empty() {
+                    return Err(ErrorCode::NoticeAttachmentNameEmpty.into());
+                }
+                let file_url = x.file_url.clone().unwrap_or_default();
+                if !is_safe_url(&file_url) {
+                    return Err(ErrorCode::NoticeAttachmentUrlInvalid.into());
+                }
+                (file_name, file_url)
+            }
+            Some(file_id) => match SysFile::select_by_id(rb, file_id).await? {
+                None => return Err(ErrorCode::NoticeAttachmentNotFound.into()),
+                Some(f) if file_name.trim().is_empty() => (f.file_name, file_download_url(file_id)),
+                Some(_) => (file_name, file_download_url(file_id)),
+            },
+        };
+
+        list.push(NoticeAttachment {
+            id: None,                           //主键
+            notice_id: 0,                       //公告ID(保存时设置)
+            file_id: x.file_id,                 //文件ID
+            file_name,                          //文件名称
+            file_url,                           //文件地址
+            sort: i as i32,                     //排序
+            create_time: Some(DateTime::now()), //创建时间
+        })
+    }
+    Ok(list)
+}
+
+/*
+ *保存通知公告及通知对象、附件(在事务中调用;新增时id为空,更新时先删除原来的通知对象和附件;已开启的公告同时送达通知对象)
+ *author：刘飞华
+ *date：2026/10/20 11:32:18
+ */
+async fn save_notice(
+    tx: &dyn Executor,
+    notice: &Notice,
+    target_ids: &[i64],
+    mut attachments: Vec<NoticeAttachment>,
+) -> AppResult<i64> {
+    let id = match notice.id {
+        None => Notice::insert(tx, notice).await?.last_insert_id.i64(),
+        Some(id) => {
+            Notice::update_by_map(tx, notice, value! {"id": id}).await?;
+            //update_by_map会跳过空值,发布时间和过期时间需要单独更新才能清空
+            update_notice_schedule(
+                tx,
+                notice.status,
+                notice.publish_at.clone(),
+                notice.expire_at.clone(),
+                id,
+            )
+            .await?;
+            NoticeTarget::delete_by_map(tx, value! {"notice_id": id}).await?;
+            NoticeAttachment::delete_by_map(tx, value! {"notice_id": id}).await?;
+            id
+        }
+    };
+
+    let targets: Vec<NoticeTarget> = target_ids
+        .iter()
+        .map(|&target_id| NoticeTarget {
+            notice_id: id,
+            target_id,
+        })
+        .collect();
+    if !targets.is_empty() {
+        NoticeTarget::insert_batch(tx, &targets, targets.len() as u64).await?;
+    }
+
+    for x in attachments.iter_mut() {
+        x.notice_id = id;
+    }
+    if !attachments.is_empty() {
+        NoticeAttachment::insert_batch(tx, &attachments, attachments.len() as u64).await?;
+    }
+
+    //已送达的用户保留送达和阅读记录,只给新增的通知对象补发
+    if notice.status == 1 {
+        deliver_notice(tx, id, notice.target_type, target_ids).await?;
+    }
+    Ok(id)
+}
+
+/*
+ *删除通知公告及通知对象、附件和送达记录(在事务中调用)
+ *author：刘飞华
+ *date：2026/10/20 11:32:18
+ */
+async fn delete_notice(tx: &dyn Executor, ids: &[i64]) -> AppResult<()> {
+    NoticeTarget::delete_by_map(tx, value! {"notice_id": ids}).await?;
+    NoticeAttachment::delete_by_map(tx, value! {"notice_id": ids}).await?;
+    NoticeUser::delete_by_map(tx, value! {"notice_id": ids}).await?;
+    Notice::delete_by_map(tx, value! {"id": ids}).await?;
+    Ok(())
+}
+
+/*
+ *查询通知对象ID
+ *author：刘飞华
+ *date：2026/10/19 15:02:47
+ */
+async fn query_notice_target_ids(notice_id: i64) -> AppResult<Vec<i64>> {
+    let list = NoticeTarget::select_by_map(&RB.clone(), value! {"notice_id": &notice_id})
+        .await?
+        .iter()
+        .map(|x| x.target_id)
+        .collect::<Vec<i64>>();
+
+    Ok(list)
+}
+
+/*
+ *推送新发布的通知公告给在线用户
+ *author：刘飞华
+ *date：2026/10/19 16:31:08
+ */
+async fn push_notice(notice_id: i64, notice: &Notice) -> AppResult<()> {
+    let data = json!({
+        "id": notice_id,
+        "noticeTitle": notice.notice_title,
+        "noticeType": notice.notice_type,
+    });
+
+    if notice.target_type == 0 {
+        EVENT_HUB.publish_all(EVENT_NOTICE, data);
+        return Ok(());
+    }
+
+    let user_ids = NoticeUser::select_by_map(&RB.clone(), value! {"notice_id": &notice_id})
+        .await?
+        .iter()
+        .map(|x| x.user_id)
+        .collect::<Vec<i64>>();
+    EVENT_HUB.publish_users(user_ids, EVENT_NOTICE, data);
+    Ok(())
+}
+
+/*
+ *通知公告定时任务(到点发布定时公告,关闭已过期公告)
+ *author：刘飞华
+ *date：2026/10/19 17:05:22
+ */
+pub async fn run_notice_scheduler() {
+    let mut ticker = interval(Duration::from_secs(30));
+    loop {
+        ticker.tick().await;
+        if let Err(err) = publish_due_notice().await {
+            log::error!("publish due notice error: {}", err);
+        }
+        match expire_notice(&RB.clone()).await {
+            Ok(x) if x.rows_affected > 0 => log::info!("expire notice: {}", x.rows_affected),
+            Ok(_) => {}
+            Err(err) => log::error!("expire notice error: {}", err),
+        }
+    }
+}
+
+/*
+ *发布到达发布时间的通知公告
+ *author：刘飞华
+ *date：2026/10/19 17:05:22
+ */
+async fn publish_due_notice() -> AppResult<()> {
+    let rb = &RB.clone();
+
+    for mut x in select_due_notice(rb).await? {
+        let id = x.id.unwrap_or_default();
+        log::info!("publish scheduled notice: {}", id);
+
+        //多个实例同时执行时,只有修改成功的实例推送通知
+        x.status = 1;
+        let update_sql = "update sys_notice set status = 1 where id = ? and status = 0";
+        if rb.exec(update_sql, vec![value!(id)]).await?.rows_affected == 0 {
+            continue;
+        }
+
+        let target_ids = query_notice_target_ids(id).await?;
+        deliver_notice(rb, id, x.target_type, &target_ids).await?;
+        push_notice(id, &x).await?;
+    }
+
+    Ok(())
+}
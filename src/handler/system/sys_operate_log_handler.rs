@@ -1,8 +1,13 @@
-use crate::common::error::{AppError, AppResult};
-use crate::common::result::{ok_result, ok_result_data, ok_result_page};
+use crate::common::error::AppResult;
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{
+    ok_result, ok_result_cursor_page, ok_result_data, ok_result_page, BaseResponse, ResponsePage,
+};
 use crate::middleware::auth::Token;
 use crate::model::system::sys_operate_log_model::{clean_operate_log, OperateLog};
-use crate::utils::time_util::time_to_string;
+use crate::utils::cursor_util::{next_cursor, Cursor};
+use crate::utils::sort_util::order_by;
+use crate::utils::time_util::{check_time_range, time_to_string};
 use crate::vo::system::sys_operate_log_vo::*;
 use crate::RB;
 use rbatis::plugin::page::PageRequest;
@@ -14,6 +19,15 @@ use rocket::serde::json::{Json, Value};
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/operateLog/deleteOperateLog",
+    tag = "操作日志",
+    summary = "删除操作日志记录",
+    request_body = DeleteOperateLogReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/operateLog/deleteOperateLog", data = "<item>")]
 pub async fn delete_sys_operate_log(
     item: Json<DeleteOperateLogReq>,
@@ -46,6 +60,15 @@ pub async fn clean_sys_operate_log(_auth: Token) -> AppResult<Value> {
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/operateLog/queryOperateLogDetail",
+    tag = "操作日志",
+    summary = "查询操作日志记录详情",
+    request_body = QueryOperateLogDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<QueryOperateLogDetailResp>)),
+    security(("token" = []))
+)]
 #[post("/system/operateLog/queryOperateLogDetail", data = "<item>")]
 pub async fn query_sys_operate_log_detail(
     item: Json<QueryOperateLogDetailReq>,
@@ -55,7 +78,7 @@ pub async fn query_sys_operate_log_detail(
     let rb = &mut RB.clone();
 
     match OperateLog::select_by_id(rb, &item.id).await? {
-        None => Err(AppError::BusinessError("操作日志不存在")),
+        None => Err(ErrorCode::OperateLogNotFound.into()),
         Some(x) => {
             let sys_operate_log = QueryOperateLogDetailResp {
                 id: x.id,                                     //日志主键
@@ -87,6 +110,15 @@ pub async fn query_sys_operate_log_detail(
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/operateLog/queryOperateLogList",
+    tag = "操作日志",
+    summary = "查询操作日志记录列表",
+    request_body = QueryOperateLogListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<OperateLogListDataResp>>)),
+    security(("token" = []))
+)]
 #[post("/system/operateLog/queryOperateLogList", data = "<item>")]
 pub async fn query_sys_operate_log_list(
     item: Json<QueryOperateLogListReq>,
@@ -105,13 +137,53 @@ pub async fn query_sys_operate_log_list(
     let operate_url = item.operate_url.as_deref().unwrap_or_default(); //请求URL
     let operate_ip = item.operate_ip.as_deref().unwrap_or_default(); //主机地址
     let status = item.status.unwrap_or(2); //操作状态(0:异常,正常)
+    let statuses = item.statuses.clone().filter(|x| !x.is_empty()); //操作状态(多选)
+    let business_types = item.business_types.clone().filter(|x| !x.is_empty()); //业务类型(多选)
+    let begin_time = item.begin_time.as_deref().unwrap_or_default(); //开始时间
+    let end_time = item.end_time.as_deref().unwrap_or_default(); //结束时间
+    check_time_range(begin_time, end_time)?;
+    let cursor = match item.cursor.as_deref() {
+        Some(x) => Some(Cursor::decode(x)?), //游标分页
+        None => None,                        //页码分页
+    };
+    let with_total = item.with_total.unwrap_or_default(); //游标分页时是否查询总数
 
-    let page = &PageRequest::new(item.page_no, item.page_size);
+    let order_by = order_by(
+        item.sort_field.as_deref(),
+        item.sort_order.as_deref(),
+        &[
+            ("id", "id"),
+            ("title", "title"),
+            ("businessType", "business_type"),
+            ("operateName", "operate_name"),
+            ("deptName", "dept_name"),
+            ("operateUrl", "operate_url"),
+            ("status", "status"),
+            ("operateTime", "operate_time"),
+            ("costTime", "cost_time"),
+        ],
+        "operate_time desc",
+    );
+    //排序字段的值可能重复,追加id保证分页结果稳定
+    let order_by = format!("{}, id desc", order_by);
+
+    let (page, order_by) = match cursor {
+        Some(_) => (
+            PageRequest::new(1, item.page_size).set_do_count(with_total),
+            "operate_time desc, id desc".to_string(),
+        ),
+        None => (PageRequest::new(item.page_no, item.page_size), order_by),
+    };
+    let (cursor_time, cursor_id) = cursor
+        .as_ref()
+        .map(|x| (x.time.as_str(), x.id))
+        .unwrap_or_default();
     let d = OperateLog::select_page_by_name(
         rb,
-        page,
+        &page,
         title,
         &business_type,
+        &business_types,
         method,
         request_method,
         &operator_type,
@@ -120,12 +192,19 @@ pub async fn query_sys_operate_log_list(
         operate_url,
         operate_ip,
         &status,
+        &statuses,
+        begin_time,
+        end_time,
+        cursor_time,
+        &cursor_id,
+        &order_by,
     )
     .await?;
 
     let mut list: Vec<OperateLogListDataResp> = Vec::new();
 
     let total = d.total;
+    let page_size = d.page_size;
 
     for x in d.records {
         list.push(OperateLogListDataResp {
@@ -149,5 +228,13 @@ pub async fn query_sys_operate_log_list(
         })
     }
 
+    if cursor.is_some() {
+        let next_cursor = next_cursor(&list, page_size, |x| Cursor {
+            time: x.operate_time.clone(),
+            id: x.id.unwrap_or_default(),
+        });
+        return ok_result_cursor_page(list, next_cursor, with_total.then_some(total));
+    }
+
     ok_result_page(list, total)
 }
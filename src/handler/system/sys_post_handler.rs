@@ -1,12 +1,17 @@
 use rbatis::plugin::page::PageRequest;
 use rocket::serde::json::{Json, Value};
 
-use crate::common::error::{AppError, AppResult};
-use crate::common::result::{ok_result, ok_result_data, ok_result_page};
+use crate::common::error::AppResult;
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{
+    ok_result, ok_result_data, ok_result_page, BaseResponse, ResponsePage,
+};
 use crate::middleware::auth::Token;
+use crate::middleware::validate::ValidJson;
 use crate::model::system::sys_post_model::Post;
 use crate::model::system::sys_user_post_model::count_user_post_by_id;
-use crate::utils::time_util::time_to_string;
+use crate::utils::sort_util::order_by;
+use crate::utils::time_util::{check_time_range, time_to_string};
 use crate::vo::system::sys_post_vo::*;
 use crate::RB;
 use rbs::value;
@@ -15,18 +20,27 @@ use rbs::value;
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/post/addPost",
+    tag = "岗位管理",
+    summary = "添加岗位信息表",
+    request_body = AddPostReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/post/addPost", data = "<item>")]
-pub async fn add_sys_post(item: Json<AddPostReq>, _auth: Token) -> AppResult<Value> {
+pub async fn add_sys_post(item: ValidJson<AddPostReq>, _auth: Token) -> AppResult<Value> {
     log::info!("add sys_post params: {:?}", &item);
     let rb = &mut RB.clone();
     let req = item.0;
 
     if Post::select_by_name(rb, &req.post_name).await?.is_some() {
-        return Err(AppError::BusinessError("岗位名称已存在"));
+        return Err(ErrorCode::PostNameExists.into());
     }
 
     if Post::select_by_code(rb, &req.post_code).await?.is_some() {
-        return Err(AppError::BusinessError("岗位编码已存在"));
+        return Err(ErrorCode::PostCodeExists.into());
     }
 
     let sys_post = Post {
@@ -50,6 +64,15 @@ pub async fn add_sys_post(item: Json<AddPostReq>, _auth: Token) -> AppResult<Val
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/post/deletePost",
+    tag = "岗位管理",
+    summary = "删除岗位信息表",
+    request_body = DeletePostReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/post/deletePost", data = "<item>")]
 pub async fn delete_sys_post(item: Json<DeletePostReq>, _auth: Token) -> AppResult<Value> {
     log::info!("delete sys_post params: {:?}", &item);
@@ -58,7 +81,7 @@ pub async fn delete_sys_post(item: Json<DeletePostReq>, _auth: Token) -> AppResu
     let ids = item.ids.clone();
     for id in ids {
         if count_user_post_by_id(rb, id).await? > 0 {
-            return Err(AppError::BusinessError("已分配,不能删除"));
+            return Err(ErrorCode::PostInUse.into());
         }
     }
 
@@ -72,25 +95,34 @@ pub async fn delete_sys_post(item: Json<DeletePostReq>, _auth: Token) -> AppResu
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/post/updatePost",
+    tag = "岗位管理",
+    summary = "更新岗位信息表",
+    request_body = UpdatePostReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/post/updatePost", data = "<item>")]
-pub async fn update_sys_post(item: Json<UpdatePostReq>, _auth: Token) -> AppResult<Value> {
+pub async fn update_sys_post(item: ValidJson<UpdatePostReq>, _auth: Token) -> AppResult<Value> {
     log::info!("update sys_post params: {:?}", &item);
     let rb = &mut RB.clone();
     let req = item.0;
 
     if Post::select_by_id(rb, &req.id).await?.is_none() {
-        return Err(AppError::BusinessError("岗位不存在"));
+        return Err(ErrorCode::PostNotFound.into());
     }
 
     if let Some(x) = Post::select_by_name(rb, &req.post_name).await? {
         if x.id.unwrap_or_default() != req.id {
-            return Err(AppError::BusinessError("岗位名称已存在"));
+            return Err(ErrorCode::PostNameExists.into());
         }
     }
 
     if let Some(x) = Post::select_by_code(rb, &req.post_code).await? {
         if x.id.unwrap_or_default() != req.id {
-            return Err(AppError::BusinessError("岗位编码已存在"));
+            return Err(ErrorCode::PostCodeExists.into());
         }
     }
 
@@ -115,9 +147,18 @@ pub async fn update_sys_post(item: Json<UpdatePostReq>, _auth: Token) -> AppResu
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/post/updatePostStatus",
+    tag = "岗位管理",
+    summary = "更新岗位信息表状态",
+    request_body = UpdatePostStatusReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/post/updatePostStatus", data = "<item>")]
 pub async fn update_sys_post_status(
-    item: Json<UpdatePostStatusReq>,
+    item: ValidJson<UpdatePostStatusReq>,
     _auth: Token,
 ) -> AppResult<Value> {
     log::info!("update sys_post_status params: {:?}", &item);
@@ -145,6 +186,15 @@ pub async fn update_sys_post_status(
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/post/queryPostDetail",
+    tag = "岗位管理",
+    summary = "查询岗位信息表详情",
+    request_body = QueryPostDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<QueryPostDetailResp>)),
+    security(("token" = []))
+)]
 #[post("/system/post/queryPostDetail", data = "<item>")]
 pub async fn query_sys_post_detail(
     item: Json<QueryPostDetailReq>,
@@ -154,7 +204,7 @@ pub async fn query_sys_post_detail(
     let rb = &mut RB.clone();
 
     match Post::select_by_id(rb, &item.id).await? {
-        None => Err(AppError::BusinessError("岗位不存在")),
+        None => Err(ErrorCode::PostNotFound.into()),
         Some(x) => {
             let sys_post = QueryPostDetailResp {
                 id: x.id.unwrap_or_default(),               //岗位id
@@ -177,6 +227,15 @@ pub async fn query_sys_post_detail(
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/post/queryPostList",
+    tag = "岗位管理",
+    summary = "查询岗位信息表列表",
+    request_body = QueryPostListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<PostListDataResp>>)),
+    security(("token" = []))
+)]
 #[post("/system/post/queryPostList", data = "<item>")]
 pub async fn query_sys_post_list(item: Json<QueryPostListReq>, _auth: Token) -> AppResult<Value> {
     log::info!("query sys_post_list params: {:?}", &item);
@@ -186,8 +245,30 @@ pub async fn query_sys_post_list(item: Json<QueryPostListReq>, _auth: Token) ->
     let post_name = item.post_name.as_deref().unwrap_or_default(); //岗位名称
     let status = item.status.unwrap_or(2); //部状态（0：停用，1:正常）
 
+    let keyword = item.keyword.as_deref().unwrap_or_default().trim(); //关键字(模糊搜索)
+    let begin_time = item.begin_time.as_deref().unwrap_or_default(); //创建时间(开始)
+    let end_time = item.end_time.as_deref().unwrap_or_default(); //创建时间(结束)
+    check_time_range(begin_time, end_time)?;
+
+    let order_by = order_by(
+        item.sort_field.as_deref(),
+        item.sort_order.as_deref(),
+        &[
+            ("id", "id"),
+            ("postCode", "post_code"),
+            ("postName", "post_name"),
+            ("sort", "sort"),
+            ("status", "status"),
+            ("createTime", "create_time"),
+        ],
+        "create_time desc",
+    );
+
     let page = &PageRequest::new(item.page_no, item.page_size);
-    let d = Post::select_post_list(rb, page, post_code, post_name, status).await?;
+    let d = Post::select_post_list(
+        rb, page, post_code, post_name, status, keyword, begin_time, end_time, &order_by,
+    )
+    .await?;
 
     let mut list: Vec<PostListDataResp> = Vec::new();
 
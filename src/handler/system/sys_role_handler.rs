@@ -1,10 +1,17 @@
 use rbatis::plugin::page::PageRequest;
 use rbatis::rbdc::datetime::DateTime;
+use rbatis::RBatis;
+use rocket::serde::json::serde_json::json;
 use rocket::serde::json::{Json, Value};
 
-use crate::common::error::{AppError, AppResult};
-use crate::common::result::{ok_result, ok_result_data, ok_result_page};
+use crate::common::error::AppResult;
+use crate::common::error_code::ErrorCode;
+use crate::common::event_hub::{EVENT_HUB, EVENT_PERMISSION_CHANGED};
+use crate::common::result::{
+    ok_result, ok_result_cursor_page, ok_result_data, ok_result_page, BaseResponse, ResponsePage,
+};
 use crate::middleware::auth::Token;
+use crate::middleware::validate::ValidJson;
 use crate::model::system::sys_menu_model::Menu;
 use crate::model::system::sys_role_dept_model::RoleDept;
 use crate::model::system::sys_role_menu_model::{query_menu_by_role, RoleMenu};
@@ -15,7 +22,9 @@ use crate::model::system::sys_user_model::{
 use crate::model::system::sys_user_role_model::{
     count_user_role_by_role_id, delete_user_role_by_role_id_user_id, UserRole,
 };
-use crate::utils::time_util::time_to_string;
+use crate::utils::cursor_util::{next_cursor, Cursor};
+use crate::utils::sort_util::order_by;
+use crate::utils::time_util::{check_time_range, time_to_string};
 use crate::vo::system::sys_role_vo::*;
 use crate::vo::system::sys_user_vo::UserListDataResp;
 use crate::RB;
@@ -25,20 +34,29 @@ use rbs::value;
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/role/addRole",
+    tag = "角色管理",
+    summary = "添加角色信息",
+    request_body = AddRoleReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/role/addRole", data = "<item>")]
-pub async fn add_sys_role(item: Json<AddRoleReq>, _auth: Token) -> AppResult<Value> {
+pub async fn add_sys_role(item: ValidJson<AddRoleReq>, _auth: Token) -> AppResult<Value> {
     log::info!("add sys_role params: {:?}", &item);
     let rb = &mut RB.clone();
     let req = item.0;
 
     let name = req.role_name;
     if Role::select_by_role_name(rb, &name).await?.is_some() {
-        return Err(AppError::BusinessError("角色名称已存在"));
+        return Err(ErrorCode::RoleNameExists.into());
     }
 
     let key = req.role_key;
     if Role::select_by_role_key(rb, &key).await?.is_some() {
-        return Err(AppError::BusinessError("角色权限已存在"));
+        return Err(ErrorCode::RoleKeyExists.into());
     }
 
     let sys_role = Role {
@@ -63,6 +81,15 @@ pub async fn add_sys_role(item: Json<AddRoleReq>, _auth: Token) -> AppResult<Val
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/role/deleteRole",
+    tag = "角色管理",
+    summary = "删除角色信息",
+    request_body = DeleteRoleReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/role/deleteRole", data = "<item>")]
 pub async fn delete_sys_role(item: Json<DeleteRoleReq>, _auth: Token) -> AppResult<Value> {
     log::info!("delete sys_role params: {:?}", &item);
@@ -71,20 +98,24 @@ pub async fn delete_sys_role(item: Json<DeleteRoleReq>, _auth: Token) -> AppResu
     let ids = item.ids.clone();
 
     if ids.contains(&1) {
-        return Err(AppError::BusinessError("不允许操作超级管理员角色"));
+        return Err(ErrorCode::RoleSuperAdmin.into());
     }
 
     for id in ids {
         if count_user_role_by_role_id(rb, id).await? > 0 {
-            return Err(AppError::BusinessError("已分配,不能删除"));
+            return Err(ErrorCode::RoleInUse.into());
         }
     }
 
+    let user_ids = query_role_user_ids(rb, &item.ids).await?;
+
     RoleMenu::delete_by_map(rb, value! {"role_id": &item.ids}).await?;
 
     RoleDept::delete_by_map(rb, value! {"role_id": &item.ids}).await?;
 
     Role::delete_by_map(rb, value! {"id": &item.ids}).await?;
+
+    EVENT_HUB.publish_users(user_ids, EVENT_PERMISSION_CHANGED, json!({}));
     ok_result()
 }
 
@@ -93,29 +124,38 @@ pub async fn delete_sys_role(item: Json<DeleteRoleReq>, _auth: Token) -> AppResu
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/role/updateRole",
+    tag = "角色管理",
+    summary = "更新角色信息",
+    request_body = UpdateRoleReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/role/updateRole", data = "<item>")]
-pub async fn update_sys_role(item: Json<UpdateRoleReq>, _auth: Token) -> AppResult<Value> {
+pub async fn update_sys_role(item: ValidJson<UpdateRoleReq>, _auth: Token) -> AppResult<Value> {
     log::info!("update sys_role params: {:?}", &item);
     let rb = &mut RB.clone();
     let req = item.0;
 
     if req.id == 1 {
-        return Err(AppError::BusinessError("不允许操作超级管理员角色"));
+        return Err(ErrorCode::RoleSuperAdmin.into());
     }
 
     if Role::select_by_id(rb, &req.id).await?.is_none() {
-        return Err(AppError::BusinessError("角色不存在"));
+        return Err(ErrorCode::RoleNotFound.into());
     }
 
     if let Some(x) = Role::select_by_role_name(rb, &req.role_name).await? {
         if x.id.unwrap_or_default() != req.id {
-            return Err(AppError::BusinessError("角色名称已存在"));
+            return Err(ErrorCode::RoleNameExists.into());
         }
     }
 
     if let Some(x) = Role::select_by_role_key(rb, &req.role_key).await? {
         if x.id.unwrap_or_default() != req.id {
-            return Err(AppError::BusinessError("角色权限已存在"));
+            return Err(ErrorCode::RoleKeyExists.into());
         }
     }
 
@@ -141,9 +181,18 @@ pub async fn update_sys_role(item: Json<UpdateRoleReq>, _auth: Token) -> AppResu
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/role/updateRoleStatus",
+    tag = "角色管理",
+    summary = "更新角色信息状态",
+    request_body = UpdateRoleStatusReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/role/updateRoleStatus", data = "<item>")]
 pub async fn update_sys_role_status(
-    item: Json<UpdateRoleStatusReq>,
+    item: ValidJson<UpdateRoleStatusReq>,
     _auth: Token,
 ) -> AppResult<Value> {
     log::info!("update sys_role_status params: {:?}", &item);
@@ -151,7 +200,7 @@ pub async fn update_sys_role_status(
     let req = item.0;
 
     if req.ids.contains(&1) {
-        return Err(AppError::BusinessError("不允许操作超级管理员角色"));
+        return Err(ErrorCode::RoleSuperAdmin.into());
     }
 
     let update_sql = format!(
@@ -167,6 +216,9 @@ pub async fn update_sys_role_status(
     param.extend(req.ids.iter().map(|&id| value!(id)));
     rb.exec(&update_sql, param).await?;
 
+    let user_ids = query_role_user_ids(rb, &req.ids).await?;
+    EVENT_HUB.publish_users(user_ids, EVENT_PERMISSION_CHANGED, json!({}));
+
     ok_result()
 }
 
@@ -175,6 +227,15 @@ pub async fn update_sys_role_status(
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/role/queryRoleDetail",
+    tag = "角色管理",
+    summary = "查询角色信息详情",
+    request_body = QueryRoleDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<QueryRoleDetailResp>)),
+    security(("token" = []))
+)]
 #[post("/system/role/queryRoleDetail", data = "<item>")]
 pub async fn query_sys_role_detail(
     item: Json<QueryRoleDetailReq>,
@@ -184,7 +245,7 @@ pub async fn query_sys_role_detail(
     let rb = &mut RB.clone();
 
     match Role::select_by_id(rb, &item.id).await? {
-        None => Err(AppError::BusinessError("角色不存在")),
+        None => Err(ErrorCode::RoleNotFound.into()),
         Some(x) => {
             let sys_role = QueryRoleDetailResp {
                 id: x.id.unwrap_or_default(),               //主键
@@ -208,6 +269,15 @@ pub async fn query_sys_role_detail(
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/role/queryRoleList",
+    tag = "角色管理",
+    summary = "查询角色信息列表",
+    request_body = QueryRoleListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<RoleListDataResp>>)),
+    security(("token" = []))
+)]
 #[post("/system/role/queryRoleList", data = "<item>")]
 pub async fn query_sys_role_list(item: Json<QueryRoleListReq>, _auth: Token) -> AppResult<Value> {
     log::info!("query sys_role_list params: {:?}", &item);
@@ -217,8 +287,29 @@ pub async fn query_sys_role_list(item: Json<QueryRoleListReq>, _auth: Token) ->
     let role_key = item.role_key.as_deref().unwrap_or_default();
     let status = item.status_id.unwrap_or(2);
 
+    let keyword = item.keyword.as_deref().unwrap_or_default().trim(); //关键字(模糊搜索)
+    let begin_time = item.begin_time.as_deref().unwrap_or_default(); //创建时间(开始)
+    let end_time = item.end_time.as_deref().unwrap_or_default(); //创建时间(结束)
+    check_time_range(begin_time, end_time)?;
+
+    let order_by = order_by(
+        item.sort_field.as_deref(),
+        item.sort_order.as_deref(),
+        &[
+            ("id", "id"),
+            ("roleName", "role_name"),
+            ("roleKey", "role_key"),
+            ("status", "status"),
+            ("createTime", "create_time"),
+        ],
+        "create_time desc",
+    );
+
     let page = &PageRequest::new(item.page_no, item.page_size);
-    let d = Role::select_sys_role_list(rb, page, role_name, role_key, status).await?;
+    let d = Role::select_sys_role_list(
+        rb, page, role_name, role_key, status, keyword, begin_time, end_time, &order_by,
+    )
+    .await?;
 
     let total = d.total;
 
@@ -245,6 +336,15 @@ pub async fn query_sys_role_list(item: Json<QueryRoleListReq>, _auth: Token) ->
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/role/queryRoleMenu",
+    tag = "角色管理",
+    summary = "查询角色关联的菜单",
+    request_body = QueryRoleMenuReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<QueryRoleMenuData>)),
+    security(("token" = []))
+)]
 #[post("/system/role/queryRoleMenu", data = "<item>")]
 pub async fn query_role_menu(item: Json<QueryRoleMenuReq>, _auth: Token) -> AppResult<Value> {
     log::info!("query role_menu params: {:?}", &item);
@@ -294,13 +394,22 @@ pub async fn query_role_menu(item: Json<QueryRoleMenuReq>, _auth: Token) -> AppR
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/role/updateRoleMenu",
+    tag = "角色管理",
+    summary = "更新角色关联的菜单",
+    request_body = UpdateRoleMenuReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/role/updateRoleMenu", data = "<item>")]
 pub async fn update_role_menu(item: Json<UpdateRoleMenuReq>, _auth: Token) -> AppResult<Value> {
     log::info!("update role_menu params: {:?}", &item);
     let role_id = item.role_id;
 
     if role_id == 1 {
-        return Err(AppError::BusinessError("不允许操作超级管理员角色"));
+        return Err(ErrorCode::RoleSuperAdmin.into());
     }
 
     let rb = &mut RB.clone();
@@ -321,6 +430,9 @@ pub async fn update_role_menu(item: Json<UpdateRoleMenuReq>, _auth: Token) -> Ap
 
     RoleMenu::insert_batch(rb, &role_menu, item.menu_ids.len() as u64).await?;
 
+    let user_ids = query_role_user_ids(rb, &[role_id]).await?;
+    EVENT_HUB.publish_users(user_ids, EVENT_PERMISSION_CHANGED, json!({}));
+
     ok_result()
 }
 
@@ -329,6 +441,15 @@ pub async fn update_role_menu(item: Json<UpdateRoleMenuReq>, _auth: Token) -> Ap
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/role/queryAllocatedList",
+    tag = "角色管理",
+    summary = "查询已分配用户角色列表",
+    request_body = AllocatedListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<UserListDataResp>>)),
+    security(("token" = []))
+)]
 #[post("/system/role/queryAllocatedList", data = "<item>")]
 pub async fn query_allocated_list(item: Json<AllocatedListReq>, _auth: Token) -> AppResult<Value> {
     log::info!("update role_menu params: {:?}", &item);
@@ -340,9 +461,26 @@ pub async fn query_allocated_list(item: Json<AllocatedListReq>, _auth: Token) ->
     let role_id = item.role_id;
     let mobile = item.mobile.as_deref().unwrap_or_default();
     let user_name = item.user_name.as_deref().unwrap_or_default();
+    let cursor = match item.cursor.as_deref() {
+        Some(x) => Some(Cursor::decode(x)?), //游标分页
+        None => None,                        //页码分页
+    };
+    let with_total = item.with_total.unwrap_or_default(); //游标分页时是否查询总数
 
-    let page_no = (page_no - 1) * page_size;
-    let d = select_allocated_list(rb, role_id, user_name, mobile, page_no, page_size).await?;
+    let page_no = match cursor {
+        Some(_) => 0,
+        None => (page_no - 1) * page_size,
+    };
+    let d = select_allocated_list(
+        rb,
+        role_id,
+        user_name,
+        mobile,
+        cursor.as_ref().unwrap_or(&Cursor::default()),
+        page_no,
+        page_size,
+    )
+    .await?;
 
     let mut sys_user_list_data: Vec<UserListDataResp> = Vec::new();
     for x in d {
@@ -368,6 +506,18 @@ pub async fn query_allocated_list(item: Json<AllocatedListReq>, _auth: Token) ->
         })
     }
 
+    if cursor.is_some() {
+        let next_cursor = next_cursor(&sys_user_list_data, page_size, |x| Cursor {
+            time: x.create_time.clone(),
+            id: x.id,
+        });
+        let total = match with_total {
+            true => Some(count_allocated_list(rb, role_id, user_name, mobile).await?),
+            false => None,
+        };
+        return ok_result_cursor_page(sys_user_list_data, next_cursor, total);
+    }
+
     let total = count_allocated_list(rb, role_id, user_name, mobile).await?;
     ok_result_page(sys_user_list_data, total)
 }
@@ -377,6 +527,15 @@ pub async fn query_allocated_list(item: Json<AllocatedListReq>, _auth: Token) ->
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/role/queryUnallocatedList",
+    tag = "角色管理",
+    summary = "查询未分配用户角色列表",
+    request_body = UnallocatedListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<UserListDataResp>>)),
+    security(("token" = []))
+)]
 #[post("/system/role/queryUnallocatedList", data = "<item>")]
 pub async fn query_unallocated_list(
     item: Json<UnallocatedListReq>,
@@ -391,9 +550,26 @@ pub async fn query_unallocated_list(
     let role_id = item.role_id;
     let mobile = item.mobile.as_deref().unwrap_or_default();
     let user_name = item.user_name.as_deref().unwrap_or_default();
+    let cursor = match item.cursor.as_deref() {
+        Some(x) => Some(Cursor::decode(x)?), //游标分页
+        None => None,                        //页码分页
+    };
+    let with_total = item.with_total.unwrap_or_default(); //游标分页时是否查询总数
 
-    let page_no = (page_no - 1) * page_size;
-    let d = select_unallocated_list(rb, role_id, user_name, mobile, page_no, page_size).await?;
+    let page_no = match cursor {
+        Some(_) => 0,
+        None => (page_no - 1) * page_size,
+    };
+    let d = select_unallocated_list(
+        rb,
+        role_id,
+        user_name,
+        mobile,
+        cursor.as_ref().unwrap_or(&Cursor::default()),
+        page_no,
+        page_size,
+    )
+    .await?;
 
     let mut sys_user_list_data: Vec<UserListDataResp> = Vec::new();
     for x in d {
@@ -419,6 +595,18 @@ pub async fn query_unallocated_list(
         })
     }
 
+    if cursor.is_some() {
+        let next_cursor = next_cursor(&sys_user_list_data, page_size, |x| Cursor {
+            time: x.create_time.clone(),
+            id: x.id,
+        });
+        let total = match with_total {
+            true => Some(count_unallocated_list(rb, role_id, user_name, mobile).await?),
+            false => None,
+        };
+        return ok_result_cursor_page(sys_user_list_data, next_cursor, total);
+    }
+
     let total = count_unallocated_list(rb, role_id, user_name, mobile).await?;
     ok_result_page(sys_user_list_data, total)
 }
@@ -428,6 +616,15 @@ pub async fn query_unallocated_list(
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/role/cancelAuthUser",
+    tag = "角色管理",
+    summary = "取消授权用户",
+    request_body = CancelAuthUserReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/role/cancelAuthUser", data = "<item>")]
 pub async fn cancel_auth_user(item: Json<CancelAuthUserReq>, _auth: Token) -> AppResult<Value> {
     log::info!("update role_menu params: {:?}", &item);
@@ -436,6 +633,8 @@ pub async fn cancel_auth_user(item: Json<CancelAuthUserReq>, _auth: Token) -> Ap
 
     delete_user_role_by_role_id_user_id(rb, item.role_id, item.user_id).await?;
 
+    EVENT_HUB.publish_users(vec![item.user_id], EVENT_PERMISSION_CHANGED, json!({}));
+
     ok_result()
 }
 
@@ -444,6 +643,15 @@ pub async fn cancel_auth_user(item: Json<CancelAuthUserReq>, _auth: Token) -> Ap
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/role/batchCancelAuthUser",
+    tag = "角色管理",
+    summary = "批量取消授权用户",
+    request_body = CancelAuthUserAllReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/role/batchCancelAuthUser", data = "<item>")]
 pub async fn batch_cancel_auth_user(
     item: Json<CancelAuthUserAllReq>,
@@ -466,6 +674,8 @@ pub async fn batch_cancel_auth_user(
     param.extend(item.user_ids.iter().map(|&id| value!(id)));
     rb.exec(&update_sql, param).await?;
 
+    EVENT_HUB.publish_users(item.user_ids.clone(), EVENT_PERMISSION_CHANGED, json!({}));
+
     ok_result()
 }
 
@@ -474,6 +684,15 @@ pub async fn batch_cancel_auth_user(
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/role/batchAuthUser",
+    tag = "角色管理",
+    summary = "批量选择用户授权",
+    request_body = SelectAuthUserAllReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/role/batchAuthUser", data = "<item>")]
 pub async fn batch_auth_user(item: Json<SelectAuthUserAllReq>, _auth: Token) -> AppResult<Value> {
     log::info!("select all_auth_user params: {:?}", &item);
@@ -495,5 +714,24 @@ pub async fn batch_auth_user(item: Json<SelectAuthUserAllReq>, _auth: Token) ->
 
     UserRole::insert_batch(rb, &user_role, item.user_ids.len() as u64).await?;
 
+    EVENT_HUB.publish_users(item.user_ids.clone(), EVENT_PERMISSION_CHANGED, json!({}));
+
     ok_result()
 }
+
+/*
+ *查询角色下的用户ID(角色的权限变化时通知这些用户)
+ *author：刘飞华
+ *date：2026/10/20 11:52:06
+ */
+async fn query_role_user_ids(rb: &RBatis, role_ids: &[i64]) -> AppResult<Vec<i64>> {
+    if role_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let user_ids = UserRole::select_by_map(rb, value! {"role_id": role_ids})
+        .await?
+        .iter()
+        .map(|x| x.user_id)
+        .collect::<Vec<i64>>();
+    Ok(user_ids)
+}
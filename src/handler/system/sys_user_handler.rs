@@ -1,15 +1,30 @@
+use crate::common::config_cache::{CONFIG_DEFAULT_AVATAR_STYLE, SYS_CONFIG};
 use crate::common::error::{AppError, AppResult};
-use crate::common::result::{ok_result, ok_result_data, ok_result_page};
+use crate::common::error_code::ErrorCode;
+use crate::common::event_hub::{EVENT_FORCE_LOGOUT, EVENT_HUB, EVENT_PERMISSION_CHANGED};
+use crate::common::metrics::METRICS;
+use crate::common::result::{
+    ok_result, ok_result_data, ok_result_page, BaseResponse, ResponsePage,
+};
+use crate::common::result::{ok_result_file, FileResponse};
+use crate::common::storage::FILE_STORAGE;
+use crate::handler::system::sys_file_handler::{delete_files, read_temp_file, save_file};
 use crate::middleware::auth::Token;
+use crate::middleware::client::ClientInfo;
+use crate::middleware::validate::ValidJson;
 use crate::model::system::sys_dept_model::Dept;
+use crate::model::system::sys_file_model::SysFile;
 use crate::model::system::sys_login_log_model::LoginLog;
 use crate::model::system::sys_menu_model::Menu;
 use crate::model::system::sys_role_model::Role;
-use crate::model::system::sys_user_model::User;
+use crate::model::system::sys_user_model::{count_by_avatar, User};
 use crate::model::system::sys_user_post_model::UserPost;
 use crate::model::system::sys_user_role_model::{is_admin, UserRole};
+use crate::utils::avatar_util::{avatar_url, default_avatar_svg, resize_avatar, AVATAR_SIZES};
+use crate::utils::ip_util::ip_location;
 use crate::utils::jwt_util::JwtToken;
-use crate::utils::time_util::time_to_string;
+use crate::utils::sort_util::order_by;
+use crate::utils::time_util::{check_time_range, time_to_string};
 use crate::utils::user_agent_util::UserAgentUtil;
 use crate::vo::system::sys_dept_vo::QueryDeptDetailResp;
 use crate::vo::system::sys_user_vo::*;
@@ -18,7 +33,15 @@ use rbatis::plugin::page::PageRequest;
 use rbatis::rbatis_codegen::ops::AsProxy;
 use rbatis::rbdc::datetime::DateTime;
 use rbs::value;
+use rocket::form::Form;
+use rocket::http::ContentType;
+use rocket::response::stream::{Event, EventStream};
+use rocket::serde::json::serde_json::json;
 use rocket::serde::json::{Json, Value};
+use rocket::tokio::select;
+use rocket::tokio::sync::broadcast::error::RecvError;
+use rocket::tokio::time::Duration;
+use rocket::Shutdown;
 use std::collections::{HashMap, HashSet};
 
 /*
@@ -26,29 +49,37 @@ use std::collections::{HashMap, HashSet};
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/addUser",
+    tag = "用户管理",
+    summary = "添加用户信息",
+    request_body = AddUserReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/user/addUser", data = "<item>")]
-pub async fn add_sys_user(item: Json<AddUserReq>, _auth: Token) -> AppResult<Value> {
+pub async fn add_sys_user(item: ValidJson<AddUserReq>, _auth: Token) -> AppResult<Value> {
     log::info!("add sys_user params: {:?}", &item);
     let rb = &mut RB.clone();
     let req = item.0;
 
     let name = req.user_name;
     if User::select_by_user_name(rb, &name).await?.is_some() {
-        return Err(AppError::BusinessError("登录账号已存在"));
+        return Err(ErrorCode::UserNameExists.into());
     }
 
     if User::select_by_mobile(rb, &req.mobile).await?.is_some() {
-        return Err(AppError::BusinessError("手机号码已存在"));
+        return Err(ErrorCode::UserMobileExists.into());
     }
 
-    if User::select_by_email(rb, &req.email).await?.is_some() {
-        return Err(AppError::BusinessError("邮箱账号已存在"));
+    //邮箱选填,为空时不校验是否重复
+    if !req.email.is_empty() && User::select_by_email(rb, &req.email).await?.is_some() {
+        return Err(ErrorCode::UserEmailExists.into());
     }
 
-    let avatar = req.avatar.unwrap_or(
-        "https://gw.alipayobjects.com/zos/antfincdn/XAosXuNZyF/BiazfanxmamNRoxxVxka.png"
-            .to_string(),
-    );
+    //没有上传头像时为空,查询时返回生成的默认头像
+    let avatar = req.avatar.unwrap_or_default();
     let sys_user = User {
         id: None,                          //主键
         mobile: req.mobile,                //手机
@@ -89,6 +120,15 @@ pub async fn add_sys_user(item: Json<AddUserReq>, _auth: Token) -> AppResult<Val
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/deleteUser",
+    tag = "用户管理",
+    summary = "删除用户信息",
+    request_body = DeleteUserReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/user/deleteUser", data = "<item>")]
 pub async fn delete_sys_user(item: Json<DeleteUserReq>, auth: Token) -> AppResult<Value> {
     log::info!("delete sys_user params: {:?}", &item);
@@ -98,10 +138,10 @@ pub async fn delete_sys_user(item: Json<DeleteUserReq>, auth: Token) -> AppResul
 
     let ids = item.ids.clone();
     if ids.contains(&user_id) {
-        return Err(AppError::BusinessError("当前用户不能删除"));
+        return Err(ErrorCode::UserDeleteSelf.into());
     }
     if ids.contains(&1) {
-        return Err(AppError::BusinessError("不允许操作超级管理员用户"));
+        return Err(ErrorCode::UserSuperAdmin.into());
     }
 
     UserRole::delete_by_map(rb, value! {"user_id": &ids}).await?;
@@ -110,6 +150,8 @@ pub async fn delete_sys_user(item: Json<DeleteUserReq>, auth: Token) -> AppResul
 
     User::delete_by_map(rb, value! {"id": &item.ids}).await?;
 
+    EVENT_HUB.publish_users(ids, EVENT_FORCE_LOGOUT, json!({"reason": "用户已被删除"}));
+
     ok_result()
 }
 
@@ -118,44 +160,52 @@ pub async fn delete_sys_user(item: Json<DeleteUserReq>, auth: Token) -> AppResul
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/updateUser",
+    tag = "用户管理",
+    summary = "更新用户信息",
+    request_body = UpdateUserReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/user/updateUser", data = "<item>")]
-pub async fn update_sys_user(item: Json<UpdateUserReq>, _auth: Token) -> AppResult<Value> {
+pub async fn update_sys_user(item: ValidJson<UpdateUserReq>, _auth: Token) -> AppResult<Value> {
     log::info!("update sys_user params: {:?}", &item);
     let rb = &mut RB.clone();
     let req = item.0;
 
     let id = req.id.clone();
     if id == 1 {
-        return Err(AppError::BusinessError("不允许操作超级管理员用户"));
+        return Err(ErrorCode::UserSuperAdmin.into());
     }
 
     let u = match User::select_by_id(rb, req.id).await? {
-        None => return Err(AppError::BusinessError("用户不存在")),
+        None => return Err(ErrorCode::UserNotFound.into()),
         Some(x) => x,
     };
 
     if let Some(x) = User::select_by_user_name(rb, &req.user_name).await? {
         if x.id.unwrap_or_default() != req.id {
-            return Err(AppError::BusinessError("登录账号已存在"));
+            return Err(ErrorCode::UserNameExists.into());
         }
     }
 
     if let Some(x) = User::select_by_mobile(rb, &req.mobile).await? {
         if x.id.unwrap_or_default() != req.id {
-            return Err(AppError::BusinessError("手机号码已存在"));
+            return Err(ErrorCode::UserMobileExists.into());
         }
     }
 
-    if let Some(x) = User::select_by_email(rb, &req.email).await? {
-        if x.id.unwrap_or_default() != req.id {
-            return Err(AppError::BusinessError("邮箱账号已存在"));
+    if !req.email.is_empty() {
+        if let Some(x) = User::select_by_email(rb, &req.email).await? {
+            if x.id.unwrap_or_default() != req.id {
+                return Err(ErrorCode::UserEmailExists.into());
+            }
         }
     }
 
-    let avatar = req.avatar.unwrap_or(
-        "https://gw.alipayobjects.com/zos/antfincdn/XAosXuNZyF/BiazfanxmamNRoxxVxka.png"
-            .to_string(),
-    );
+    let avatar = req.avatar.unwrap_or(u.avatar);
     let sys_user = User {
         id: Some(req.id),                   //主键
         mobile: req.mobile,                 //手机
@@ -189,6 +239,15 @@ pub async fn update_sys_user(item: Json<UpdateUserReq>, _auth: Token) -> AppResu
         })
     }
     UserPost::insert_batch(rb, &user_post_list, user_post_list.len() as u64).await?;
+
+    //和修改用户状态一样,停用时强制下线
+    if u.status == 1 && sys_user.status == 0 {
+        EVENT_HUB.publish_users(
+            vec![id],
+            EVENT_FORCE_LOGOUT,
+            json!({"reason": "用户已被禁用"}),
+        );
+    }
     ok_result()
 }
 
@@ -197,9 +256,18 @@ pub async fn update_sys_user(item: Json<UpdateUserReq>, _auth: Token) -> AppResu
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/updateUserStatus",
+    tag = "用户管理",
+    summary = "更新用户信息状态",
+    request_body = UpdateUserStatusReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/user/updateUserStatus", data = "<item>")]
 pub async fn update_sys_user_status(
-    item: Json<UpdateUserStatusReq>,
+    item: ValidJson<UpdateUserStatusReq>,
     _auth: Token,
 ) -> AppResult<Value> {
     log::info!("update sys_user_status params: {:?}", &item);
@@ -208,7 +276,7 @@ pub async fn update_sys_user_status(
 
     let ids = req.ids.clone();
     if ids.contains(&1) {
-        return Err(AppError::BusinessError("不允许操作超级管理员用户"));
+        return Err(ErrorCode::UserSuperAdmin.into());
     }
 
     let update_sql = format!(
@@ -224,6 +292,10 @@ pub async fn update_sys_user_status(
     param.extend(req.ids.iter().map(|&id| value!(id)));
     rb.exec(&update_sql, param).await?;
 
+    if req.status == 0 {
+        EVENT_HUB.publish_users(ids, EVENT_FORCE_LOGOUT, json!({"reason": "用户已被禁用"}));
+    }
+
     ok_result()
 }
 
@@ -232,9 +304,18 @@ pub async fn update_sys_user_status(
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/resetUserPassword",
+    tag = "用户管理",
+    summary = "重置用户密码",
+    request_body = ResetUserPwdReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/user/resetUserPassword", data = "<item>")]
 pub async fn reset_sys_user_password(
-    item: Json<ResetUserPwdReq>,
+    item: ValidJson<ResetUserPwdReq>,
     _auth: Token,
 ) -> AppResult<Value> {
     log::info!("update sys_user_password params: {:?}", &item);
@@ -243,17 +324,22 @@ pub async fn reset_sys_user_password(
 
     let id = req.id.clone();
     if id == 1 {
-        return Err(AppError::BusinessError("不允许操作超级管理员用户"));
+        return Err(ErrorCode::UserSuperAdmin.into());
     }
 
     let sys_user_result = User::select_by_id(rb, req.id).await?;
 
     match sys_user_result {
-        None => Err(AppError::BusinessError("用户不存在")),
+        None => Err(ErrorCode::UserNotFound.into()),
         Some(x) => {
             let mut user = x;
             user.password = req.password;
             User::update_by_map(rb, &user, value! {"id": &user.id}).await?;
+            EVENT_HUB.publish_users(
+                vec![id],
+                EVENT_FORCE_LOGOUT,
+                json!({"reason": "密码已被重置"}),
+            );
             ok_result()
         }
     }
@@ -264,9 +350,18 @@ pub async fn reset_sys_user_password(
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/updateUserPassword",
+    tag = "用户管理",
+    summary = "用户修改自己的密码",
+    request_body = UpdateUserPwdReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/user/updateUserPassword", data = "<item>")]
 pub async fn update_sys_user_password(
-    item: Json<UpdateUserPwdReq>,
+    item: ValidJson<UpdateUserPwdReq>,
     auth: Token,
 ) -> AppResult<Value> {
     log::info!("update sys_user_password params: {:?}", &item);
@@ -276,11 +371,11 @@ pub async fn update_sys_user_password(
     let user_id = auth.id;
 
     match User::select_by_id(rb, user_id).await? {
-        None => Err(AppError::BusinessError("用户不存在")),
+        None => Err(ErrorCode::UserNotFound.into()),
         Some(x) => {
             let mut user = x;
             if user.password != req.pwd {
-                return Err(AppError::BusinessError("旧密码不正确"));
+                return Err(ErrorCode::OldPasswordIncorrect.into());
             }
             user.password = req.re_pwd;
             User::update_by_map(rb, &user, value! {"id": &user.id}).await?;
@@ -294,6 +389,15 @@ pub async fn update_sys_user_password(
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/queryUserDetail",
+    tag = "用户管理",
+    summary = "查询用户信息详情",
+    request_body = QueryUserDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<QueryUserDetailResp>)),
+    security(("token" = []))
+)]
 #[post("/system/user/queryUserDetail", data = "<item>")]
 pub async fn query_sys_user_detail(
     item: Json<QueryUserDetailReq>,
@@ -303,11 +407,11 @@ pub async fn query_sys_user_detail(
     let rb = &mut RB.clone();
 
     match User::select_by_id(rb, item.id).await? {
-        None => Err(AppError::BusinessError("用户不存在")),
+        None => Err(ErrorCode::UserNotFound.into()),
         Some(x) => {
             let dept_result = Dept::select_by_id(rb, &x.dept_id).await?;
             let dept = match dept_result {
-                None => Err(AppError::BusinessError("部门不存在")),
+                None => Err(AppError::from(ErrorCode::DeptNotFound)),
                 Some(x) => {
                     Ok(QueryDeptDetailResp {
                         id: x.id.unwrap_or_default(),               //部门id
@@ -332,6 +436,7 @@ pub async fn query_sys_user_detail(
                 .map(|x| x.post_id)
                 .collect::<Vec<i64>>();
 
+            let avatar = avatar_url(&x.avatar, &x.user_name);
             let sys_user = QueryUserDetailResp {
                 id: x.id.unwrap_or_default(),                       //主键
                 mobile: x.mobile,                                   //手机
@@ -339,7 +444,7 @@ pub async fn query_sys_user_detail(
                 nick_name: x.nick_name,                             //用户昵称
                 user_type: x.user_type.unwrap_or_default(),         //用户类型（00系统用户）
                 email: x.email,                                     //用户邮箱
-                avatar: x.avatar,                                   //头像路径
+                avatar,                                             //头像路径
                 status: x.status,                                   //状态(1:正常，0:禁用)
                 dept_id: x.dept_id,                                 //部门ID
                 login_ip: x.login_ip,                               //最后登录IP
@@ -365,6 +470,15 @@ pub async fn query_sys_user_detail(
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/queryUserList",
+    tag = "用户管理",
+    summary = "查询用户信息列表",
+    request_body = QueryUserListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<UserListDataResp>>)),
+    security(("token" = []))
+)]
 #[post("/system/user/queryUserList", data = "<item>")]
 pub async fn query_sys_user_list(item: Json<QueryUserListReq>, _auth: Token) -> AppResult<Value> {
     log::info!("query sys_user_list params: {:?}", &item);
@@ -375,12 +489,36 @@ pub async fn query_sys_user_list(item: Json<QueryUserListReq>, _auth: Token) ->
     let status = item.status.unwrap_or(2);
     let dept_id = item.dept_id.unwrap_or_default();
 
+    let keyword = item.keyword.as_deref().unwrap_or_default().trim(); //关键字(模糊搜索)
+    let begin_time = item.begin_time.as_deref().unwrap_or_default(); //创建时间(开始)
+    let end_time = item.end_time.as_deref().unwrap_or_default(); //创建时间(结束)
+    check_time_range(begin_time, end_time)?;
+
+    let order_by = order_by(
+        item.sort_field.as_deref(),
+        item.sort_order.as_deref(),
+        &[
+            ("id", "id"),
+            ("userName", "user_name"),
+            ("nickName", "nick_name"),
+            ("mobile", "mobile"),
+            ("status", "status"),
+            ("loginDate", "login_date"),
+            ("createTime", "create_time"),
+        ],
+        "create_time desc",
+    );
+
     let page = &PageRequest::new(item.page_no, item.page_size);
-    let d = User::select_sys_user_list(rb, page, mobile, user_name, status, dept_id).await?;
+    let d = User::select_sys_user_list(
+        rb, page, mobile, user_name, status, dept_id, keyword, begin_time, end_time, &order_by,
+    )
+    .await?;
 
     let total = d.total;
     let mut sys_user_list_data: Vec<UserListDataResp> = Vec::new();
     for x in d.records {
+        let avatar = avatar_url(&x.avatar, &x.user_name);
         sys_user_list_data.push(UserListDataResp {
             id: x.id.unwrap_or_default(),                       //主键
             mobile: x.mobile,                                   //手机
@@ -388,7 +526,7 @@ pub async fn query_sys_user_list(item: Json<QueryUserListReq>, _auth: Token) ->
             nick_name: x.nick_name,                             //用户昵称
             user_type: x.user_type.unwrap_or_default(),         //用户类型（00系统用户）
             email: x.email,                                     //用户邮箱
-            avatar: x.avatar,                                   //头像路径
+            avatar,                                             //头像路径
             status: x.status,                                   //状态(1:正常，0:禁用)
             dept_id: x.dept_id,                                 //部门ID
             login_ip: x.login_ip,                               //最后登录IP
@@ -411,24 +549,30 @@ pub async fn query_sys_user_list(item: Json<QueryUserListReq>, _auth: Token) ->
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/login",
+    tag = "用户管理",
+    summary = "用户登录",
+    request_body = UserLoginReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
 #[post("/system/user/login", data = "<item>")]
-pub async fn login(item: Json<UserLoginReq>) -> AppResult<Value> {
+pub async fn login(item: Json<UserLoginReq>, client: ClientInfo) -> AppResult<Value> {
     log::info!("user login params: {:?}", &item);
     let req = item.0;
     let rb = &mut RB.clone();
 
-    // let user_agent = headers.get("User-Agent").unwrap().to_str().unwrap();
-    let user_agent = "";
-    log::info!("user agent: {:?}", user_agent);
-    let agent = UserAgentUtil::new(user_agent);
+    log::info!("user login client: {:?}", client);
+    let agent = UserAgentUtil::new(&client.user_agent);
 
     let user_result = User::select_by_account(rb, &req.account).await?;
     log::info!("query user by account: {:?}", user_result);
 
     match user_result {
         None => {
-            add_login_log(req.account, 0, "用户不存在", agent).await;
-            Err(AppError::BusinessError("用户不存在"))
+            add_login_log(req.account, 0, "用户不存在", &client, agent).await;
+            Err(ErrorCode::UserNotFound.into())
         }
         Some(user) => {
             let mut s_user = user.clone();
@@ -437,22 +581,30 @@ pub async fn login(item: Json<UserLoginReq>) -> AppResult<Value> {
             let password = user.password;
 
             if password.ne(&req.password) {
-                add_login_log(req.account, 0, "密码不正确", agent).await;
-                return Err(AppError::BusinessError("密码不正确"));
+                add_login_log(req.account, 0, "密码不正确", &client, agent).await;
+                return Err(ErrorCode::PasswordIncorrect.into());
             }
 
             let btn_menu = query_btn_menu(&id).await;
 
             if btn_menu.len() == 0 {
-                add_login_log(req.account, 0, "用户没有分配角色或者菜单,不能登录", agent).await;
-                return Err(AppError::BusinessError("用户没有分配角色或者菜单,不能登录"));
+                add_login_log(
+                    req.account,
+                    0,
+                    "用户没有分配角色或者菜单,不能登录",
+                    &client,
+                    agent,
+                )
+                .await;
+                return Err(ErrorCode::UserNoPermission.into());
             }
 
             let token = JwtToken::new(id, &username, btn_menu).create_token("123")?;
 
-            add_login_log(req.account, 1, "登录成功", agent.clone()).await;
+            add_login_log(req.account, 1, "登录成功", &client, agent.clone()).await;
             s_user.login_os = agent.os;
             s_user.login_browser = agent.browser;
+            s_user.login_ip = client.ip_string();
             s_user.login_date = Some(DateTime::now());
             User::update_by_map(rb, &s_user, value! {"id": &s_user.id}).await?;
             ok_result_data(token)
@@ -465,23 +617,31 @@ pub async fn login(item: Json<UserLoginReq>) -> AppResult<Value> {
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
-async fn add_login_log(name: String, status: i8, msg: &str, agent: UserAgentUtil) {
+async fn add_login_log(
+    name: String,
+    status: i8,
+    msg: &str,
+    client: &ClientInfo,
+    agent: UserAgentUtil,
+) {
+    METRICS.observe_login(status == 1);
+
     let sys_login_log = LoginLog {
-        id: None,                             //访问ID
-        login_name: name,                     //登录账号
-        ipaddr: "todo".to_string(),           //登录IP地址
-        login_location: "todo".to_string(),   //登录地点
-        platform: agent.platform,             //平台信息
-        browser: agent.browser,               //浏览器类型
-        version: agent.version,               //浏览器版本
-        os: agent.os,                         //操作系统
-        arch: agent.arch,                     //体系结构信息
-        engine: agent.engine,                 //渲染引擎信息
-        engine_details: agent.engine_details, //渲染引擎详细信息
-        extra: agent.extra,                   //其他信息（可选）
-        status,                               //登录状态(0:失败,1:成功)
-        msg: msg.to_string(),                 //提示消息
-        login_time: None,                     //访问时间
+        id: None,                               //访问ID
+        login_name: name,                       //登录账号
+        ipaddr: client.ip_string(),             //登录IP地址
+        login_location: ip_location(client.ip), //登录地点
+        platform: agent.platform,               //平台信息
+        browser: agent.browser,                 //浏览器类型
+        version: agent.version,                 //浏览器版本
+        os: agent.os,                           //操作系统
+        arch: agent.arch,                       //体系结构信息
+        engine: agent.engine,                   //渲染引擎信息
+        engine_details: agent.engine_details,   //渲染引擎详细信息
+        extra: agent.extra,                     //其他信息（可选）
+        status,                                 //登录状态(0:失败,1:成功)
+        msg: msg.to_string(),                   //提示消息
+        login_time: None,                       //访问时间
     };
 
     match LoginLog::insert(&mut RB.clone(), &sys_login_log).await {
@@ -525,6 +685,15 @@ async fn query_btn_menu(id: &i64) -> Vec<String> {
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/queryUserRole",
+    tag = "用户管理",
+    summary = "查询用户角色",
+    request_body = QueryUserRoleReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<QueryUserRoleResp>)),
+    security(("token" = []))
+)]
 #[post("/system/user/queryUserRole", data = "<item>")]
 pub async fn query_user_role(item: Json<QueryUserRoleReq>, _auth: Token) -> AppResult<Value> {
     log::info!("query user_role params: {:?}", item);
@@ -567,6 +736,15 @@ pub async fn query_user_role(item: Json<QueryUserRoleReq>, _auth: Token) -> AppR
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/updateUserRole",
+    tag = "用户管理",
+    summary = "更新用户角色",
+    request_body = UpdateUserRoleReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>)),
+    security(("token" = []))
+)]
 #[post("/system/user/updateUserRole", data = "<item>")]
 pub async fn update_user_role(item: Json<UpdateUserRoleReq>, _auth: Token) -> AppResult<Value> {
     log::info!("update_user_role params: {:?}", item);
@@ -577,7 +755,7 @@ pub async fn update_user_role(item: Json<UpdateUserRoleReq>, _auth: Token) -> Ap
     let len = item.role_ids.len();
 
     if user_id == 1 {
-        return Err(AppError::BusinessError("不能修改超级管理员的角色"));
+        return Err(ErrorCode::UserSuperAdminRole.into());
     }
 
     UserRole::delete_by_map(rb, value! {"user_id": &user_id}).await?;
@@ -595,6 +773,8 @@ pub async fn update_user_role(item: Json<UpdateUserRoleReq>, _auth: Token) -> Ap
 
     UserRole::insert_batch(rb, &list, len as u64).await?;
 
+    EVENT_HUB.publish_users(vec![user_id], EVENT_PERMISSION_CHANGED, json!({}));
+
     ok_result()
 }
 /*
@@ -602,6 +782,14 @@ pub async fn update_user_role(item: Json<UpdateUserRoleReq>, _auth: Token) -> Ap
  *author：刘飞华
  *date：2025/01/09 16:16:41
  */
+#[utoipa::path(
+    get,
+    path = "/api/system/user/queryUserMenu",
+    tag = "用户管理",
+    summary = "查询用户菜单",
+    responses((status = 200, description = "操作成功", body = BaseResponse<QueryUserMenuResp>)),
+    security(("token" = []))
+)]
 #[get("/system/user/queryUserMenu")]
 pub async fn query_user_menu(auth: Token) -> AppResult<Value> {
     let user_id = auth.id;
@@ -610,8 +798,8 @@ pub async fn query_user_menu(auth: Token) -> AppResult<Value> {
     let rb = &mut RB.clone();
 
     //根据id查询用户
-    match User::select_by_id(rb, 1).await? {
-        None => Err(AppError::BusinessError("用户不存在")),
+    match User::select_by_id(rb, user_id).await? {
+        None => Err(ErrorCode::UserNotFound.into()),
         Some(user) => {
             //role_id为1是超级管理员--判断是不是超级管理员
             let sql_str = "select count(id) from sys_user_role where role_id = 1 and user_id = ?";
@@ -670,9 +858,7 @@ pub async fn query_user_menu(auth: Token) -> AppResult<Value> {
             let resp = QueryUserMenuResp {
                 sys_menu,
                 btn_menu,
-                avatar:
-                    "https://gw.alipayobjects.com/zos/antfincdn/XAosXuNZyF/BiazfanxmamNRoxxVxka.png"
-                        .to_string(),
+                avatar: avatar_url(&user.avatar, &user.user_name),
                 name: user.user_name,
             };
 
@@ -680,3 +866,160 @@ pub async fn query_user_menu(auth: Token) -> AppResult<Value> {
         }
     }
 }
+
+/*
+ *订阅当前用户的实时事件(sse)
+ *author：刘飞华
+ *date：2026/10/19 16:31:08
+ */
+#[utoipa::path(
+    get,
+    path = "/api/system/user/subscribeUserEvent",
+    tag = "用户管理",
+    summary = "订阅当前用户的实时事件(sse)",
+    responses((status = 200, description = "服务端推送事件流", content_type = "text/event-stream", body = String)),
+    security(("token" = []))
+)]
+#[get("/system/user/subscribeUserEvent")]
+pub async fn subscribe_user_event(auth: Token, mut shutdown: Shutdown) -> EventStream![] {
+    let user_id = auth.id;
+    log::info!("subscribe user event params user_id {:?}", user_id);
+
+    let mut rx = EVENT_HUB.subscribe();
+    EventStream! {
+        loop {
+            let push_event = select! {
+                msg = rx.recv() => match msg {
+                    Ok(msg) => msg,
+                    Err(RecvError::Closed) => break,
+                    Err(RecvError::Lagged(n)) => {
+                        log::warn!("user {} lagged {} events", user_id, n);
+                        continue;
+                    }
+                },
+                _ = &mut shutdown => break,
+            };
+
+            if !push_event.is_for(user_id) {
+                continue;
+            }
+
+            yield Event::json(&push_event.data).event(push_event.event);
+
+            //强制下线后关闭连接
+            if push_event.event == EVENT_FORCE_LOGOUT {
+                break;
+            }
+        }
+    }
+    .heartbeat(Duration::from_secs(15))
+}
+
+/*
+ *上传我的头像(裁剪缩放为标准尺寸后保存)
+ *author：刘飞华
+ *date：2026/10/19 19:12:26
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/updateMyAvatar",
+    tag = "用户管理",
+    summary = "上传我的头像(裁剪缩放为标准尺寸后保存)",
+    request_body(content = UpdateMyAvatarReq, content_type = "multipart/form-data"),
+    responses((status = 200, description = "操作成功", body = BaseResponse<UpdateMyAvatarResp>)),
+    security(("token" = []))
+)]
+#[post("/system/user/updateMyAvatar", data = "<item>")]
+pub async fn update_my_avatar(item: Form<UpdateMyAvatarReq<'_>>, auth: Token) -> AppResult<Value> {
+    log::info!("update my avatar params: {:?}", &item);
+    let rb = &RB.clone();
+
+    if item.file.len() == 0 {
+        return Err(ErrorCode::AvatarEmpty.into());
+    }
+
+    let data = read_temp_file(&item.file).await?;
+
+    let mut ids: Vec<i64> = Vec::new();
+    let mut urls: Vec<String> = Vec::new();
+    for (x, size) in resize_avatar(&data)?.iter().zip(AVATAR_SIZES) {
+        let file_name = format!("avatar_{}.png", size);
+        let sys_file = save_file(&file_name, x, &auth.username, "avatar").await?;
+        let id = sys_file.id.unwrap_or_default();
+        ids.push(id);
+        urls.push(format!("/api/system/user/avatar?id={}", id));
+    }
+
+    let update_sql = "update sys_user set avatar = ? where id = ?";
+    rb.exec(update_sql, vec![value!(&urls[0]), value!(auth.id)])
+        .await?;
+
+    //删除之前上传的头像(其他用户仍在使用的保留),失败不影响本次修改
+    let mut old_files = Vec::new();
+    for x in SysFile::select_by_biz_type(rb, "avatar", &auth.username).await? {
+        let id = x.id.unwrap_or_default();
+        let url = format!("/api/system/user/avatar?id={}", id);
+        if !ids.contains(&id) && count_by_avatar(rb, &url).await? == 0 {
+            old_files.push(x);
+        }
+    }
+    if let Err(e) = delete_files(rb, &old_files).await {
+        log::error!("delete old avatar error: {}", e);
+    }
+
+    ok_result_data(UpdateMyAvatarResp {
+        avatar: urls[0].clone(),       //头像地址(256x256)
+        avatar_small: urls[1].clone(), //小头像地址(64x64)
+    })
+}
+
+/*
+ *查询用户头像(头像需要在img标签中直接展示,不校验token)
+ *author：刘飞华
+ *date：2026/10/19 19:12:26
+ */
+#[utoipa::path(
+    get,
+    path = "/api/system/user/avatar",
+    tag = "用户管理",
+    summary = "查询用户头像(头像需要在img标签中直接展示,不校验token)",
+    params(
+        ("id" = i64, Query, description = "文件ID")
+    ),
+    responses((status = 200, description = "头像图片", content_type = "image/*"))
+)]
+#[get("/system/user/avatar?<id>")]
+pub async fn query_user_avatar(id: i64) -> AppResult<FileResponse> {
+    let rb = &RB.clone();
+
+    match SysFile::select_by_id(rb, id).await? {
+        Some(x) if x.biz_type == "avatar" && x.storage_type == FILE_STORAGE.storage_type() => {
+            let data = FILE_STORAGE.get(&x.file_key).await?;
+            ok_result_file(data, &x.content_type, &x.file_name)
+        }
+        _ => Err(ErrorCode::AvatarNotFound.into()),
+    }
+}
+
+/*
+ *查询默认头像(根据name生成,style: identicon:对称色块,initials:首字母)
+ *author：刘飞华
+ *date：2026/10/19 19:12:26
+ */
+#[utoipa::path(
+    get,
+    path = "/api/system/user/defaultAvatar",
+    tag = "用户管理",
+    summary = "查询默认头像(根据name生成,style: identicon:对称色块,initials:首字母)",
+    params(
+        ("name" = String, Query, description = "用户名称"),
+        ("style" = Option<String>, Query, description = "头像样式(initials/identicon)")
+    ),
+    responses((status = 200, description = "默认头像", content_type = "image/svg+xml", body = String))
+)]
+#[get("/system/user/defaultAvatar?<name>&<style>")]
+pub async fn query_default_avatar(name: &str, style: Option<&str>) -> (ContentType, String) {
+    let default_style = SYS_CONFIG.get_str(CONFIG_DEFAULT_AVATAR_STYLE, "initials");
+    let svg = default_avatar_svg(name, style.unwrap_or(&default_style));
+    (ContentType::SVG, svg)
+}
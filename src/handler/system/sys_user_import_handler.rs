@@ -0,0 +1,616 @@
+use crate::common::config_cache::{CONFIG_USER_INIT_PASSWORD, SYS_CONFIG};
+use crate::common::error::{AppError, AppResult};
+use crate::common::error_code::{BizError, ErrorCode};
+use crate::common::event_hub::{EVENT_FORCE_LOGOUT, EVENT_HUB};
+use crate::common::i18n::Lang;
+use crate::common::result::{ok_result_data, ok_result_file, BaseResponse, FileResponse};
+use crate::common::storage::FILE_MAX_SIZE;
+use crate::handler::system::sys_file_handler::read_temp_file;
+use crate::middleware::auth::Token;
+use crate::middleware::validate::field_errors;
+use crate::model::system::sys_dept_model::Dept;
+use crate::model::system::sys_post_model::Post;
+use crate::model::system::sys_user_model::User;
+use crate::model::system::sys_user_post_model::UserPost;
+use crate::utils::excel_util::{
+    read_table, table_content_type, write_table, TABLE_FORMAT_CSV, TABLE_FORMAT_XLSX,
+};
+use crate::vo::system::sys_user_vo::*;
+use crate::RB;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use rbatis::executor::Executor;
+use rbatis::rbatis_codegen::ops::AsProxy;
+use rbatis::RBatis;
+use rbs::value;
+use rocket::form::Form;
+use rocket::serde::json::serde_json::json;
+use rocket::serde::json::Value;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use validator::Validate;
+
+//一次最多导入的用户数
+const IMPORT_USER_MAX_ROWS: usize = 1000;
+
+//导入用户的列(字段名称, 列名),列名带*的为必填列
+const IMPORT_USER_COLUMNS: [(&str, &str); 9] = [
+    ("userName", "用户账号*"),
+    ("nickName", "用户昵称*"),
+    ("mobile", "手机号码*"),
+    ("email", "邮箱"),
+    ("deptId", "部门编号*"),
+    ("postCodes", "岗位编码(多个用逗号分隔)"),
+    ("status", "状态(1:正常,0:停用)"),
+    ("remark", "备注"),
+    ("password", "密码(为空时使用初始密码)"),
+];
+
+//导入结果文件中错误信息的列名
+const IMPORT_USER_ERROR_COLUMN: &str = "错误信息";
+
+/*
+ *下载用户导入模板
+ *author：刘飞华
+ *date：2026/10/20 02:46:15
+ */
+#[utoipa::path(
+    get,
+    path = "/api/system/user/downloadImportTemplate",
+    tag = "用户管理",
+    summary = "下载用户导入模板",
+    params(("format" = Option<String>, Query, description = "文件格式(xlsx/csv,默认xlsx)")),
+    responses((status = 200, description = "导入模板", content_type = "application/octet-stream")),
+    security(("token" = []))
+)]
+#[get("/system/user/downloadImportTemplate?<format>")]
+pub async fn download_import_user_template(
+    format: Option<&str>,
+    _auth: Token,
+) -> AppResult<FileResponse> {
+    let format = format.unwrap_or(TABLE_FORMAT_XLSX);
+    if ![TABLE_FORMAT_XLSX, TABLE_FORMAT_CSV].contains(&format) {
+        return Err(ErrorCode::UserImportFormatInvalid.into());
+    }
+
+    let headers = IMPORT_USER_COLUMNS.map(|(_, title)| title);
+    let data = write_table(format, &headers, &[]).map_err(|e| {
+        log::error!("write user import template error: {}", e);
+        AppError::from(ErrorCode::UserImportFileFailed)
+    })?;
+    let file_name = format!("user_import_template.{}", format);
+    ok_result_file(data, table_content_type(format), &file_name)
+}
+
+/*
+ *导入用户(逐行校验,校验通过的行在事务中写入,有错误时生成导入结果文件;dryRun为true时只校验)
+ *author：刘飞华
+ *date：2026/10/20 02:46:15
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/importUser",
+    tag = "用户管理",
+    summary = "导入用户(xlsx/csv,dryRun为true时只校验)",
+    request_body(content = ImportUserReq, content_type = "multipart/form-data"),
+    responses((status = 200, description = "操作成功", body = BaseResponse<ImportUserResp>)),
+    security(("token" = []))
+)]
+#[post("/system/user/importUser", data = "<item>")]
+pub async fn import_sys_user(
+    item: Form<ImportUserReq<'_>>,
+    lang: Lang,
+    _auth: Token,
+) -> AppResult<Value> {
+    log::info!("import sys_user params: {:?}", &item);
+    let rb = &mut RB.clone();
+
+    let update_support = item.update_support.unwrap_or_default();
+    let dry_run = item.dry_run.unwrap_or_default();
+
+    let file = &item.file;
+    let file_ext = file
+        .raw_name()
+        .map(|x| x.dangerous_unsafe_unsanitized_raw().as_str())
+        .and_then(|x| x.rsplit_once('.'))
+        .map(|(_, ext)| ext.to_lowercase())
+        .unwrap_or_default();
+    if ![TABLE_FORMAT_XLSX, TABLE_FORMAT_CSV].contains(&file_ext.as_str()) {
+        return Err(ErrorCode::UserImportFormatInvalid.into());
+    }
+    if file.len() > *FILE_MAX_SIZE {
+        return Err(ErrorCode::FileTooLarge
+            .with("max", *FILE_MAX_SIZE / 1024 / 1024)
+            .into());
+    }
+
+    let data = read_temp_file(file).await?;
+    let table = read_table(&file_ext, &data).map_err(|e| {
+        log::error!("import sys_user parse error: {}", e);
+        AppError::from(ErrorCode::UserImportParseFailed)
+    })?;
+    let rows = parse_user_import_rows(table)?;
+
+    let mut plans = build_user_import_plan(rb, rows, update_support, lang).await?;
+
+    if !dry_run {
+        let tx = rb.acquire_begin().await?;
+        if let Err(e) = apply_user_import_plan(&tx, &mut plans).await {
+            tx.rollback().await?;
+            return Err(e);
+        }
+        tx.commit().await?;
+
+        //导入时停用的已有用户,和修改用户状态一样强制下线
+        let disabled_ids: Vec<i64> = plans
+            .iter()
+            .filter(|x| x.is_update() && x.user.status == 0)
+            .filter_map(|x| x.user.id)
+            .collect();
+        if !disabled_ids.is_empty() {
+            EVENT_HUB.publish_users(
+                disabled_ids,
+                EVENT_FORCE_LOGOUT,
+                json!({"reason": "用户已被禁用"}),
+            );
+        }
+    }
+
+    let failed: Vec<&UserImportPlan> = plans.iter().filter(|x| !x.errors.is_empty()).collect();
+    //结果文件直接在响应中返回(base64),不保存到文件存储中
+    let (result_file, result_file_name) = match failed.is_empty() {
+        true => (None, None),
+        false => {
+            let data = user_import_result_file(&file_ext, &failed, lang)?;
+            let file_name = format!("user_import_result.{}", file_ext);
+            (Some(STANDARD.encode(data)), Some(file_name))
+        }
+    };
+
+    ok_result_data(ImportUserResp {
+        dry_run,                                                        //是否只校验
+        total: plans.len() as u64,                                      //数据行数
+        created: plans.iter().filter(|x| x.is_create()).count() as u64, //新增条数
+        updated: plans.iter().filter(|x| x.is_update()).count() as u64, //更新条数
+        failed: failed.len() as u64,                                    //失败条数
+        errors: failed
+            .iter()
+            .flat_map(|p| {
+                p.errors.iter().map(|(field, message)| ImportUserErrorResp {
+                    row: p.row,                          //行号
+                    user_name: p.user.user_name.clone(), //用户账号
+                    field: field.clone(),                //字段名称
+                    message: message.clone(),            //错误信息
+                })
+            })
+            .collect(),
+        result_file,      //导入结果文件
+        result_file_name, //导入结果文件名
+    })
+}
+
+/*
+ *导入文件中的一行用户数据
+ *author：刘飞华
+ *date：2026/10/20 02:46:15
+ */
+struct UserImportRow {
+    row: u64,            //表格中的行号(表头为第1行)
+    values: Vec<String>, //单元格(按IMPORT_USER_COLUMNS的顺序)
+}
+
+impl UserImportRow {
+    fn get(&self, field: &str) -> &str {
+        IMPORT_USER_COLUMNS
+            .iter()
+            .position(|(x, _)| *x == field)
+            .and_then(|i| self.values.get(i))
+            .map(|x| x.as_str())
+            .unwrap_or_default()
+    }
+}
+
+/*
+ *导入计划-用户(user.id为空时新增,否则更新;errors不为空时跳过)
+ *author：刘飞华
+ *date：2026/10/20 02:46:15
+ */
+struct UserImportPlan {
+    row: u64,
+    values: Vec<String>,
+    user: User,
+    post_ids: Vec<i64>,
+    errors: Vec<(String, String)>, //(字段名称, 错误信息)
+    lang: Lang,                    //错误信息的语言
+}
+
+impl UserImportPlan {
+    fn is_create(&self) -> bool {
+        self.errors.is_empty() && self.user.id.is_none()
+    }
+
+    fn is_update(&self) -> bool {
+        self.errors.is_empty() && self.user.id.is_some()
+    }
+
+    fn error(&mut self, field: &str, error: impl Into<BizError>) {
+        self.errors
+            .push((field.to_string(), error.into().message(self.lang)));
+    }
+}
+
+/*
+ *列名(去掉必填标记和说明,例如: 用户账号*  -> 用户账号)
+ *author：刘飞华
+ *date：2026/10/20 02:46:15
+ */
+fn import_column_name(title: &str) -> &str {
+    title
+        .split(['*', '(', '（'])
+        .next()
+        .unwrap_or_default()
+        .trim()
+}
+
+/*
+ *按表头解析导入的用户数据(列名或字段名称都可以作为表头,列的顺序不限)
+ *author：刘飞华
+ *date：2026/10/20 02:46:15
+ */
+fn parse_user_import_rows(table: Vec<Vec<String>>) -> AppResult<Vec<UserImportRow>> {
+    let mut table = table.into_iter();
+    let header = table.next().unwrap_or_default();
+
+    let mut indexes: Vec<Option<usize>> = Vec::new();
+    for (field, title) in IMPORT_USER_COLUMNS {
+        let index = header.iter().position(|x| {
+            import_column_name(x) == import_column_name(title) || x.eq_ignore_ascii_case(field)
+        });
+        if index.is_none() && title.contains('*') {
+            return Err(ErrorCode::UserImportColumnMissing
+                .with("column", import_column_name(title))
+                .into());
+        }
+        indexes.push(index);
+    }
+
+    let mut rows: Vec<UserImportRow> = Vec::new();
+    for (i, x) in table.enumerate() {
+        if x.iter().all(|c| c.is_empty()) {
+            continue;
+        }
+        let values = indexes
+            .iter()
+            .map(|index| index.and_then(|i| x.get(i)).cloned().unwrap_or_default())
+            .collect();
+        rows.push(UserImportRow {
+            row: i as u64 + 2,
+            values,
+        });
+    }
+
+    if rows.is_empty() {
+        return Err(ErrorCode::UserImportEmpty.into());
+    }
+    if rows.len() > IMPORT_USER_MAX_ROWS {
+        return Err(ErrorCode::UserImportTooManyRows
+            .with("max", IMPORT_USER_MAX_ROWS)
+            .into());
+    }
+    Ok(rows)
+}
+
+/*
+ *根据导入的数据和数据库中已有的用户生成导入计划(逐行校验,不写入数据库)
+ *author：刘飞华
+ *date：2026/10/20 02:46:15
+ */
+async fn build_user_import_plan(
+    rb: &RBatis,
+    rows: Vec<UserImportRow>,
+    update_support: bool,
+    lang: Lang,
+) -> AppResult<Vec<UserImportPlan>> {
+    let dept_ids: HashSet<i64> = Dept::select_all(rb)
+        .await?
+        .into_iter()
+        .filter(|x| x.del_flag != Some(0))
+        .filter_map(|x| x.id)
+        .collect();
+    let post_ids: HashMap<String, i64> = Post::select_all(rb)
+        .await?
+        .into_iter()
+        .filter_map(|x| Some((x.post_code, x.id?)))
+        .collect();
+
+    //一次查询出导入数据中的账号、手机号码和邮箱对应的已有用户
+    let mut users_by_field: HashMap<(&str, String), User> = HashMap::new();
+    for (field, column) in [
+        ("userName", "user_name"),
+        ("mobile", "mobile"),
+        ("email", "email"),
+    ] {
+        let values: Vec<&str> = rows
+            .iter()
+            .map(|x| x.get(field))
+            .filter(|x| !x.is_empty())
+            .collect();
+        if values.is_empty() {
+            continue;
+        }
+        for u in User::select_by_map(rb, value! {column: &values}).await? {
+            let value = match field {
+                "userName" => u.user_name.clone(),
+                "mobile" => u.mobile.clone(),
+                _ => u.email.clone(),
+            };
+            users_by_field.insert((field, value), u);
+        }
+    }
+
+    let init_password = SYS_CONFIG.get_str(CONFIG_USER_INIT_PASSWORD, "123456");
+    let mut first_rows: HashMap<(&str, String), u64> = HashMap::new();
+    let mut plans: Vec<UserImportPlan> = Vec::new();
+    for r in rows {
+        let mut errors: Vec<(String, String)> = Vec::new();
+
+        let dept_id = match r.get("deptId").parse::<i64>() {
+            Ok(x) if dept_ids.contains(&x) => x,
+            _ => {
+                let error = ErrorCode::UserImportDeptNotFound.with("deptId", r.get("deptId"));
+                errors.push(("deptId".to_string(), error.message(lang)));
+                0
+            }
+        };
+
+        let mut user_post_ids: Vec<i64> = Vec::new();
+        for code in r.get("postCodes").split([',', '，']).map(|x| x.trim()) {
+            match post_ids.get(code) {
+                _ if code.is_empty() => {}
+                Some(x) => user_post_ids.push(*x),
+                None => {
+                    let error = ErrorCode::UserImportPostNotFound.with("postCode", code);
+                    errors.push(("postCodes".to_string(), error.message(lang)));
+                }
+            }
+        }
+
+        let status = match r.get("status") {
+            "" | "1" | "正常" => 1,
+            "0" | "停用" => 0,
+            _ => -1,
+        };
+        let password = match r.get("password") {
+            "" => init_password.clone(),
+            x => x.to_string(),
+        };
+        let req = AddUserReq {
+            mobile: r.get("mobile").to_string(),       //手机
+            user_name: r.get("userName").to_string(),  //用户账号
+            nick_name: r.get("nickName").to_string(),  //用户昵称
+            password,                                  //用户密码
+            email: r.get("email").to_string(),         //用户邮箱
+            avatar: None,                              //头像路径
+            status,                                    //状态(1:正常，0:禁用)
+            dept_id,                                   //部门ID
+            remark: Some(r.get("remark").to_string()), //备注
+            post_ids: user_post_ids.clone(),           //岗位ids
+        };
+        if let Err(e) = req.validate() {
+            for x in field_errors(&e, lang) {
+                errors.push((x.field, x.message));
+            }
+        }
+
+        //和导入文件中前面的行重复
+        for field in ["userName", "mobile", "email"] {
+            let value = r.get(field);
+            if value.is_empty() {
+                continue;
+            }
+            match first_rows.entry((field, value.to_string())) {
+                Entry::Occupied(x) => {
+                    let error = ErrorCode::UserImportDuplicated.with("row", x.get());
+                    errors.push((field.to_string(), error.message(lang)));
+                }
+                Entry::Vacant(x) => {
+                    x.insert(r.row);
+                }
+            }
+        }
+
+        let old = users_by_field.get(&("userName", req.user_name.clone()));
+        let mut plan = UserImportPlan {
+            row: r.row,
+            values: r.values,
+            user: User {
+                id: None,                          //主键
+                mobile: req.mobile,                //手机
+                user_name: req.user_name,          //用户账号
+                nick_name: req.nick_name,          //用户昵称
+                user_type: Some("01".to_string()), //用户类型（00系统用户）
+                email: req.email,                  //用户邮箱
+                avatar: "".to_string(),            //头像路径
+                password: req.password,            //密码
+                status: req.status,                //状态(1:正常，0:禁用)
+                dept_id: req.dept_id,              //部门ID
+                login_ip: "".to_string(),          //最后登录IP
+                login_date: None,                  //最后登录时间
+                login_browser: "".to_string(),     //浏览器类型
+                login_os: "".to_string(),          //操作系统
+                pwd_update_date: None,             //密码最后更新时间
+                remark: req.remark,                //备注
+                del_flag: 1,                       //删除标志（0代表删除 1代表存在）
+                create_time: None,                 //创建时间
+                update_time: None,                 //修改时间
+            },
+            post_ids: user_post_ids,
+            errors,
+            lang,
+        };
+
+        //账号已存在时更新用户信息(保留密码、头像和登录信息)
+        match old {
+            None => {}
+            Some(_) if !update_support => plan.error("userName", ErrorCode::UserNameExists),
+            Some(u) if u.id == Some(1) => plan.error("userName", ErrorCode::UserSuperAdmin),
+            Some(u) => {
+                plan.user.id = u.id;
+                plan.user.user_type = None;
+                plan.user.avatar = u.avatar.clone();
+                plan.user.password = u.password.clone();
+                plan.user.login_ip = u.login_ip.clone();
+                plan.user.login_date = u.login_date.clone();
+                plan.user.login_browser = u.login_browser.clone();
+                plan.user.login_os = u.login_os.clone();
+                plan.user.pwd_update_date = u.pwd_update_date.clone();
+                plan.user.del_flag = u.del_flag;
+            }
+        }
+
+        for (field, code) in [
+            ("mobile", ErrorCode::UserMobileExists),
+            ("email", ErrorCode::UserEmailExists),
+        ] {
+            let value = match field {
+                "mobile" => plan.user.mobile.clone(),
+                _ => plan.user.email.clone(),
+            };
+            if let Some(u) = users_by_field.get(&(field, value)) {
+                if u.id.is_none() || u.id != plan.user.id {
+                    plan.error(field, code);
+                }
+            }
+        }
+
+        plans.push(plan);
+    }
+    Ok(plans)
+}
+
+/*
+ *执行导入计划(只写入校验通过的行)
+ *author：刘飞华
+ *date：2026/10/20 02:46:15
+ */
+async fn apply_user_import_plan(tx: &dyn Executor, plans: &mut [UserImportPlan]) -> AppResult<()> {
+    for p in plans.iter_mut().filter(|x| x.errors.is_empty()) {
+        let user_id = match p.user.id {
+            Some(id) => {
+                User::update_by_map(tx, &p.user, value! {"id": id}).await?;
+                UserPost::delete_by_map(tx, value! {"user_id": id}).await?;
+                id
+            }
+            None => User::insert(tx, &p.user).await?.last_insert_id.i64(),
+        };
+
+        let user_post_list: Vec<UserPost> = p
+            .post_ids
+            .iter()
+            .map(|&post_id| UserPost { user_id, post_id })
+            .collect();
+        if !user_post_list.is_empty() {
+            UserPost::insert_batch(tx, &user_post_list, user_post_list.len() as u64).await?;
+        }
+    }
+    Ok(())
+}
+
+/*
+ *生成导入结果文件(导入失败的行和错误信息,修改后可以直接重新导入;密码列置空,不输出明文密码)
+ *author：刘飞华
+ *date：2026/10/20 02:46:15
+ */
+fn user_import_result_file(
+    format: &str,
+    failed: &[&UserImportPlan],
+    lang: Lang,
+) -> AppResult<Vec<u8>> {
+    let mut headers = IMPORT_USER_COLUMNS.map(|(_, title)| title).to_vec();
+    headers.push(IMPORT_USER_ERROR_COLUMN);
+    let password_index = IMPORT_USER_COLUMNS
+        .iter()
+        .position(|(x, _)| *x == "password");
+
+    let rows: Vec<Vec<String>> = failed
+        .iter()
+        .map(|p| {
+            let message = p
+                .errors
+                .iter()
+                .map(|(field, message)| {
+                    let column = IMPORT_USER_COLUMNS
+                        .iter()
+                        .find(|(x, _)| x == field)
+                        .map(|(_, title)| import_column_name(title))
+                        .unwrap_or(field);
+                    format!("{}: {}", column, message)
+                })
+                .collect::<Vec<String>>()
+                .join("; ");
+            let mut row = p.values.clone();
+            if let Some(x) = password_index.and_then(|i| row.get_mut(i)) {
+                x.clear();
+            }
+            row.push(match lang {
+                Lang::ZhCn => format!("第{}行 {}", p.row, message),
+                Lang::EnUs => format!("Row {}: {}", p.row, message),
+            });
+            row
+        })
+        .collect();
+
+    write_table(format, &headers, &rows).map_err(|e| {
+        log::error!("write user import result error: {}", e);
+        AppError::from(ErrorCode::UserImportFileFailed)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rocket::serde::json::serde_json;
+
+    #[test]
+    fn test_user_import_result_file_hides_password() {
+        let header = IMPORT_USER_COLUMNS
+            .map(|(_, title)| title.to_string())
+            .to_vec();
+        let row = vec![
+            "zs",
+            "张三",
+            "13800000000",
+            "",
+            "1",
+            "",
+            "1",
+            "",
+            "secret123",
+        ];
+        let rows =
+            parse_user_import_rows(vec![header, row.iter().map(|x| x.to_string()).collect()])
+                .unwrap();
+
+        let r = &rows[0];
+        let plan = UserImportPlan {
+            row: r.row,
+            values: r.values.clone(),
+            user: serde_json::from_value(json!({
+                "mobile": r.get("mobile"), "user_name": r.get("userName"),
+                "nick_name": r.get("nickName"), "email": "", "avatar": "",
+                "password": r.get("password"), "status": 1, "dept_id": 1,
+                "login_ip": "", "login_browser": "", "login_os": "", "del_flag": 1,
+            }))
+            .unwrap(),
+            post_ids: vec![],
+            errors: vec![("deptId".to_string(), "部门不存在".to_string())],
+            lang: Lang::ZhCn,
+        };
+
+        let data = user_import_result_file(TABLE_FORMAT_CSV, &[&plan], Lang::ZhCn).unwrap();
+        let table = read_table(TABLE_FORMAT_CSV, &data).unwrap();
+        assert_eq!(table[1][0], "zs");
+        assert_eq!(table[1][8], "");
+        assert!(!String::from_utf8(data).unwrap().contains("secret123"));
+    }
+}
@@ -5,22 +5,36 @@ extern crate rbatis;
 #[macro_use]
 extern crate rocket;
 
+use crate::common::catcher;
+use crate::common::config_cache::SYS_CONFIG;
+use crate::common::scheduler::run_job_scheduler;
+use crate::common::storage::{FILE_MAX_SIZE, FILE_STORAGE};
+use crate::common::telemetry::{init_telemetry, SqlTraceIntercept};
+use crate::handler::monitor::{health_handler, metrics_handler, server_handler};
 use crate::handler::system::{
-    sys_dept_handler, sys_dict_data_handler, sys_dict_type_handler, sys_login_log_handler,
+    sys_config_handler, sys_dept_handler, sys_dict_data_handler, sys_dict_type_handler,
+    sys_file_handler, sys_job_handler, sys_job_log_handler, sys_login_log_handler,
     sys_menu_handler, sys_notice_handler, sys_operate_log_handler, sys_post_handler,
-    sys_role_handler, sys_user_handler,
+    sys_role_handler, sys_user_handler, sys_user_import_handler,
 };
+use crate::route::openapi::ApiDoc;
 use dotenvy::dotenv;
 use middleware::auth::Token;
+use middleware::metrics::MetricsFairing;
+use middleware::request_id::RequestIdFairing;
+use middleware::trace::TraceFairing;
 use rbatis::rbatis::RBatis;
 use rbatis::rbdc::pool::{ConnectionManager, Pool};
 use rbdc_mysql::MysqlDriver;
 use rbdc_pool_fast::FastPool;
-use rocket::serde::json::serde_json::json;
-use rocket::serde::json::Value;
-use rocket::{Config, Request};
+use rocket::data::{Limits, ToByteUnit};
+use rocket::{Config, Route};
 use std::env;
 use std::net::Ipv4Addr;
+use std::sync::Arc;
+use utils::log_util::log_deserializers;
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
 
 pub mod common;
 pub mod handler;
@@ -30,24 +44,148 @@ pub mod route;
 pub mod utils;
 pub mod vo;
 
+#[utoipa::path(
+    get,
+    path = "/ping",
+    tag = "健康检查",
+    summary = "连通性检查",
+    responses((status = 200, description = "pong", content_type = "text/plain", body = String)),
+    security(("token" = []))
+)]
 #[get("/ping")]
 fn ping(_auth: Token) -> &'static str {
     "pong"
 }
 
-#[catch(404)]
-fn not_found(req: &Request) -> Value {
-    json!({"code": 1,"msg": format!("Sorry, '{}' is not a valid path", req.uri())})
+/*
+ *根路径下的路由
+ *author：刘飞华
+ *date：2026/10/19 22:58:36
+ */
+pub fn root_routes() -> Vec<Route> {
+    routes![
+        ping,
+        health_handler::health_live,
+        health_handler::health_ready,
+        metrics_handler::query_metrics
+    ]
 }
 
-#[catch(403)]
-fn not_permissions(req: &Request) -> Value {
-    json!({"code": 1,"msg": format!("you has no permissions request path: '{}'", req.uri())})
-}
-
-#[catch(401)]
-fn resp() -> Value {
-    json!({"code": 401,"msg": "Unauthorized","description": "The request requires user authentication"})
+/*
+ *业务接口路由(挂载在/api下)
+ *author：刘飞华
+ *date：2026/10/19 22:58:36
+ */
+pub fn api_routes() -> Vec<Route> {
+    routes![
+        sys_user_handler::add_sys_user,
+        sys_user_handler::delete_sys_user,
+        sys_user_handler::update_sys_user,
+        sys_user_handler::update_sys_user_status,
+        sys_user_handler::update_sys_user_password,
+        sys_user_handler::reset_sys_user_password,
+        sys_user_handler::query_sys_user_detail,
+        sys_user_handler::query_sys_user_list,
+        sys_user_handler::login,
+        sys_user_handler::query_user_role,
+        sys_user_handler::update_user_role,
+        sys_user_handler::query_user_menu,
+        sys_user_handler::subscribe_user_event,
+        sys_user_handler::update_my_avatar,
+        sys_user_handler::query_user_avatar,
+        sys_user_handler::query_default_avatar,
+        sys_user_import_handler::download_import_user_template,
+        sys_user_import_handler::import_sys_user,
+        sys_role_handler::add_sys_role,
+        sys_role_handler::delete_sys_role,
+        sys_role_handler::update_sys_role,
+        sys_role_handler::update_sys_role_status,
+        sys_role_handler::query_sys_role_detail,
+        sys_role_handler::query_sys_role_list,
+        sys_role_handler::query_role_menu,
+        sys_role_handler::update_role_menu,
+        sys_role_handler::query_allocated_list,
+        sys_role_handler::query_unallocated_list,
+        sys_role_handler::cancel_auth_user,
+        sys_role_handler::batch_cancel_auth_user,
+        sys_role_handler::batch_auth_user,
+        sys_menu_handler::add_sys_menu,
+        sys_menu_handler::delete_sys_menu,
+        sys_menu_handler::update_sys_menu,
+        sys_menu_handler::update_sys_menu_status,
+        sys_menu_handler::query_sys_menu_detail,
+        sys_menu_handler::query_sys_menu_list,
+        sys_menu_handler::query_sys_menu_list_simple,
+        sys_menu_handler::query_sys_menu_resource_list,
+        sys_post_handler::add_sys_post,
+        sys_post_handler::delete_sys_post,
+        sys_post_handler::update_sys_post,
+        sys_post_handler::update_sys_post_status,
+        sys_post_handler::query_sys_post_detail,
+        sys_post_handler::query_sys_post_list,
+        sys_operate_log_handler::delete_sys_operate_log,
+        sys_operate_log_handler::query_sys_operate_log_detail,
+        sys_operate_log_handler::query_sys_operate_log_list,
+        sys_notice_handler::add_sys_notice,
+        sys_notice_handler::delete_sys_notice,
+        sys_notice_handler::update_sys_notice,
+        sys_notice_handler::update_sys_notice_status,
+        sys_notice_handler::query_sys_notice_detail,
+        sys_notice_handler::query_sys_notice_list,
+        sys_notice_handler::query_my_notice_list,
+        sys_notice_handler::query_my_unread_count,
+        sys_notice_handler::read_sys_notice,
+        sys_notice_handler::read_all_sys_notice,
+        sys_notice_handler::query_notice_read_statistics,
+        sys_file_handler::upload_sys_file,
+        sys_file_handler::download_sys_file,
+        sys_file_handler::delete_sys_file,
+        sys_file_handler::query_sys_file_list,
+        sys_login_log_handler::delete_sys_login_log,
+        sys_login_log_handler::query_sys_login_log_detail,
+        sys_login_log_handler::query_sys_login_log_list,
+        sys_login_log_handler::query_sys_login_log_statistics,
+        sys_dict_type_handler::add_sys_dict_type,
+        sys_dict_type_handler::delete_sys_dict_type,
+        sys_dict_type_handler::update_sys_dict_type,
+        sys_dict_type_handler::update_sys_dict_type_status,
+        sys_dict_type_handler::query_sys_dict_type_detail,
+        sys_dict_type_handler::query_sys_dict_type_list,
+        sys_dict_type_handler::export_sys_dict_type,
+        sys_dict_type_handler::import_sys_dict_type,
+        sys_dict_data_handler::add_sys_dict_data,
+        sys_dict_data_handler::delete_sys_dict_data,
+        sys_dict_data_handler::update_sys_dict_data,
+        sys_dict_data_handler::update_sys_dict_data_status,
+        sys_dict_data_handler::query_sys_dict_data_detail,
+        sys_dict_data_handler::query_sys_dict_data_list,
+        sys_dict_data_handler::query_dict_data_by_type,
+        sys_dict_data_handler::query_dict_data_by_types,
+        sys_dept_handler::add_sys_dept,
+        sys_dept_handler::delete_sys_dept,
+        sys_dept_handler::update_sys_dept,
+        sys_dept_handler::update_sys_dept_status,
+        sys_dept_handler::query_sys_dept_detail,
+        sys_dept_handler::query_sys_dept_list,
+        sys_config_handler::add_sys_config,
+        sys_config_handler::delete_sys_config,
+        sys_config_handler::update_sys_config,
+        sys_config_handler::query_sys_config_detail,
+        sys_config_handler::query_sys_config_list,
+        sys_config_handler::refresh_sys_config_cache,
+        sys_job_handler::add_sys_job,
+        sys_job_handler::delete_sys_job,
+        sys_job_handler::update_sys_job,
+        sys_job_handler::update_sys_job_status,
+        sys_job_handler::run_sys_job,
+        sys_job_handler::query_sys_job_detail,
+        sys_job_handler::query_sys_job_list,
+        sys_job_handler::query_job_handler_list,
+        sys_job_log_handler::delete_sys_job_log,
+        sys_job_log_handler::query_sys_job_log_detail,
+        sys_job_log_handler::query_sys_job_log_list,
+        server_handler::query_server_info,
+    ]
 }
 
 lazy_static! {
@@ -56,7 +194,7 @@ lazy_static! {
 
 #[rocket::main]
 async fn main() -> Result<(), rocket::Error> {
-    log4rs::init_file("src/config/log4rs.yaml", Default::default()).unwrap();
+    log4rs::init_file("src/config/log4rs.yaml", log_deserializers()).unwrap();
     dotenv().ok();
     let server_port = env::var("SERVER_PORT").expect("SERVER_PORT is not set in .env file");
     let db_url = env::var("DATABASE_URL").expect("DATABASE_URL is not set in .env file");
@@ -66,92 +204,51 @@ async fn main() -> Result<(), rocket::Error> {
 
     RB.init_pool(pool).expect("init db pool error");
 
+    //配置了OTEL_EXPORTER_OTLP_ENDPOINT时启用链路追踪
+    let tracer_provider = init_telemetry();
+    if tracer_provider.is_some() {
+        RB.intercepts.push(Arc::new(SqlTraceIntercept::default()));
+    }
+
+    //加载参数配置,数据库不可用时使用各参数的默认值
+    SYS_CONFIG.refresh().await;
+
+    rocket::tokio::spawn(sys_notice_handler::run_notice_scheduler());
+    rocket::tokio::spawn(run_job_scheduler());
+
+    //启动时创建文件存储,配置错误时直接退出
+    lazy_static::initialize(&FILE_STORAGE);
+    log::info!("file storage: {}", FILE_STORAGE.storage_type());
+
     let config = Config {
         address: Ipv4Addr::new(0, 0, 0, 0).into(),
         port: server_port.parse::<u16>().unwrap(),
+        limits: Limits::default()
+            .limit("file", FILE_MAX_SIZE.bytes())
+            .limit("data-form", (*FILE_MAX_SIZE + 1024 * 1024).bytes()),
         ..Config::debug_default()
     };
 
     let _rocket = rocket::build()
         .configure(config)
-        .mount("/", routes![ping])
+        .attach(RequestIdFairing)
+        .attach(TraceFairing)
+        .attach(MetricsFairing)
+        .mount("/", root_routes())
+        .mount("/api", api_routes())
         .mount(
-            "/api",
-            routes![
-                sys_user_handler::add_sys_user,
-                sys_user_handler::delete_sys_user,
-                sys_user_handler::update_sys_user,
-                sys_user_handler::update_sys_user_status,
-                sys_user_handler::update_sys_user_password,
-                sys_user_handler::reset_sys_user_password,
-                sys_user_handler::query_sys_user_detail,
-                sys_user_handler::query_sys_user_list,
-                sys_user_handler::login,
-                sys_user_handler::query_user_role,
-                sys_user_handler::update_user_role,
-                sys_user_handler::query_user_menu,
-                sys_role_handler::add_sys_role,
-                sys_role_handler::delete_sys_role,
-                sys_role_handler::update_sys_role,
-                sys_role_handler::update_sys_role_status,
-                sys_role_handler::query_sys_role_detail,
-                sys_role_handler::query_sys_role_list,
-                sys_role_handler::query_role_menu,
-                sys_role_handler::update_role_menu,
-                sys_role_handler::query_allocated_list,
-                sys_role_handler::query_unallocated_list,
-                sys_role_handler::cancel_auth_user,
-                sys_role_handler::batch_cancel_auth_user,
-                sys_role_handler::batch_auth_user,
-                sys_menu_handler::add_sys_menu,
-                sys_menu_handler::delete_sys_menu,
-                sys_menu_handler::update_sys_menu,
-                sys_menu_handler::update_sys_menu_status,
-                sys_menu_handler::query_sys_menu_detail,
-                sys_menu_handler::query_sys_menu_list,
-                sys_menu_handler::query_sys_menu_list_simple,
-                sys_menu_handler::query_sys_menu_resource_list,
-                sys_post_handler::add_sys_post,
-                sys_post_handler::delete_sys_post,
-                sys_post_handler::update_sys_post,
-                sys_post_handler::update_sys_post_status,
-                sys_post_handler::query_sys_post_detail,
-                sys_post_handler::query_sys_post_list,
-                sys_operate_log_handler::delete_sys_operate_log,
-                sys_operate_log_handler::query_sys_operate_log_detail,
-                sys_operate_log_handler::query_sys_operate_log_list,
-                sys_notice_handler::add_sys_notice,
-                sys_notice_handler::delete_sys_notice,
-                sys_notice_handler::update_sys_notice,
-                sys_notice_handler::update_sys_notice_status,
-                sys_notice_handler::query_sys_notice_detail,
-                sys_notice_handler::query_sys_notice_list,
-                sys_login_log_handler::delete_sys_login_log,
-                sys_login_log_handler::query_sys_login_log_detail,
-                sys_login_log_handler::query_sys_login_log_list,
-                sys_dict_type_handler::add_sys_dict_type,
-                sys_dict_type_handler::delete_sys_dict_type,
-                sys_dict_type_handler::update_sys_dict_type,
-                sys_dict_type_handler::update_sys_dict_type_status,
-                sys_dict_type_handler::query_sys_dict_type_detail,
-                sys_dict_type_handler::query_sys_dict_type_list,
-                sys_dict_data_handler::add_sys_dict_data,
-                sys_dict_data_handler::delete_sys_dict_data,
-                sys_dict_data_handler::update_sys_dict_data,
-                sys_dict_data_handler::update_sys_dict_data_status,
-                sys_dict_data_handler::query_sys_dict_data_detail,
-                sys_dict_data_handler::query_sys_dict_data_list,
-                sys_dept_handler::add_sys_dept,
-                sys_dept_handler::delete_sys_dept,
-                sys_dept_handler::update_sys_dept,
-                sys_dept_handler::update_sys_dept_status,
-                sys_dept_handler::query_sys_dept_detail,
-                sys_dept_handler::query_sys_dept_list,
-            ],
+            "/",
+            SwaggerUi::new("/swagger-ui/<_..>").url("/api-docs/openapi.json", ApiDoc::openapi()),
         )
-        .register("/", catchers![not_found, resp, not_permissions])
+        .register("/", catcher::catchers())
         .launch()
         .await?;
 
+    if let Some(provider) = tracer_provider {
+        if let Err(e) = provider.shutdown() {
+            log::error!("shutdown tracer provider error: {}", e);
+        }
+    }
+
     Ok(())
 }
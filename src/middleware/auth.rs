@@ -2,6 +2,7 @@ use rocket::http::Status;
 use rocket::request::{FromRequest, Outcome};
 use serde::Deserialize;
 
+use crate::common::request_context;
 use crate::utils::jwt_util::JwtToken;
 
 #[derive(Debug, Deserialize)]
@@ -14,7 +15,7 @@ pub struct Token {
 impl<'r> FromRequest<'r> for Token {
     type Error = ();
     async fn from_request(request: &'r rocket::Request<'_>) -> Outcome<Self, Self::Error> {
-        let path = request.uri().to_string();
+        let path = request.uri().path().to_string();
         let header_auth = request.headers().get_one("Authorization");
         if let Some(header_auth) = header_auth {
             let split_vec = header_auth.split_whitespace().collect::<Vec<_>>();
@@ -33,6 +34,7 @@ impl<'r> FromRequest<'r> for Token {
                         return Outcome::Error((Status::Unauthorized, ()));
                     }
                 };
+                request_context::set_user_id(request, jwt_token.id);
 
                 let mut flag: bool = false;
                 for token_permission in &jwt_token.permissions {
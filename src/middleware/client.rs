@@ -0,0 +1,41 @@
+use rocket::request::{FromRequest, Outcome};
+use rocket::Request;
+use std::net::IpAddr;
+
+/*
+ *客户端信息(IP地址按Rocket的ip_header配置取代理转发的真实IP,默认X-Real-IP)
+ *author：刘飞华
+ *date：2026/10/20 09:12:40
+ */
+#[derive(Debug, Clone)]
+pub struct ClientInfo {
+    pub ip: Option<IpAddr>, //客户端IP地址
+    pub user_agent: String, //User-Agent请求头
+}
+
+impl ClientInfo {
+    /*
+     *客户端IP地址(获取不到时为空)
+     *author：刘飞华
+     *date：2026/10/20 09:12:40
+     */
+    pub fn ip_string(&self) -> String {
+        self.ip.map(|x| x.to_string()).unwrap_or_default()
+    }
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for ClientInfo {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        Outcome::Success(ClientInfo {
+            ip: request.client_ip(),
+            user_agent: request
+                .headers()
+                .get_one("User-Agent")
+                .unwrap_or_default()
+                .to_string(),
+        })
+    }
+}
@@ -0,0 +1,38 @@
+use rocket::request::{FromRequest, Outcome};
+
+/*
+ *请求头If-None-Match(客户端缓存的ETag)
+ *author：刘飞华
+ *date：2026/10/19 17:42:15
+ */
+#[derive(Debug)]
+pub struct IfNoneMatch(pub Option<String>);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for IfNoneMatch {
+    type Error = ();
+    async fn from_request(request: &'r rocket::Request<'_>) -> Outcome<Self, Self::Error> {
+        let etag = request
+            .headers()
+            .get_one("If-None-Match")
+            .map(|x| x.to_string());
+        Outcome::Success(IfNoneMatch(etag))
+    }
+}
+
+impl IfNoneMatch {
+    /*
+     *判断客户端缓存是否仍然有效
+     *author：刘飞华
+     *date：2026/10/19 17:42:15
+     */
+    pub fn matches(&self, etag: &str) -> bool {
+        match &self.0 {
+            None => false,
+            Some(x) => x
+                .split(',')
+                .map(|s| s.trim().trim_start_matches("W/"))
+                .any(|s| s == "*" || s == etag),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::common::metrics::METRICS;
+use rocket::fairing::{Fairing, Info, Kind};
+use rocket::{Data, Request, Response};
+use std::time::Instant;
+
+//请求开始时间(缓存在request中)
+struct RequestStart(Instant);
+
+/*
+ *请求指标采集(按路由模板统计请求次数、耗时和状态码)
+ *author：刘飞华
+ *date：2026/10/19 21:40:05
+ */
+pub struct MetricsFairing;
+
+#[rocket::async_trait]
+impl Fairing for MetricsFairing {
+    fn info(&self) -> Info {
+        Info {
+            name: "Prometheus metrics",
+            kind: Kind::Request | Kind::Response,
+        }
+    }
+
+    async fn on_request(&self, request: &mut Request<'_>, _: &mut Data<'_>) {
+        request.local_cache(|| RequestStart(Instant::now()));
+    }
+
+    async fn on_response<'r>(&self, request: &'r Request<'_>, response: &mut Response<'r>) {
+        let start = request.local_cache(|| RequestStart(Instant::now()));
+        //使用路由模板作为标签,避免路径参数导致标签数量无限增长
+        let route = request.route().map(|x| x.uri.path()).unwrap_or("unmatched");
+        METRICS.observe_request(
+            request.method().as_str(),
+            route,
+            response.status().code,
+            start.0.elapsed().as_secs_f64(),
+        );
+    }
+}
@@ -1 +1,7 @@
 pub mod auth;
+pub mod client;
+pub mod etag;
+pub mod metrics;
+pub mod request_id;
+pub mod trace;
+pub mod validate;
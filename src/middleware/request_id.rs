@@ -0,0 +1,122 @@
+use crate::common::request_context::{self, RequestContext, REQUEST_ID_HEADER};
+use rocket::fairing::{Fairing, Info, Kind};
+use rocket::http::Header;
+use rocket::request::{FromRequest, Outcome};
+use rocket::{Data, Request, Response};
+use std::time::Instant;
+
+/*
+ *请求ID(优先使用请求头中的X-Request-Id,没有或格式不正确时生成)
+ *author：刘飞华
+ *date：2026/10/19 22:05:47
+ */
+#[derive(Debug, Clone)]
+pub struct RequestId(pub String);
+
+impl RequestId {
+    /*
+     *获取请求的请求ID(未经过fairing时为空字符串)
+     *author：刘飞华
+     *date：2026/10/19 22:05:47
+     */
+    pub fn of<'r>(request: &'r Request<'_>) -> &'r str {
+        &request.local_cache(|| RequestId(String::new())).0
+    }
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for RequestId {
+    type Error = ();
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        Outcome::Success(RequestId(RequestId::of(request).to_string()))
+    }
+}
+
+/*
+ *校验外部传入的请求ID(最长64位,只允许字母、数字、-和_)
+ *author：刘飞华
+ *date：2026/10/19 22:05:47
+ */
+fn is_valid_request_id(request_id: &str) -> bool {
+    !request_id.is_empty()
+        && request_id.len() <= 64
+        && request_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/*
+ *请求ID和访问日志(请求开始时设置请求上下文,响应时写入X-Request-Id响应头并输出访问日志)
+ *author：刘飞华
+ *date：2026/10/19 22:05:47
+ */
+pub struct RequestIdFairing;
+
+#[rocket::async_trait]
+impl Fairing for RequestIdFairing {
+    fn info(&self) -> Info {
+        Info {
+            name: "Request id",
+            kind: Kind::Request | Kind::Response,
+        }
+    }
+
+    async fn on_request(&self, request: &mut Request<'_>, _: &mut Data<'_>) {
+        let request_id = match request.headers().get_one(REQUEST_ID_HEADER) {
+            Some(x) if is_valid_request_id(x) => x.to_string(),
+            _ => uuid::Uuid::new_v4().simple().to_string(),
+        };
+
+        request_context::begin(
+            request,
+            RequestContext {
+                request_id: request_id.clone(),        //请求ID
+                method: request.method().to_string(),  //请求方法
+                uri: request.uri().path().to_string(), //请求地址
+                route: None,                           //路由模板
+                user_id: None,                         //用户ID
+                status: None,                          //响应状态码
+                latency_ms: None,                      //耗时(毫秒)
+                start: Instant::now(),                 //开始时间
+                span: None,                            //请求的span
+            },
+        );
+        request.local_cache(|| RequestId(request_id));
+    }
+
+    async fn on_response<'r>(&self, request: &'r Request<'_>, response: &mut Response<'r>) {
+        let request_id = RequestId::of(request);
+        response.set_header(Header::new(REQUEST_ID_HEADER, request_id.to_string()));
+
+        let route = request.route().map(|x| x.uri.path().to_string());
+        let status = response.status().code;
+        request_context::update(request, |x| {
+            x.route = route;
+            x.status = Some(status);
+            x.latency_ms = Some(x.start.elapsed().as_millis());
+        });
+        if let Some(x) = request_context::of(request) {
+            log::info!(
+                "{} {} {} {}ms",
+                x.method,
+                x.uri,
+                status,
+                x.latency_ms.unwrap_or_default()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::middleware::request_id::is_valid_request_id;
+
+    #[test]
+    fn test_is_valid_request_id() {
+        assert!(is_valid_request_id("a1b2-c3_d4"));
+        assert!(!is_valid_request_id(""));
+        assert!(!is_valid_request_id("a b"));
+        assert!(!is_valid_request_id("a\nb"));
+        assert!(!is_valid_request_id(&"a".repeat(65)));
+    }
+}
@@ -0,0 +1,84 @@
+use crate::common::request_context;
+use crate::common::telemetry::HeaderExtractor;
+use crate::middleware::request_id::RequestId;
+use opentelemetry::global;
+use rocket::fairing::{Fairing, Info, Kind};
+use rocket::{Data, Request, Response};
+use std::sync::Mutex;
+use tracing::field::Empty;
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+//请求的span(缓存在request中,响应时结束)
+struct RequestSpan(Mutex<Option<Span>>);
+
+/*
+ *请求链路追踪(每个请求一个span,sql的span挂在请求span下;需要在RequestIdFairing之后注册)
+ *author：刘飞华
+ *date：2026/10/19 22:31:26
+ */
+pub struct TraceFairing;
+
+#[rocket::async_trait]
+impl Fairing for TraceFairing {
+    fn info(&self) -> Info {
+        Info {
+            name: "OpenTelemetry trace",
+            kind: Kind::Request | Kind::Response,
+        }
+    }
+
+    async fn on_request(&self, request: &mut Request<'_>, _: &mut Data<'_>) {
+        let method = request.method().as_str();
+        let span = tracing::info_span!(
+            "request",
+            otel.name = %format!("{} {}", method, request.uri().path()),
+            otel.kind = "server",
+            otel.status_code = Empty,
+            http.request.method = method,
+            url.path = %request.uri().path(),
+            http.route = Empty,
+            http.response.status_code = Empty,
+            code.function = Empty,
+            request_id = RequestId::of(request),
+            user.id = Empty,
+        );
+        //上游传入了traceparent时延续上游的链路
+        let parent =
+            global::get_text_map_propagator(|x| x.extract(&HeaderExtractor(request.headers())));
+        let _ = span.set_parent(parent);
+
+        request_context::update(request, |x| x.span = Some(span.clone()));
+        request.local_cache(|| RequestSpan(Mutex::new(Some(span))));
+    }
+
+    async fn on_response<'r>(&self, request: &'r Request<'_>, response: &mut Response<'r>) {
+        let span = request
+            .local_cache(|| RequestSpan(Mutex::new(None)))
+            .0
+            .lock()
+            .ok()
+            .and_then(|mut x| x.take());
+        let span = match span {
+            Some(x) => x,
+            None => return,
+        };
+
+        //使用路由模板作为span名称,避免路径参数导致名称数量无限增长
+        if let Some(route) = request.route() {
+            span.record(
+                "otel.name",
+                format!("{} {}", request.method(), route.uri.path()),
+            );
+            span.record("http.route", route.uri.path());
+            if let Some(name) = &route.name {
+                span.record("code.function", name.as_ref());
+            }
+        }
+        let status = response.status().code;
+        span.record("http.response.status_code", status);
+        if status >= 500 {
+            span.record("otel.status_code", "ERROR");
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use crate::common::error_code::{BizError, ErrorCode};
+use crate::common::i18n::Lang;
+use rocket::data::{FromData, Outcome};
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::{Data, Request};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::ops::Deref;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+/*
+ *字段校验错误
+ *author：刘飞华
+ *date：2026/10/19 23:26:10
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldError {
+    pub field: String,   //字段名称(驼峰)
+    pub code: i32,       //错误码
+    pub message: String, //错误信息
+}
+
+/*
+ *请求参数校验失败的字段(保存在请求的local_cache中,供400的catcher输出)
+ *author：刘飞华
+ *date：2026/10/19 23:26:10
+ */
+#[derive(Debug, Default)]
+pub struct FieldErrors(pub Vec<FieldError>);
+
+impl FieldErrors {
+    /*
+     *获取请求参数校验失败的字段(没有时为空)
+     *author：刘飞华
+     *date：2026/10/19 23:26:10
+     */
+    pub fn of<'r>(request: &'r Request<'_>) -> &'r [FieldError] {
+        &request.local_cache(FieldErrors::default).0
+    }
+}
+
+/*
+ *带参数校验的Json(反序列化后根据VO上声明的校验规则校验,失败时返回400)
+ *author：刘飞华
+ *date：2026/10/19 23:26:10
+ */
+#[derive(Debug)]
+pub struct ValidJson<T>(pub T);
+
+impl<T> Deref for ValidJson<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+#[rocket::async_trait]
+impl<'r, T> FromData<'r> for ValidJson<T>
+where
+    T: DeserializeOwned + Validate,
+{
+    type Error = Vec<FieldError>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> Outcome<'r, Self> {
+        let item = match Json::<T>::from_data(req, data).await {
+            Outcome::Success(x) => x.into_inner(),
+            Outcome::Forward(x) => return Outcome::Forward(x),
+            Outcome::Error((status, e)) => {
+                log::warn!("parse json body error: {}", e);
+                return Outcome::Error((status, vec![]));
+            }
+        };
+
+        match item.validate() {
+            Ok(()) => Outcome::Success(ValidJson(item)),
+            Err(e) => {
+                let errors = field_errors(&e, Lang::of(req));
+                req.local_cache(|| FieldErrors(errors.clone()));
+                Outcome::Error((Status::BadRequest, errors))
+            }
+        }
+    }
+}
+
+/*
+ *把校验错误转换成字段错误列表(按字段名称排序,错误信息按语言生成)
+ *author：刘飞华
+ *date：2026/10/19 23:26:10
+ */
+pub fn field_errors(errors: &ValidationErrors, lang: Lang) -> Vec<FieldError> {
+    let mut list: Vec<FieldError> = errors
+        .field_errors()
+        .into_iter()
+        .flat_map(|(field, errors)| {
+            let field = to_camel_case(&field);
+            errors.iter().map(move |e| {
+                let error = rule_error(e);
+                FieldError {
+                    field: field.clone(),         //字段名称
+                    code: error.code.code(),      //错误码
+                    message: error.message(lang), //错误信息
+                }
+            })
+        })
+        .collect();
+    list.sort_by(|a, b| a.field.cmp(&b.field));
+    list
+}
+
+/*
+ *校验规则对应的错误码(按规则和参数选择,例如length只有max时为"不能超过{max}个字符")
+ *author：刘飞华
+ *date：2026/10/20 10:58:27
+ */
+fn rule_error(e: &ValidationError) -> BizError {
+    let min = e.params.get("min").map(|x| x.to_string());
+    let max = e.params.get("max").map(|x| x.to_string());
+    let code = match (e.code.as_ref(), &min, &max) {
+        ("length", Some(min), None) if min == "1" => ErrorCode::ValidateRequired,
+        ("length", Some(_), Some(_)) => ErrorCode::ValidateLength,
+        ("length", None, Some(_)) => ErrorCode::ValidateLengthMax,
+        ("length", Some(_), None) => ErrorCode::ValidateLengthMin,
+        ("range", Some(_), Some(_)) => ErrorCode::ValidateRange,
+        ("range", Some(_), None) => ErrorCode::ValidateRangeMin,
+        ("range", None, Some(_)) => ErrorCode::ValidateRangeMax,
+        ("email", _, _) => ErrorCode::ValidateEmail,
+        ("mobile", _, _) => ErrorCode::ValidateMobile,
+        ("yes_no", _, _) => ErrorCode::ValidateYesNo,
+        _ => ErrorCode::ValidateInvalid,
+    };
+
+    let mut error = BizError::from(code);
+    for (name, value) in [("min", min), ("max", max)] {
+        if let Some(x) = value {
+            error = error.with(name, x);
+        }
+    }
+    error
+}
+
+/*
+ *下划线转驼峰(和VO上的serde(rename_all = "camelCase")保持一致)
+ *author：刘飞华
+ *date：2026/10/19 23:26:10
+ */
+fn to_camel_case(field: &str) -> String {
+    let mut result = String::with_capacity(field.len());
+    let mut upper = false;
+    for c in field.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            result.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vo::system::sys_dept_vo::AddDeptReq;
+
+    #[test]
+    fn test_field_errors() {
+        let req = AddDeptReq {
+            parent_id: 0,
+            dept_name: "".to_string(),
+            sort: -1,
+            leader: "".to_string(),
+            phone: "".to_string(),
+            email: "abc".to_string(),
+            status: 3,
+        };
+        let errors = field_errors(&req.validate().unwrap_err(), Lang::ZhCn);
+        let fields: Vec<&str> = errors.iter().map(|x| x.field.as_str()).collect();
+        assert_eq!(fields, vec!["deptName", "email", "sort", "status"]);
+        assert_eq!(errors[0].message, "长度必须在1到30个字符之间");
+        assert_eq!(errors[1].code, ErrorCode::ValidateEmail.code());
+        assert_eq!(errors[2].message, "不能小于0");
+        assert_eq!(errors[3].message, "只能是0到1之间的值");
+
+        let errors = field_errors(&req.validate().unwrap_err(), Lang::EnUs);
+        assert_eq!(errors[3].message, "Must be between 0 and 1");
+    }
+}
@@ -1,9 +1,16 @@
+pub mod sys_config_model;
 pub mod sys_dept_model;
 pub mod sys_dict_data_model;
 pub mod sys_dict_type_model;
+pub mod sys_file_model;
+pub mod sys_job_log_model;
+pub mod sys_job_model;
 pub mod sys_login_log_model;
 pub mod sys_menu_model;
+pub mod sys_notice_attachment_model;
 pub mod sys_notice_model;
+pub mod sys_notice_target_model;
+pub mod sys_notice_user_model;
 pub mod sys_operate_log_model;
 pub mod sys_post_model;
 pub mod sys_role_dept_model;
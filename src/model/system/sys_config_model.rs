@@ -0,0 +1,74 @@
+// author：刘飞华
+// createTime：2026/10/19 19:20:14
+
+use rbatis::rbdc::datetime::DateTime;
+use serde::{Deserialize, Serialize};
+
+/*
+ *参数配置表
+ *author：刘飞华
+ *date：2026/10/19 19:20:14
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SysConfig {
+    pub id: Option<i64>,               //参数主键
+    pub config_name: String,           //参数名称
+    pub config_key: String,            //参数键名
+    pub config_value: String,          //参数键值
+    pub value_type: String,            //值类型(string:字符串,int:整数,bool:布尔,json:JSON)
+    pub is_builtin: String,            //是否内置（Y是 N否）
+    pub remark: String,                //备注
+    pub create_time: Option<DateTime>, //创建时间
+    pub update_time: Option<DateTime>, //修改时间
+}
+
+/*
+ *参数配置表基本操作
+ *author：刘飞华
+ *date：2026/10/19 19:20:14
+ */
+rbatis::crud!(SysConfig {}, "sys_config");
+
+/*
+ *根据id查询参数配置表
+ *author：刘飞华
+ *date：2026/10/19 19:20:14
+ */
+impl_select!(SysConfig{select_by_id(id:&i64) -> Option => "`where id = #{id} limit 1`"}, "sys_config");
+
+/*
+ *根据config_key查询参数配置表
+ *author：刘飞华
+ *date：2026/10/19 19:20:14
+ */
+impl_select!(SysConfig{select_by_config_key(config_key:&str) -> Option => "`where config_key = #{config_key} limit 1`"}, "sys_config");
+
+/*
+ *根据条件分页查询参数配置表
+ *author：刘飞华
+ *date：2026/10/19 19:20:14
+ */
+impl_select_page!(SysConfig{select_sys_config_list(
+    config_name:&str,
+    config_key:&str,
+    value_type:&str,
+    keyword:&str,
+    begin_time:&str,
+    end_time:&str,
+    order_by:&str,) =>"
+    where 1=1
+     if config_name != null && config_name != '':
+      ` and config_name like concat('%', #{config_name}, '%') `
+     if config_key != null && config_key != '':
+      ` and config_key = #{config_key} `
+     if value_type != null && value_type != '':
+      ` and value_type = #{value_type} `
+     if keyword != '':
+      ` and (config_name like concat('%', #{keyword}, '%') or config_key like concat('%', #{keyword}, '%') or remark like concat('%', #{keyword}, '%')) `
+     if begin_time != '':
+      ` and create_time >= #{begin_time} `
+     if end_time != '':
+      ` and create_time <= #{end_time} `
+     if !sql.contains('count'):
+      ` order by ${order_by} `"
+},"sys_config");
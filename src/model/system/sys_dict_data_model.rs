@@ -70,16 +70,29 @@ impl_select_page!(DictData{select_page() =>"
  *author：刘飞华
  *date：2024/12/25 10:01:11
  */
-impl_select_page!(DictData{select_dict_data_list(dict_label:&str, dict_type:&str, status:i8) =>"
+impl_select_page!(DictData{select_dict_data_list(
+    dict_label:&str,
+    dict_type:&str,
+    status:i8,
+    keyword:&str,
+    begin_time:&str,
+    end_time:&str,
+    order_by:&str,) =>"
     where 1=1
      if dict_label != null && dict_label != '':
-      ` and dict_label = #{dict_label} `
+      ` and dict_label like concat('%', #{dict_label}, '%') `
      if dict_type != null && dict_type != '':
       ` and dict_type = #{dict_type} `
      if status != 2:
       ` and status = #{status} `
+     if keyword != '':
+      ` and (dict_label like concat('%', #{keyword}, '%') or dict_value like concat('%', #{keyword}, '%') or remark like concat('%', #{keyword}, '%')) `
+     if begin_time != '':
+      ` and create_time >= #{begin_time} `
+     if end_time != '':
+      ` and create_time <= #{end_time} `
      if !sql.contains('count'):
-      ` order by create_time desc"
+      ` order by ${order_by} `"
 },"sys_dict_data");
 
 /*
@@ -105,3 +118,23 @@ pub async fn update_dict_data_type(
 pub async fn count_dict_data_by_type(rb: &RBatis, dict_type: &str) -> rbatis::Result<i64> {
     impled!()
 }
+
+/*
+ *根据字典类型查询已启用的字典数据(字典类型也需启用)
+ *author：刘飞华
+ *date：2026/10/19 17:42:15
+ */
+#[sql("select d.* from sys_dict_data d inner join sys_dict_type t on t.dict_type = d.dict_type where d.dict_type = ? and d.status = 1 and t.status = 1 order by d.dict_sort, d.id")]
+pub async fn select_enabled_by_dict_type(
+    rb: &RBatis,
+    dict_type: &str,
+) -> rbatis::Result<Vec<DictData>> {
+    impled!()
+}
+
+/*
+ *根据字典类型查询全部字典数据
+ *author：刘飞华
+ *date：2026/10/19 18:05:37
+ */
+impl_select!(DictData{select_by_dict_type(dict_type:&str) => "`where dict_type = #{dict_type} order by dict_sort, id`"}, "sys_dict_data");
@@ -57,14 +57,27 @@ impl_select_page!(DictType{select_page() =>"
  *author：刘飞华
  *date：2024/12/25 10:01:11
  */
-impl_select_page!(DictType{select_dict_type_list(dict_name:&str, dict_type:&str, status:i8) =>"
+impl_select_page!(DictType{select_dict_type_list(
+    dict_name:&str,
+    dict_type:&str,
+    status:i8,
+    keyword:&str,
+    begin_time:&str,
+    end_time:&str,
+    order_by:&str,) =>"
     where 1=1
      if dict_name != null && dict_name != '':
-      ` and dict_name = #{dict_name} `
+      ` and dict_name like concat('%', #{dict_name}, '%') `
      if dict_type != null && dict_type != '':
       ` and dict_type = #{dict_type} `
      if status != 2:
       ` and status = #{status} `
+     if keyword != '':
+      ` and (dict_name like concat('%', #{keyword}, '%') or dict_type like concat('%', #{keyword}, '%') or remark like concat('%', #{keyword}, '%')) `
+     if begin_time != '':
+      ` and create_time >= #{begin_time} `
+     if end_time != '':
+      ` and create_time <= #{end_time} `
      if !sql.contains('count'):
-      ` order by create_time desc"
+      ` order by ${order_by} `"
 },"sys_dict_type");
@@ -0,0 +1,85 @@
+// author：刘飞华
+// createTime：2026/10/19 18:26:40
+
+use rbatis::rbdc::datetime::DateTime;
+use rbatis::RBatis;
+use serde::{Deserialize, Serialize};
+
+/*
+ *文件信息
+ *author：刘飞华
+ *date：2026/10/19 18:26:40
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SysFile {
+    pub id: Option<i64>,               //文件ID
+    pub file_name: String,             //原始文件名
+    pub file_key: String,              //存储路径
+    pub file_ext: String,              //文件扩展名
+    pub file_size: i64,                //文件大小(字节)
+    pub content_type: String,          //文件类型
+    pub file_hash: String,             //文件内容sha256
+    pub storage_type: String,          //存储类型(local:本地磁盘,s3:对象存储)
+    pub biz_type: String,              //业务类型(avatar:头像)
+    pub create_by: String,             //上传者
+    pub create_time: Option<DateTime>, //上传时间
+}
+
+/*
+ *文件信息基本操作
+ *author：刘飞华
+ *date：2026/10/19 18:26:40
+ */
+rbatis::crud!(SysFile {}, "sys_file");
+
+/*
+ *根据id查询文件信息
+ *author：刘飞华
+ *date：2026/10/19 18:26:40
+ */
+impl_select!(SysFile{select_by_id(id:i64) -> Option => "`where id = #{id} limit 1`"}, "sys_file");
+
+/*
+ *根据文件hash查询已存储的文件(用于去重)
+ *author：刘飞华
+ *date：2026/10/19 18:26:40
+ */
+impl_select!(SysFile{select_by_hash(file_hash:&str, storage_type:&str) -> Option => "`where file_hash = #{file_hash} and storage_type = #{storage_type} limit 1`"}, "sys_file");
+
+/*
+ *根据业务类型查询某个用户上传的文件
+ *author：刘飞华
+ *date：2026/10/20 12:08:15
+ */
+impl_select!(SysFile{select_by_biz_type(biz_type:&str, create_by:&str) => "`where biz_type = #{biz_type} and create_by = #{create_by}`"}, "sys_file");
+
+/*
+ *根据条件分页查询文件信息
+ *author：刘飞华
+ *date：2026/10/19 18:26:40
+ */
+impl_select_page!(SysFile{select_sys_file_list(file_name:&str, file_ext:&str, storage_type:&str) =>"
+    where 1=1
+     if file_name != '':
+       ` and file_name like concat('%', #{file_name}, '%') `
+     if file_ext != '':
+       ` and file_ext = #{file_ext} `
+     if storage_type != '':
+       ` and storage_type = #{storage_type} `
+     if !sql.contains('count'):
+       ` order by create_time desc `"
+},"sys_file");
+
+/*
+ *查询引用同一存储路径的文件数量
+ *author：刘飞华
+ *date：2026/10/19 18:26:40
+ */
+#[sql("select count(1) from sys_file where file_key = ? and storage_type = ?")]
+pub async fn count_by_file_key(
+    rb: &RBatis,
+    file_key: &str,
+    storage_type: &str,
+) -> rbatis::Result<u64> {
+    impled!()
+}
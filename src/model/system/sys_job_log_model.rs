@@ -0,0 +1,60 @@
+// author：刘飞华
+// createTime：2026/10/19 20:02:31
+
+use rbatis::rbdc::datetime::DateTime;
+use serde::{Deserialize, Serialize};
+
+/*
+ *定时任务执行日志表
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SysJobLog {
+    pub id: Option<i64>,                //任务日志ID
+    pub job_id: i64,                    //任务ID
+    pub job_name: String,               //任务名称
+    pub job_group: String,              //任务组名
+    pub invoke_target: String,          //任务处理器名称
+    pub job_params: String,             //任务参数
+    pub trigger_type: i8,               //触发方式（0：定时,1:手动）
+    pub status: i8,                     //执行状态（0：失败,1:成功,2:跳过）
+    pub job_message: String,            //执行结果
+    pub exception_info: Option<String>, //错误信息
+    pub start_time: Option<DateTime>,   //开始时间
+    pub end_time: Option<DateTime>,     //结束时间
+    pub duration: i64,                  //耗时(毫秒)
+}
+
+/*
+ *定时任务执行日志表基本操作
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+rbatis::crud!(SysJobLog {}, "sys_job_log");
+
+/*
+ *根据id查询定时任务执行日志表
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+impl_select!(SysJobLog{select_by_id(id:&i64) -> Option => "`where id = #{id} limit 1`"}, "sys_job_log");
+
+/*
+ *根据条件分页查询定时任务执行日志表
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+impl_select_page!(SysJobLog{select_sys_job_log_list(job_id:i64, job_name:&str, job_group:&str, status:i8) =>"
+    where 1=1
+     if job_id != 0:
+      ` and job_id = #{job_id} `
+     if job_name != null && job_name != '':
+      ` and job_name = #{job_name} `
+     if job_group != null && job_group != '':
+      ` and job_group = #{job_group} `
+     if status != 3:
+      ` and status = #{status} `
+     if !sql.contains('count'):
+      ` order by start_time desc"
+},"sys_job_log");
@@ -0,0 +1,63 @@
+// author：刘飞华
+// createTime：2026/10/19 20:02:31
+
+use rbatis::rbdc::datetime::DateTime;
+use serde::{Deserialize, Serialize};
+
+/*
+ *定时任务表
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SysJob {
+    pub id: Option<i64>,               //任务ID
+    pub job_name: String,              //任务名称
+    pub job_group: String,             //任务组名
+    pub invoke_target: String,         //任务处理器名称
+    pub job_params: String,            //任务参数
+    pub cron_expression: String,       //cron表达式
+    pub concurrent: i8,                //是否允许并发执行（0：禁止,1:允许）
+    pub status: i8,                    //任务状态（0：暂停，1:正常）
+    pub remark: String,                //备注
+    pub create_time: Option<DateTime>, //创建时间
+    pub update_time: Option<DateTime>, //修改时间
+}
+
+/*
+ *定时任务表基本操作
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+rbatis::crud!(SysJob {}, "sys_job");
+
+/*
+ *根据id查询定时任务表
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+impl_select!(SysJob{select_by_id(id:&i64) -> Option => "`where id = #{id} limit 1`"}, "sys_job");
+
+/*
+ *查询正常状态的定时任务
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+impl_select!(SysJob{select_enabled_job() => "`where status = 1`"}, "sys_job");
+
+/*
+ *根据条件分页查询定时任务表
+ *author：刘飞华
+ *date：2026/10/19 20:02:31
+ */
+impl_select_page!(SysJob{select_sys_job_list(job_name:&str, job_group:&str, status:i8) =>"
+    where 1=1
+     if job_name != null && job_name != '':
+      ` and job_name = #{job_name} `
+     if job_group != null && job_group != '':
+      ` and job_group = #{job_group} `
+     if status != 2:
+      ` and status = #{status} `
+     if !sql.contains('count'):
+      ` order by create_time desc"
+},"sys_job");
@@ -59,20 +59,45 @@ impl_select_page!(LoginLog{select_page() =>"
  *author：刘飞华
  *date：2024/12/25 10:01:11
  */
-impl_select_page!(LoginLog{select_login_log_list(name:&str, ipaddr:&str,browser:&str,os:&str,status:&i8) =>"
+impl_select_page!(LoginLog{select_login_log_list(
+    name:&str,
+    ipaddr:&str,
+    login_location:&str,
+    browser:&str,
+    os:&str,
+    status:&i8,
+    statuses:&Option<Vec<i8>>,
+    begin_time:&str,
+    end_time:&str,
+    cursor_time:&str,
+    cursor_id:&i64,
+    order_by:&str,) =>"
     where 1=1
      if name != '':
-       ` and login_name = #{name} `
+       ` and login_name like concat(#{name}, '%') `
      if ipaddr != '':
-       ` and ipaddr = #{ipaddr} `
+       ` and ipaddr like concat(#{ipaddr}, '%') `
+     if login_location != '':
+       ` and login_location like concat('%', #{login_location}, '%') `
      if browser != '':
        ` and browser = #{browser} `
      if os != '':
        ` and os = #{os} `
      if status != 2:
        ` and status = #{status} `
+     if statuses != null:
+       ` and status in (`
+       trim ',': for item in statuses:
+         #{item},
+       `)`
+     if begin_time != '':
+       ` and login_time >= #{begin_time} `
+     if end_time != '':
+       ` and login_time <= #{end_time} `
      if !sql.contains('count'):
-       ` order by login_time desc `"
+       if cursor_time != '':
+         ` and (login_time < #{cursor_time} or (login_time = #{cursor_time} and id < #{cursor_id})) `
+       ` order by ${order_by} `"
 },"sys_login_log");
 
 /*
@@ -84,3 +109,153 @@ impl_select_page!(LoginLog{select_login_log_list(name:&str, ipaddr:&str,browser:
 pub async fn clean_login_log(rb: &RBatis) -> Option<i64> {
     impled!()
 }
+
+/*
+ *登录统计汇总
+ *author：刘飞华
+ *date：2026/10/19 14:20:05
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LoginLogTotal {
+    pub total_count: i64,   //登录总次数
+    pub success_count: i64, //登录成功次数
+    pub fail_count: i64,    //登录失败次数
+}
+
+/*
+ *按天统计的登录次数
+ *author：刘飞华
+ *date：2026/10/19 14:20:05
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LoginLogDaily {
+    pub login_date: String, //登录日期
+    pub success_count: i64, //登录成功次数
+    pub fail_count: i64,    //登录失败次数
+}
+
+/*
+ *分组统计的登录次数
+ *author：刘飞华
+ *date：2026/10/19 14:20:05
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LoginLogGroup {
+    pub name: String, //分组名称
+    pub count: i64,   //登录次数
+}
+
+/*
+ *异地登录(在统计时间之前从未成功登录过的地点)
+ *author：刘飞华
+ *date：2026/10/19 14:20:05
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LoginLogNewLocation {
+    pub login_name: String,                 //登录账号
+    pub login_location: String,             //登录地点
+    pub ipaddr: String,                     //登录IP地址
+    pub first_login_time: Option<DateTime>, //首次登录时间
+}
+
+/*
+ *统计时间范围内的登录次数
+ *author：刘飞华
+ *date：2026/10/19 14:20:05
+ */
+#[sql("select count(1) as total_count, count(case when status = 1 then 1 end) as success_count, count(case when status = 0 then 1 end) as fail_count from sys_login_log where login_time >= ? and login_time <= ?")]
+pub async fn count_login_log_total(
+    rb: &RBatis,
+    begin_time: &str,
+    end_time: &str,
+) -> rbatis::Result<LoginLogTotal> {
+    impled!()
+}
+
+/*
+ *按天统计登录成功和失败次数
+ *author：刘飞华
+ *date：2026/10/19 14:20:05
+ */
+#[sql("select date_format(login_time, '%Y-%m-%d') as login_date, count(case when status = 1 then 1 end) as success_count, count(case when status = 0 then 1 end) as fail_count from sys_login_log where login_time >= ? and login_time <= ? group by login_date order by login_date")]
+pub async fn count_login_log_by_day(
+    rb: &RBatis,
+    begin_time: &str,
+    end_time: &str,
+) -> rbatis::Result<Vec<LoginLogDaily>> {
+    impled!()
+}
+
+/*
+ *统计登录失败次数最多的账号
+ *author：刘飞华
+ *date：2026/10/19 14:20:05
+ */
+#[sql("select login_name as name, count(1) as count from sys_login_log where status = 0 and login_time >= ? and login_time <= ? group by login_name order by count desc limit ?")]
+pub async fn count_top_fail_account(
+    rb: &RBatis,
+    begin_time: &str,
+    end_time: &str,
+    limit: u64,
+) -> rbatis::Result<Vec<LoginLogGroup>> {
+    impled!()
+}
+
+/*
+ *统计登录失败次数最多的IP
+ *author：刘飞华
+ *date：2026/10/19 14:20:05
+ */
+#[sql("select ipaddr as name, count(1) as count from sys_login_log where status = 0 and login_time >= ? and login_time <= ? group by ipaddr order by count desc limit ?")]
+pub async fn count_top_fail_ip(
+    rb: &RBatis,
+    begin_time: &str,
+    end_time: &str,
+    limit: u64,
+) -> rbatis::Result<Vec<LoginLogGroup>> {
+    impled!()
+}
+
+/*
+ *统计浏览器分布
+ *author：刘飞华
+ *date：2026/10/19 14:20:05
+ */
+#[sql("select browser as name, count(1) as count from sys_login_log where login_time >= ? and login_time <= ? group by browser order by count desc")]
+pub async fn count_login_log_by_browser(
+    rb: &RBatis,
+    begin_time: &str,
+    end_time: &str,
+) -> rbatis::Result<Vec<LoginLogGroup>> {
+    impled!()
+}
+
+/*
+ *统计操作系统分布
+ *author：刘飞华
+ *date：2026/10/19 14:20:05
+ */
+#[sql("select os as name, count(1) as count from sys_login_log where login_time >= ? and login_time <= ? group by os order by count desc")]
+pub async fn count_login_log_by_os(
+    rb: &RBatis,
+    begin_time: &str,
+    end_time: &str,
+) -> rbatis::Result<Vec<LoginLogGroup>> {
+    impled!()
+}
+
+/*
+ *查询异地登录(账号在统计开始时间之前从未在该地点成功登录过)
+ *author：刘飞华
+ *date：2026/10/19 14:20:05
+ */
+#[sql("select t.login_name, t.login_location, max(t.ipaddr) as ipaddr, min(t.login_time) as first_login_time from sys_login_log t where t.status = 1 and t.login_time >= ? and t.login_time <= ? and not exists (select 1 from sys_login_log h where h.login_name = t.login_name and h.login_location = t.login_location and h.status = 1 and h.login_time < ?) group by t.login_name, t.login_location order by first_login_time desc limit ?")]
+pub async fn select_new_location_login(
+    rb: &RBatis,
+    begin_time: &str,
+    end_time: &str,
+    history_end_time: &str,
+    limit: u64,
+) -> rbatis::Result<Vec<LoginLogNewLocation>> {
+    impled!()
+}
@@ -0,0 +1,46 @@
+// author：刘飞华
+// createTime：2026/10/19 17:48:05
+
+use rbatis::rbdc::datetime::DateTime;
+use rbatis::RBatis;
+use serde::{Deserialize, Serialize};
+
+/*
+ *通知公告附件
+ *author：刘飞华
+ *date：2026/10/19 17:48:05
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NoticeAttachment {
+    pub id: Option<i64>,               //主键
+    pub notice_id: i64,                //公告ID
+    pub file_id: Option<i64>,          //文件ID(上传的文件)
+    pub file_name: String,             //文件名称
+    pub file_url: String,              //文件地址
+    pub sort: i32,                     //排序
+    pub create_time: Option<DateTime>, //创建时间
+}
+
+/*
+ *通知公告附件基本操作
+ *author：刘飞华
+ *date：2026/10/19 17:48:05
+ */
+rbatis::crud!(NoticeAttachment {}, "sys_notice_attachment");
+
+/*
+ *根据公告id查询通知公告附件
+ *author：刘飞华
+ *date：2026/10/19 17:48:05
+ */
+impl_select!(NoticeAttachment{select_by_notice_id(notice_id:i64) => "`where notice_id = #{notice_id} order by sort, id`"}, "sys_notice_attachment");
+
+/*
+ *通过文件id查询引用该文件的附件数量
+ *author：刘飞华
+ *date：2026/10/20 10:36:52
+ */
+#[sql("select count(1) from sys_notice_attachment where file_id = ?")]
+pub async fn count_by_file_id(rb: &RBatis, file_id: i64) -> rbatis::Result<u64> {
+    impled!()
+}
@@ -2,7 +2,10 @@
 // author：刘飞华
 // createTime：2024/12/25 10:01:11
 
+use rbatis::executor::Executor;
 use rbatis::rbdc::datetime::DateTime;
+use rbatis::rbdc::db::ExecResult;
+use rbatis::RBatis;
 use serde::{Deserialize, Serialize};
 
 /*
@@ -17,6 +20,9 @@ pub struct Notice {
     pub notice_type: i8,               //公告类型（1:通知,2:公告）
     pub notice_content: String,        //公告内容
     pub status: i8,                    //公告状态（0:关闭,1:正常 ）
+    pub target_type: i8,               //通知对象类型（0:全部用户,1:部门,2:角色,3:用户）
+    pub publish_at: Option<DateTime>,  //发布时间
+    pub expire_at: Option<DateTime>,   //过期时间
     pub remark: String,                //备注
     pub create_time: Option<DateTime>, //创建时间
     pub update_time: Option<DateTime>, //修改时间
@@ -48,14 +54,83 @@ impl_select!(Notice{select_by_title(title:&str) -> Option => "`where notice_titl
  *author：刘飞华
  *date：2024/12/25 10:01:11
  */
-impl_select_page!(Notice{select_sys_notice_list(title:&str, notice_type:i8, status:i8) =>"
+impl_select_page!(Notice{select_sys_notice_list(
+    title:&str,
+    notice_type:i8,
+    status:i8,
+    publish_state:i8,
+    keyword:&str,
+    begin_time:&str,
+    end_time:&str,
+    order_by:&str,) =>"
     where 1=1
      if title != '':
-       ` and notice_title = #{title} `
+       ` and notice_title like concat('%', #{title}, '%') `
      if notice_type != 0:
       ` and notice_type = #{notice_type} `
      if status != 2:
        ` and status = #{status} `
+     choose:
+       when publish_state == 1:
+         ` and status = 0 and publish_at is null `
+       when publish_state == 2:
+         ` and status = 0 and publish_at is not null and (expire_at is null or expire_at > now()) `
+       when publish_state == 3:
+         ` and status = 1 and (expire_at is null or expire_at > now()) `
+       when publish_state == 4:
+         ` and expire_at <= now() `
+     if keyword != '':
+       ` and (notice_title like concat('%', #{keyword}, '%') or remark like concat('%', #{keyword}, '%')) `
+     if begin_time != '':
+       ` and create_time >= #{begin_time} `
+     if end_time != '':
+       ` and create_time <= #{end_time} `
      if !sql.contains('count'):
-       ` order by create_time desc `"
+       ` order by ${order_by} `"
 },"sys_notice");
+
+/*
+ *查询到达发布时间的通知公告
+ *author：刘飞华
+ *date：2026/10/19 17:05:22
+ */
+#[sql("select * from sys_notice where status = 0 and publish_at <= now() and (expire_at is null or expire_at > now())")]
+pub async fn select_due_notice(rb: &RBatis) -> rbatis::Result<Vec<Notice>> {
+    impled!()
+}
+
+/*
+ *查询数据库的当前时间(发布时间、过期时间统一按数据库时钟判断,和定时任务一致)
+ *author：刘飞华
+ *date：2026/10/20 12:31:40
+ */
+#[sql("select date_format(now(), '%Y-%m-%d %H:%i:%s')")]
+pub async fn select_db_now(rb: &RBatis) -> rbatis::Result<String> {
+    impled!()
+}
+
+/*
+ *关闭已过期的通知公告
+ *author：刘飞华
+ *date：2026/10/19 17:05:22
+ */
+#[sql("update sys_notice set status = 0 where status = 1 and expire_at <= now()")]
+pub async fn expire_notice(rb: &RBatis) -> rbatis::Result<ExecResult> {
+    impled!()
+}
+
+/*
+ *更新通知公告的发布状态和发布时间、过期时间(发布时间、过期时间允许清空)
+ *author：刘飞华
+ *date：2026/10/19 17:05:22
+ */
+#[sql("update sys_notice set status = ?, publish_at = ?, expire_at = ? where id = ?")]
+pub async fn update_notice_schedule(
+    rb: &dyn Executor,
+    status: i8,
+    publish_at: Option<DateTime>,
+    expire_at: Option<DateTime>,
+    id: i64,
+) -> rbatis::Result<ExecResult> {
+    impled!()
+}
@@ -0,0 +1,22 @@
+// author：刘飞华
+// createTime：2026/10/19 15:02:47
+
+use serde::{Deserialize, Serialize};
+
+/*
+ *通知公告对象关联
+ *author：刘飞华
+ *date：2026/10/19 15:02:47
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NoticeTarget {
+    pub notice_id: i64, //公告ID
+    pub target_id: i64, //通知对象ID(部门ID、角色ID或用户ID)
+}
+
+/*
+ *通知公告对象关联基本操作
+ *author：刘飞华
+ *date：2026/10/19 15:02:47
+ */
+rbatis::crud!(NoticeTarget {}, "sys_notice_target");
@@ -0,0 +1,186 @@
+// author：刘飞华
+// createTime：2026/10/19 15:02:47
+
+use rbatis::executor::Executor;
+use rbatis::rbdc::datetime::DateTime;
+use rbatis::rbdc::db::ExecResult;
+use rbatis::RBatis;
+use serde::{Deserialize, Serialize};
+
+/*
+ *通知公告用户关联(送达和阅读记录)
+ *author：刘飞华
+ *date：2026/10/19 15:02:47
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NoticeUser {
+    pub id: Option<i64>,                 //主键
+    pub notice_id: i64,                  //公告ID
+    pub user_id: i64,                    //用户ID
+    pub read_flag: i8,                   //阅读状态（0:未读,1:已读）
+    pub delivery_time: Option<DateTime>, //送达时间
+    pub read_time: Option<DateTime>,     //阅读时间
+}
+
+/*
+ *通知公告用户关联基本操作
+ *author：刘飞华
+ *date：2026/10/19 15:02:47
+ */
+rbatis::crud!(NoticeUser {}, "sys_notice_user");
+
+/*
+ *我的通知公告
+ *author：刘飞华
+ *date：2026/10/19 15:02:47
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MyNotice {
+    pub id: i64,                         //公告ID
+    pub notice_title: String,            //公告标题
+    pub notice_type: i8,                 //公告类型（1:通知,2:公告）
+    pub notice_content: String,          //公告内容
+    pub read_flag: i8,                   //阅读状态（0:未读,1:已读）
+    pub delivery_time: Option<DateTime>, //送达时间
+    pub read_time: Option<DateTime>,     //阅读时间
+}
+
+/*
+ *通知公告阅读统计
+ *author：刘飞华
+ *date：2026/10/19 15:02:47
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NoticeReadCount {
+    pub total_count: i64,  //送达人数
+    pub read_count: i64,   //已读人数
+    pub unread_count: i64, //未读人数
+}
+
+/*
+ *送达通知公告(根据通知对象类型生成用户的送达记录,已送达的用户不会重复送达)
+ *target_type: 通知对象类型（0:全部用户,1:部门(包含下级部门),2:角色,3:用户）
+ *author：刘飞华
+ *date：2026/10/19 15:02:47
+ */
+#[py_sql(
+    "`insert ignore into sys_notice_user (notice_id, user_id) select #{notice_id}, u.id from sys_user u where u.del_flag = 1 `
+      choose:
+        when target_type == 1:
+          ` and u.dept_id in (select d.id from sys_dept d where `
+          trim 'or': for item in target_ids:
+            ` d.id = #{item} or find_in_set(#{item}, d.ancestors) or`
+          `)`
+        when target_type == 2:
+          ` and u.id in (select ur.user_id from sys_user_role ur where ur.role_id in (`
+          trim ',': for item in target_ids:
+            #{item},
+          `))`
+        when target_type == 3:
+          ` and u.id in (`
+          trim ',': for item in target_ids:
+            #{item},
+          `)`"
+)]
+pub async fn deliver_notice(
+    rb: &dyn Executor,
+    notice_id: i64,
+    target_type: i8,
+    target_ids: &[i64],
+) -> rbatis::Result<ExecResult> {
+    impled!()
+}
+
+/*
+ *分页查询我的通知公告
+ *author：刘飞华
+ *date：2026/10/19 15:02:47
+ */
+#[py_sql(
+    "`select n.id, n.notice_title, n.notice_type, n.notice_content, nu.read_flag, nu.delivery_time, nu.read_time from sys_notice_user nu join sys_notice n on n.id = nu.notice_id where nu.user_id = #{user_id} and n.status = 1 `
+      if read_flag != 2:
+        ` and nu.read_flag = #{read_flag} `
+      if notice_type != 0:
+        ` and n.notice_type = #{notice_type} `
+      ` order by nu.delivery_time desc, nu.id desc limit #{page_no},#{page_size}`"
+)]
+pub async fn select_my_notice_list(
+    rb: &dyn Executor,
+    user_id: i64,
+    read_flag: i8,
+    notice_type: i8,
+    page_no: u64,
+    page_size: u64,
+) -> rbatis::Result<Vec<MyNotice>> {
+    impled!()
+}
+
+/*
+ *查询我的通知公告数量
+ *author：刘飞华
+ *date：2026/10/19 15:02:47
+ */
+#[py_sql(
+    "`select count(1) from sys_notice_user nu join sys_notice n on n.id = nu.notice_id where nu.user_id = #{user_id} and n.status = 1 `
+      if read_flag != 2:
+        ` and nu.read_flag = #{read_flag} `
+      if notice_type != 0:
+        ` and n.notice_type = #{notice_type} `"
+)]
+pub async fn count_my_notice_list(
+    rb: &dyn Executor,
+    user_id: i64,
+    read_flag: i8,
+    notice_type: i8,
+) -> rbatis::Result<u64> {
+    impled!()
+}
+
+/*
+ *标记通知公告为已读
+ *author：刘飞华
+ *date：2026/10/19 15:02:47
+ */
+#[py_sql(
+    "`update sys_notice_user set read_flag = 1, read_time = now() where user_id = #{user_id} and read_flag = 0 and notice_id in (`
+      trim ',': for item in notice_ids:
+        #{item},
+      `)`"
+)]
+pub async fn read_notice(
+    rb: &dyn Executor,
+    user_id: i64,
+    notice_ids: &[i64],
+) -> rbatis::Result<ExecResult> {
+    impled!()
+}
+
+/*
+ *标记全部通知公告为已读
+ *author：刘飞华
+ *date：2026/10/19 15:02:47
+ */
+#[sql("update sys_notice_user set read_flag = 1, read_time = now() where user_id = ? and read_flag = 0")]
+pub async fn read_all_notice(rb: &RBatis, user_id: i64) -> rbatis::Result<ExecResult> {
+    impled!()
+}
+
+/*
+ *查询未读通知公告数量
+ *author：刘飞华
+ *date：2026/10/19 15:02:47
+ */
+#[sql("select count(1) from sys_notice_user nu join sys_notice n on n.id = nu.notice_id where nu.user_id = ? and nu.read_flag = 0 and n.status = 1")]
+pub async fn count_unread_notice(rb: &RBatis, user_id: i64) -> rbatis::Result<u64> {
+    impled!()
+}
+
+/*
+ *统计通知公告阅读情况
+ *author：刘飞华
+ *date：2026/10/19 15:02:47
+ */
+#[sql("select count(1) as total_count, count(case when read_flag = 1 then 1 end) as read_count, count(case when read_flag = 0 then 1 end) as unread_count from sys_notice_user where notice_id = ?")]
+pub async fn count_notice_read(rb: &RBatis, notice_id: i64) -> rbatis::Result<NoticeReadCount> {
+    impled!()
+}
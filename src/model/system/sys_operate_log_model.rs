@@ -64,6 +64,7 @@ impl_select_page!(OperateLog{select_page() =>"
 impl_select_page!(OperateLog{select_page_by_name(
     title:&str,
     business_type:&i8,
+    business_types:&Option<Vec<i8>>,
     method:&str,
     request_method:&str,
     operator_type:&i8,
@@ -71,30 +72,52 @@ impl_select_page!(OperateLog{select_page_by_name(
     dept_name:&str,
     operate_url:&str,
     operate_ip:&str,
-    status:&i8,) =>"
+    status:&i8,
+    statuses:&Option<Vec<i8>>,
+    begin_time:&str,
+    end_time:&str,
+    cursor_time:&str,
+    cursor_id:&i64,
+    order_by:&str,) =>"
     where 1=1
      if title != '':
-       ` and title = #{title} `
+       ` and title like concat('%', #{title}, '%') `
      if business_type != 4:
        ` and business_type = #{business_type} `
+     if business_types != null:
+       ` and business_type in (`
+       trim ',': for item in business_types:
+         #{item},
+       `)`
      if method != '':
-       ` and method = #{method} `
+       ` and method like concat('%', #{method}, '%') `
      if request_method != '':
        ` and request_method = #{request_method} `
      if operator_type != 3:
        ` and operator_type = #{operator_type} `
      if operate_name != '':
-       ` and operate_name = #{operate_name} `
+       ` and operate_name like concat(#{operate_name}, '%') `
      if dept_name != '':
-       ` and dept_name = #{dept_name} `
+       ` and dept_name like concat(#{dept_name}, '%') `
      if operate_url != '':
-       ` and operate_url = #{operate_url} `
+       ` and operate_url like concat('%', #{operate_url}, '%') `
      if operate_ip != '':
-       ` and operate_ip = #{operate_ip} `
+       ` and operate_ip like concat(#{operate_ip}, '%') `
      if status != 2:
        ` and status = #{status} `
+     if statuses != null:
+       ` and status in (`
+       trim ',': for item in statuses:
+         #{item},
+       `)`
+     if begin_time != '':
+       ` and operate_time >= #{begin_time} `
+     if end_time != '':
+       ` and operate_time <= #{end_time} `
      if !sql.contains('count'):
-       ` order by operate_time desc `"
+       if cursor_time != '':
+         ` and (operate_time < #{cursor_time} or (operate_time = #{cursor_time} and id < #{cursor_id})) `
+       ` order by ${order_by} `"
 },"sys_operate_log");
 
 /*
@@ -65,14 +65,27 @@ impl_select_page!(Post{select_page() =>"
  *author：刘飞华
  *date：2024/12/25 10:01:11
  */
-impl_select_page!(Post{select_post_list(post_code:&str, post_name:&str, status:i8) =>"
+impl_select_page!(Post{select_post_list(
+    post_code:&str,
+    post_name:&str,
+    status:i8,
+    keyword:&str,
+    begin_time:&str,
+    end_time:&str,
+    order_by:&str,) =>"
     where 1=1
      if post_code != null && post_code != '':
       ` and post_code = #{post_code} `
      if post_name != null && post_name != '':
-      ` and post_name = #{post_name} `
+      ` and post_name like concat('%', #{post_name}, '%') `
      if status != 2:
       ` and status = #{status} `
+     if keyword != '':
+      ` and (post_code like concat('%', #{keyword}, '%') or post_name like concat('%', #{keyword}, '%') or remark like concat('%', #{keyword}, '%')) `
+     if begin_time != '':
+      ` and create_time >= #{begin_time} `
+     if end_time != '':
+      ` and create_time <= #{end_time} `
      if !sql.contains('count'):
-      ` order by create_time desc"
+      ` order by ${order_by} `"
 },"sys_post");
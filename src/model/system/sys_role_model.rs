@@ -65,13 +65,26 @@ impl_select_page!(Role{select_page() =>"
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-impl_select_page!(Role{select_sys_role_list(role_name:&str,role_key:&str,status:i8) =>"
+impl_select_page!(Role{select_sys_role_list(
+    role_name:&str,
+    role_key:&str,
+    status:i8,
+    keyword:&str,
+    begin_time:&str,
+    end_time:&str,
+    order_by:&str,) =>"
       where 1=1
      if role_name != null && role_name != '':
-       ` and role_name = #{role_name} `
+       ` and role_name like concat('%', #{role_name}, '%') `
      if role_key != null && role_key != '':
        ` and role_key = #{role_key} `
      if status != 2:
        ` and status = #{status} `
+     if keyword != '':
+       ` and (role_name like concat('%', #{keyword}, '%') or role_key like concat('%', #{keyword}, '%') or remark like concat('%', #{keyword}, '%')) `
+     if begin_time != '':
+       ` and create_time >= #{begin_time} `
+     if end_time != '':
+       ` and create_time <= #{end_time} `
      if !sql.contains('count'):
-        ` order by create_time desc `"},"sys_role");
+        ` order by ${order_by} `"},"sys_role");
@@ -1,6 +1,7 @@
 // author：刘飞华
 // createTime：2024/12/12 14:41:44
 
+use crate::utils::cursor_util::Cursor;
 use rbatis::executor::Executor;
 use rbatis::rbdc::datetime::DateTime;
 use rbatis::rbdc::Error;
@@ -91,18 +92,42 @@ impl_select_page!(User{select_page() =>"
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-impl_select_page!(User{select_sys_user_list(mobile:&str,user_name:&str,status:i8,dept_id:i64) =>"
+impl_select_page!(User{select_sys_user_list(
+    mobile:&str,
+    user_name:&str,
+    status:i8,
+    dept_id:i64,
+    keyword:&str,
+    begin_time:&str,
+    end_time:&str,
+    order_by:&str,) =>"
       where 1=1
       if mobile != null && mobile != '':
        ` and mobile = #{mobile} `
      if user_name != null && user_name != '':
-       ` and user_name = #{user_name} `
+       ` and user_name like concat('%', #{user_name}, '%') `
      if status != 2:
        ` and status = #{status} `
      if dept_id != 0:
        ` and (dept_id = #{dept_id} OR dept_id IN (SELECT id FROM sys_dept WHERE find_in_set(#{dept_id}, ancestors))) `
+     if keyword != '':
+       ` and (user_name like concat('%', #{keyword}, '%') or nick_name like concat('%', #{keyword}, '%') or mobile like concat('%', #{keyword}, '%') or email like concat('%', #{keyword}, '%')) `
+     if begin_time != '':
+       ` and create_time >= #{begin_time} `
+     if end_time != '':
+       ` and create_time <= #{end_time} `
      if !sql.contains('count'):
-        ` order by create_time desc `"},"sys_user");
+        ` order by ${order_by} `"},"sys_user");
+
+/*
+ *查询使用该头像地址的用户数量
+ *author：刘飞华
+ *date：2026/10/20 12:08:15
+ */
+#[sql("select count(1) from sys_user where avatar = ?")]
+pub async fn count_by_avatar(rb: &dyn Executor, avatar: &str) -> Result<u64, Error> {
+    impled!()
+}
 
 /*
  *根据条件分页查询已配用户角色列表
@@ -115,6 +140,9 @@ impl_select_page!(User{select_sys_user_list(mobile:&str,user_name:&str,status:i8
                 ` and u.mobile = #{mobile} `
             if user_name != '':
                 ` and u.user_name = #{user_name} `
+            if cursor.time != '':
+                ` and (u.create_time < #{cursor.time} or (u.create_time = #{cursor.time} and u.id < #{cursor.id})) `
+            ` order by u.create_time desc, u.id desc `
             limit #{page_no},#{page_size}` "
 )]
 async fn select_allocated_list(
@@ -122,6 +150,7 @@ async fn select_allocated_list(
     role_id: i64,
     user_name: &str,
     mobile: &str,
+    cursor: &Cursor,
     page_no: u64,
     page_size: u64,
 ) -> Result<Vec<User>, Error> {
@@ -160,6 +189,9 @@ async fn count_allocated_list(
                 ` and u.mobile = #{mobile} `
             if user_name != '':
                 ` and u.user_name = #{user_name} `
+            if cursor.time != '':
+                ` and (u.create_time < #{cursor.time} or (u.create_time = #{cursor.time} and u.id < #{cursor.id})) `
+            ` order by u.create_time desc, u.id desc `
             limit #{page_no},#{page_size}` "
 )]
 pub async fn select_unallocated_list(
@@ -167,6 +199,7 @@ pub async fn select_unallocated_list(
     role_id: i64,
     user_name: &str,
     mobile: &str,
+    cursor: &Cursor,
     page_no: u64,
     page_size: u64,
 ) -> rbatis::Result<Vec<User>> {
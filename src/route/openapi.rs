@@ -0,0 +1,194 @@
+use crate::handler::monitor::{health_handler, metrics_handler, server_handler};
+use crate::handler::system::{
+    sys_config_handler, sys_dept_handler, sys_dict_data_handler, sys_dict_type_handler,
+    sys_file_handler, sys_job_handler, sys_job_log_handler, sys_login_log_handler,
+    sys_menu_handler, sys_notice_handler, sys_operate_log_handler, sys_post_handler,
+    sys_role_handler, sys_user_handler, sys_user_import_handler,
+};
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+/*
+ *OpenAPI文档(新增接口时需要在paths中登记,否则test_routes_in_openapi会失败)
+ *author：刘飞华
+ *date：2026/10/19 22:58:36
+ */
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "rocket-admin", description = "rocket-admin后台管理系统接口文档"),
+    paths(
+        crate::ping,
+        health_handler::health_live,
+        health_handler::health_ready,
+        metrics_handler::query_metrics,
+        sys_user_handler::add_sys_user,
+        sys_user_handler::delete_sys_user,
+        sys_user_handler::update_sys_user,
+        sys_user_handler::update_sys_user_status,
+        sys_user_handler::update_sys_user_password,
+        sys_user_handler::reset_sys_user_password,
+        sys_user_handler::query_sys_user_detail,
+        sys_user_handler::query_sys_user_list,
+        sys_user_handler::login,
+        sys_user_handler::query_user_role,
+        sys_user_handler::update_user_role,
+        sys_user_handler::query_user_menu,
+        sys_user_handler::subscribe_user_event,
+        sys_user_handler::update_my_avatar,
+        sys_user_handler::query_user_avatar,
+        sys_user_handler::query_default_avatar,
+        sys_user_import_handler::download_import_user_template,
+        sys_user_import_handler::import_sys_user,
+        sys_role_handler::add_sys_role,
+        sys_role_handler::delete_sys_role,
+        sys_role_handler::update_sys_role,
+        sys_role_handler::update_sys_role_status,
+        sys_role_handler::query_sys_role_detail,
+        sys_role_handler::query_sys_role_list,
+        sys_role_handler::query_role_menu,
+        sys_role_handler::update_role_menu,
+        sys_role_handler::query_allocated_list,
+        sys_role_handler::query_unallocated_list,
+        sys_role_handler::cancel_auth_user,
+        sys_role_handler::batch_cancel_auth_user,
+        sys_role_handler::batch_auth_user,
+        sys_menu_handler::add_sys_menu,
+        sys_menu_handler::delete_sys_menu,
+        sys_menu_handler::update_sys_menu,
+        sys_menu_handler::update_sys_menu_status,
+        sys_menu_handler::query_sys_menu_detail,
+        sys_menu_handler::query_sys_menu_list,
+        sys_menu_handler::query_sys_menu_list_simple,
+        sys_menu_handler::query_sys_menu_resource_list,
+        sys_post_handler::add_sys_post,
+        sys_post_handler::delete_sys_post,
+        sys_post_handler::update_sys_post,
+        sys_post_handler::update_sys_post_status,
+        sys_post_handler::query_sys_post_detail,
+        sys_post_handler::query_sys_post_list,
+        sys_operate_log_handler::delete_sys_operate_log,
+        sys_operate_log_handler::query_sys_operate_log_detail,
+        sys_operate_log_handler::query_sys_operate_log_list,
+        sys_notice_handler::add_sys_notice,
+        sys_notice_handler::delete_sys_notice,
+        sys_notice_handler::update_sys_notice,
+        sys_notice_handler::update_sys_notice_status,
+        sys_notice_handler::query_sys_notice_detail,
+        sys_notice_handler::query_sys_notice_list,
+        sys_notice_handler::query_my_notice_list,
+        sys_notice_handler::query_my_unread_count,
+        sys_notice_handler::read_sys_notice,
+        sys_notice_handler::read_all_sys_notice,
+        sys_notice_handler::query_notice_read_statistics,
+        sys_file_handler::upload_sys_file,
+        sys_file_handler::download_sys_file,
+        sys_file_handler::delete_sys_file,
+        sys_file_handler::query_sys_file_list,
+        sys_login_log_handler::delete_sys_login_log,
+        sys_login_log_handler::query_sys_login_log_detail,
+        sys_login_log_handler::query_sys_login_log_list,
+        sys_login_log_handler::query_sys_login_log_statistics,
+        sys_dict_type_handler::add_sys_dict_type,
+        sys_dict_type_handler::delete_sys_dict_type,
+        sys_dict_type_handler::update_sys_dict_type,
+        sys_dict_type_handler::update_sys_dict_type_status,
+        sys_dict_type_handler::query_sys_dict_type_detail,
+        sys_dict_type_handler::query_sys_dict_type_list,
+        sys_dict_type_handler::export_sys_dict_type,
+        sys_dict_type_handler::import_sys_dict_type,
+        sys_dict_data_handler::add_sys_dict_data,
+        sys_dict_data_handler::delete_sys_dict_data,
+        sys_dict_data_handler::update_sys_dict_data,
+        sys_dict_data_handler::update_sys_dict_data_status,
+        sys_dict_data_handler::query_sys_dict_data_detail,
+        sys_dict_data_handler::query_sys_dict_data_list,
+        sys_dict_data_handler::query_dict_data_by_type,
+        sys_dict_data_handler::query_dict_data_by_types,
+        sys_dept_handler::add_sys_dept,
+        sys_dept_handler::delete_sys_dept,
+        sys_dept_handler::update_sys_dept,
+        sys_dept_handler::update_sys_dept_status,
+        sys_dept_handler::query_sys_dept_detail,
+        sys_dept_handler::query_sys_dept_list,
+        sys_config_handler::add_sys_config,
+        sys_config_handler::delete_sys_config,
+        sys_config_handler::update_sys_config,
+        sys_config_handler::query_sys_config_detail,
+        sys_config_handler::query_sys_config_list,
+        sys_config_handler::refresh_sys_config_cache,
+        sys_job_handler::add_sys_job,
+        sys_job_handler::delete_sys_job,
+        sys_job_handler::update_sys_job,
+        sys_job_handler::update_sys_job_status,
+        sys_job_handler::run_sys_job,
+        sys_job_handler::query_sys_job_detail,
+        sys_job_handler::query_sys_job_list,
+        sys_job_handler::query_job_handler_list,
+        sys_job_log_handler::delete_sys_job_log,
+        sys_job_log_handler::query_sys_job_log_detail,
+        sys_job_log_handler::query_sys_job_log_list,
+        server_handler::query_server_info
+    ),
+    modifiers(&SecurityAddon)
+)]
+pub struct ApiDoc;
+
+/*
+ *登录令牌(Authorization: Bearer token)
+ *author：刘飞华
+ *date：2026/10/19 22:58:36
+ */
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "token",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::route::openapi::ApiDoc;
+    use crate::{api_routes, root_routes};
+    use rocket::http::Method;
+    use utoipa::OpenApi;
+
+    #[test]
+    fn test_routes_in_openapi() {
+        let openapi = ApiDoc::openapi();
+        let rocket = rocket::build()
+            .mount("/", root_routes())
+            .mount("/api", api_routes());
+
+        let mut missing = Vec::new();
+        for route in rocket.routes() {
+            //rocket的路径参数<id>对应openapi的{id}
+            let path = route.uri.path().replace('<', "{").replace('>', "}");
+            let item = openapi.paths.paths.get(&path);
+            let operation = item.and_then(|x| match route.method {
+                Method::Get => x.get.as_ref(),
+                Method::Post => x.post.as_ref(),
+                Method::Put => x.put.as_ref(),
+                Method::Delete => x.delete.as_ref(),
+                _ => None,
+            });
+            if operation.is_none() {
+                missing.push(format!("{} {}", route.method, path));
+            }
+        }
+        assert!(
+            missing.is_empty(),
+            "routes missing in openapi: {:?}",
+            missing
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use crate::common::error::{AppError, AppResult};
+use crate::common::error_code::ErrorCode;
+use image::imageops::FilterType;
+use image::{ImageFormat, ImageReader, Limits};
+use rocket::http::RawStr;
+use sha2::{Digest, Sha256};
+use std::io::Cursor;
+
+//头像标准尺寸(大图,小图)
+pub const AVATAR_SIZES: [u32; 2] = [256, 64];
+
+//上传头像的最大宽高
+const AVATAR_MAX_DIMENSION: u32 = 4096;
+
+/*
+ *裁剪并缩放头像(居中裁剪为正方形后按标准尺寸缩放,统一输出png)
+ *author：刘飞华
+ *date：2026/10/19 19:12:26
+ */
+pub fn resize_avatar(data: &[u8]) -> AppResult<Vec<Vec<u8>>> {
+    let mut reader = ImageReader::new(Cursor::new(data))
+        .with_guessed_format()
+        .map_err(|_| AppError::from(ErrorCode::AvatarFormatInvalid))?;
+
+    //限制图片大小,防止解码超大图片耗尽内存
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(AVATAR_MAX_DIMENSION);
+    limits.max_image_height = Some(AVATAR_MAX_DIMENSION);
+    reader.limits(limits);
+
+    let img = reader
+        .decode()
+        .map_err(|_| AppError::from(ErrorCode::AvatarImageInvalid))?;
+
+    let (width, height) = (img.width(), img.height());
+    let side = width.min(height);
+    let square = img.crop_imm((width - side) / 2, (height - side) / 2, side, side);
+
+    let mut list: Vec<Vec<u8>> = Vec::new();
+    for size in AVATAR_SIZES {
+        let mut buf = Cursor::new(Vec::new());
+        square
+            .resize_exact(size, size, FilterType::Lanczos3)
+            .write_to(&mut buf, ImageFormat::Png)
+            .map_err(AppError::storage)?;
+        list.push(buf.into_inner());
+    }
+
+    Ok(list)
+}
+
+/*
+ *头像地址(没有上传头像时使用默认头像)
+ *author：刘飞华
+ *date：2026/10/19 19:12:26
+ */
+pub fn avatar_url(avatar: &str, name: &str) -> String {
+    if avatar.is_empty() {
+        default_avatar_url(name)
+    } else {
+        avatar.to_string()
+    }
+}
+
+/*
+ *默认头像地址
+ *author：刘飞华
+ *date：2026/10/19 19:12:26
+ */
+pub fn default_avatar_url(name: &str) -> String {
+    format!(
+        "/api/system/user/defaultAvatar?name={}",
+        RawStr::new(name).percent_encode()
+    )
+}
+
+/*
+ *生成默认头像(svg),style: identicon:对称色块,其他:首字母
+ *相同的name总是生成相同的头像
+ *author：刘飞华
+ *date：2026/10/19 19:12:26
+ */
+pub fn default_avatar_svg(name: &str, style: &str) -> String {
+    let hash = Sha256::digest(name.as_bytes());
+    let color = format!("hsl({}, 55%, 50%)", hash[0] as u32 * 360 / 256);
+
+    let body = if style == "identicon" {
+        //5x5的格子,左右对称,只需要根据hash计算左边3列
+        let mut cells = String::new();
+        for row in 0..5 {
+            for col in 0..3 {
+                if hash[1 + row * 3 + col] % 2 == 0 {
+                    continue;
+                }
+                for x in [col, 4 - col] {
+                    cells.push_str(&format!(
+                        r#"<rect x="{}" y="{}" width="16" height="16" fill="{}"/>"#,
+                        12 + x * 16,
+                        12 + row * 16,
+                        color
+                    ));
+                }
+            }
+        }
+        format!(
+            r##"<rect width="104" height="104" fill="#f0f0f0"/>{}"##,
+            cells
+        )
+    } else {
+        let initial = name
+            .chars()
+            .next()
+            .map(|x| x.to_uppercase().to_string())
+            .unwrap_or_default();
+        format!(
+            r##"<rect width="104" height="104" fill="{}"/><text x="52" y="52" dy="0.35em" text-anchor="middle" font-family="sans-serif" font-size="48" fill="#ffffff">{}</text>"##,
+            color,
+            escape_xml(&initial)
+        )
+    };
+
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="104" height="104" viewBox="0 0 104 104">{}</svg>"#,
+        body
+    )
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::avatar_util::{default_avatar_svg, resize_avatar, AVATAR_SIZES};
+    use image::{ImageFormat, RgbImage};
+    use std::io::Cursor;
+
+    #[test]
+    fn test_resize_avatar() {
+        let mut buf = Cursor::new(Vec::new());
+        RgbImage::new(300, 200)
+            .write_to(&mut buf, ImageFormat::Jpeg)
+            .unwrap();
+
+        let list = resize_avatar(buf.get_ref()).unwrap();
+        for (data, size) in list.iter().zip(AVATAR_SIZES) {
+            let img = image::load_from_memory(data).unwrap();
+            assert_eq!((img.width(), img.height()), (size, size));
+        }
+
+        assert!(resize_avatar(b"not an image").is_err());
+    }
+
+    #[test]
+    fn test_default_avatar_svg() {
+        assert_eq!(
+            default_avatar_svg("admin", "identicon"),
+            default_avatar_svg("admin", "identicon")
+        );
+        assert!(default_avatar_svg("admin", "initials").contains(">A</text>"));
+        assert!(default_avatar_svg("<a", "initials").contains(">&lt;</text>"));
+    }
+}
@@ -0,0 +1,97 @@
+use crate::common::error::AppResult;
+use crate::common::error_code::ErrorCode;
+use crate::utils::time_util::string_to_time;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::Serialize;
+
+/*
+ *分页游标(按时间倒序,时间相同时按id倒序),返回给前端时编码成不透明的字符串
+ *author：刘飞华
+ *date：2026/10/20 01:36:52
+ */
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct Cursor {
+    pub time: String, //上一页最后一条记录的时间(YYYY-MM-DD hh:mm:ss),为空时表示第一页
+    pub id: i64,      //上一页最后一条记录的id
+}
+
+impl Cursor {
+    /*
+     *编码游标
+     *author：刘飞华
+     *date：2026/10/20 01:36:52
+     */
+    pub fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(format!("{}|{}", self.time, self.id))
+    }
+
+    /*
+     *解析游标(空字符串表示第一页)
+     *author：刘飞华
+     *date：2026/10/20 01:36:52
+     */
+    pub fn decode(cursor: &str) -> AppResult<Cursor> {
+        if cursor.is_empty() {
+            return Ok(Cursor::default());
+        }
+
+        let cursor = URL_SAFE_NO_PAD
+            .decode(cursor)
+            .ok()
+            .and_then(|x| String::from_utf8(x).ok())
+            .and_then(|x| {
+                let (time, id) = x.rsplit_once('|')?;
+                string_to_time(time)?;
+                Some(Cursor {
+                    time: time.to_string(),
+                    id: id.parse().ok()?,
+                })
+            });
+        cursor.ok_or_else(|| ErrorCode::CursorInvalid.into())
+    }
+}
+
+/*
+ *生成下一页的游标(本页记录数小于每页条数时没有下一页)
+ *author：刘飞华
+ *date：2026/10/20 01:36:52
+ */
+pub fn next_cursor<T>(list: &[T], page_size: u64, cursor: impl Fn(&T) -> Cursor) -> Option<String> {
+    if (list.len() as u64) < page_size {
+        return None;
+    }
+    list.last().map(|x| cursor(x).encode())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor() {
+        let cursor = Cursor {
+            time: "2026-10-20 01:36:52".to_string(),
+            id: 42,
+        };
+        assert_eq!(Cursor::decode(&cursor.encode()).unwrap(), cursor);
+        assert_eq!(Cursor::decode("").unwrap(), Cursor::default());
+        assert!(Cursor::decode("bad").is_err());
+        assert!(Cursor::decode(&URL_SAFE_NO_PAD.encode("x' or 1=1|1")).is_err());
+
+        assert_eq!(next_cursor(&[1, 2], 3, |_| Cursor::default()), None);
+        assert_eq!(
+            next_cursor(&[1, 2], 2, |x| Cursor {
+                time: cursor.time.clone(),
+                id: *x,
+            }),
+            Some(
+                Cursor {
+                    id: 2,
+                    ..cursor.clone()
+                }
+                .encode()
+            )
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use calamine::{Reader, Xlsx};
+use rust_xlsxwriter::{Format, Workbook};
+use std::io::Cursor;
+
+//表格文件格式：xlsx
+pub const TABLE_FORMAT_XLSX: &str = "xlsx";
+//表格文件格式：csv
+pub const TABLE_FORMAT_CSV: &str = "csv";
+
+/*
+ *表格文件的content-type
+ *author：刘飞华
+ *date：2026/10/20 02:46:15
+ */
+pub fn table_content_type(format: &str) -> &'static str {
+    match format {
+        TABLE_FORMAT_XLSX => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        TABLE_FORMAT_CSV => "text/csv; charset=utf-8",
+        _ => "application/octet-stream",
+    }
+}
+
+/*
+ *读取表格文件的第一个工作表(xlsx/csv),返回所有行(单元格统一转换为去掉首尾空白的字符串)
+ *author：刘飞华
+ *date：2026/10/20 02:46:15
+ */
+pub fn read_table(format: &str, data: &[u8]) -> anyhow::Result<Vec<Vec<String>>> {
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    match format {
+        TABLE_FORMAT_XLSX => {
+            let mut workbook = Xlsx::new(Cursor::new(data))?;
+            let range = workbook
+                .worksheet_range_at(0)
+                .ok_or_else(|| anyhow::anyhow!("no worksheet"))??;
+            for row in range.rows() {
+                rows.push(
+                    row.iter()
+                        .map(|x| x.to_string().trim().to_string())
+                        .collect(),
+                );
+            }
+        }
+        TABLE_FORMAT_CSV => {
+            //excel另存为的csv带有utf-8 bom
+            let data = data.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(data);
+            let mut reader = csv::ReaderBuilder::new()
+                .has_headers(false)
+                .flexible(true)
+                .from_reader(data);
+            for record in reader.records() {
+                rows.push(record?.iter().map(|x| x.trim().to_string()).collect());
+            }
+        }
+        _ => anyhow::bail!("unsupported table format: {}", format),
+    }
+
+    //去掉末尾的空行
+    while rows.last().is_some_and(|x| x.iter().all(|c| c.is_empty())) {
+        rows.pop();
+    }
+    Ok(rows)
+}
+
+/*
+ *生成表格文件(xlsx的单元格统一为文本格式,避免手机号码等被转换成数字;csv带utf-8 bom,方便excel直接打开)
+ *author：刘飞华
+ *date：2026/10/20 02:46:15
+ */
+pub fn write_table(
+    format: &str,
+    headers: &[&str],
+    rows: &[Vec<String>],
+) -> anyhow::Result<Vec<u8>> {
+    match format {
+        TABLE_FORMAT_XLSX => {
+            let mut workbook = Workbook::new();
+            let sheet = workbook.add_worksheet();
+            let text = Format::new().set_num_format("@");
+            let header = Format::new().set_bold().set_num_format("@");
+            for (col, title) in headers.iter().enumerate() {
+                let col = col as u16;
+                sheet.set_column_width(col, 18)?;
+                sheet.set_column_format(col, &text)?;
+                sheet.write_string_with_format(0, col, *title, &header)?;
+            }
+            sheet.set_freeze_panes(1, 0)?;
+            for (i, row) in rows.iter().enumerate() {
+                for (col, value) in row.iter().enumerate() {
+                    sheet.write_string_with_format(i as u32 + 1, col as u16, value, &text)?;
+                }
+            }
+            Ok(workbook.save_to_buffer()?)
+        }
+        TABLE_FORMAT_CSV => {
+            let mut writer = csv::Writer::from_writer(b"\xEF\xBB\xBF".to_vec());
+            writer.write_record(headers)?;
+            for row in rows {
+                writer.write_record(row)?;
+            }
+            Ok(writer.into_inner()?)
+        }
+        _ => anyhow::bail!("unsupported table format: {}", format),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_write_table() {
+        let headers = ["用户账号", "手机号码"];
+        let rows = vec![
+            vec!["admin".to_string(), "13800000000".to_string()],
+            vec!["张三, 李四".to_string(), "".to_string()],
+        ];
+
+        for format in [TABLE_FORMAT_XLSX, TABLE_FORMAT_CSV] {
+            let data = write_table(format, &headers, &rows).unwrap();
+            let table = read_table(format, &data).unwrap();
+            assert_eq!(table.len(), 3);
+            assert_eq!(table[0], headers);
+            assert_eq!(table[1], rows[0]);
+            assert_eq!(table[2][0], rows[1][0]);
+        }
+
+        let table = read_table(TABLE_FORMAT_CSV, " a ,b\n\n,\n".as_bytes()).unwrap();
+        assert_eq!(table, vec![vec!["a", "b"]]);
+        assert!(read_table("json", b"{}").is_err());
+    }
+}
@@ -0,0 +1,57 @@
+use ammonia::Builder;
+use std::collections::HashSet;
+
+lazy_static! {
+    //富文本白名单(标签和属性使用ammonia默认白名单,去掉script、style、on*事件等危险内容)
+    static ref HTML_CLEANER: Builder<'static> = {
+        let mut builder = Builder::default();
+        builder
+            .url_schemes(HashSet::from(["http", "https", "mailto"]))
+            .link_rel(Some("noopener noreferrer"));
+        builder
+    };
+}
+
+/*
+ *清洗富文本内容,只保留白名单中的标签和属性
+ *author：刘飞华
+ *date：2026/10/19 17:48:05
+ */
+pub fn sanitize_html(content: &str) -> String {
+    HTML_CLEANER.clean(content).to_string()
+}
+
+/*
+ *判断链接是否安全(只允许http、https和站内相对路径)
+ *author：刘飞华
+ *date：2026/10/19 17:48:05
+ */
+pub fn is_safe_url(url: &str) -> bool {
+    let url = url.trim().to_lowercase();
+    url.starts_with("http://")
+        || url.starts_with("https://")
+        || (url.starts_with('/') && !url.starts_with("//"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::html_util::{is_safe_url, sanitize_html};
+
+    #[test]
+    fn test_sanitize_html() {
+        assert_eq!(
+            sanitize_html("<p onclick=\"alert(1)\">hello<script>alert(1)</script></p>"),
+            "<p>hello</p>"
+        );
+        assert_eq!(
+            sanitize_html("<a href=\"javascript:alert(1)\">link</a>"),
+            "<a rel=\"noopener noreferrer\">link</a>"
+        );
+        assert_eq!(sanitize_html("<b>通知</b>"), "<b>通知</b>");
+
+        assert!(is_safe_url("https://example.com/a.pdf"));
+        assert!(is_safe_url("/api/file/1"));
+        assert!(!is_safe_url("javascript:alert(1)"));
+        assert!(!is_safe_url("//evil.com/a.pdf"));
+    }
+}
@@ -0,0 +1,93 @@
+use maxminddb::{geoip2, Reader};
+use std::collections::BTreeMap;
+use std::env;
+use std::net::IpAddr;
+
+lazy_static! {
+    //IP地址库(IP_LOCATION_DB,GeoLite2-City.mmdb文件路径,未配置时只识别内网IP)
+    static ref IP_LOCATION_DB: Option<Reader<Vec<u8>>> = env::var("IP_LOCATION_DB")
+        .ok()
+        .filter(|x| !x.is_empty())
+        .and_then(|path| match Reader::open_readfile(&path) {
+            Ok(x) => Some(x),
+            Err(e) => {
+                log::error!("open ip location db {} error: {}", path, e);
+                None
+            }
+        });
+}
+
+/*
+ *是否内网IP(回环、私有网段、链路本地地址)
+ *author：刘飞华
+ *date：2026/10/20 09:12:40
+ */
+pub fn is_internal_ip(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(x) => x.is_loopback() || x.is_private() || x.is_link_local(),
+        IpAddr::V6(x) => {
+            //fc00::/7 唯一本地地址, fe80::/10 链路本地地址
+            x.is_loopback()
+                || (x.segments()[0] & 0xfe00) == 0xfc00
+                || (x.segments()[0] & 0xffc0) == 0xfe80
+        }
+    }
+}
+
+/*
+ *查询IP地址的归属地(国家 省份 城市)
+ *author：刘飞华
+ *date：2026/10/20 09:12:40
+ */
+pub fn ip_location(ip: Option<IpAddr>) -> String {
+    let ip = match ip {
+        None => return "未知".to_string(),
+        Some(x) if is_internal_ip(&x) => return "内网IP".to_string(),
+        Some(x) => x,
+    };
+
+    let city = IP_LOCATION_DB
+        .as_ref()
+        .and_then(|db| db.lookup::<geoip2::City>(ip).ok());
+    let city = match city {
+        None => return "未知".to_string(),
+        Some(x) => x,
+    };
+
+    let name = |names: Option<BTreeMap<&str, &str>>| {
+        names.and_then(|x| {
+            x.get("zh-CN")
+                .or_else(|| x.get("en"))
+                .map(|x| x.to_string())
+        })
+    };
+    let location = [
+        city.country.and_then(|x| name(x.names)),
+        city.subdivisions
+            .and_then(|x| x.into_iter().next())
+            .and_then(|x| name(x.names)),
+        city.city.and_then(|x| name(x.names)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<String>>();
+
+    match location.is_empty() {
+        true => "未知".to_string(),
+        false => location.join(" "),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ip_location() {
+        assert_eq!(ip_location("127.0.0.1".parse().ok()), "内网IP");
+        assert_eq!(ip_location("192.168.1.10".parse().ok()), "内网IP");
+        assert_eq!(ip_location("fd00::1".parse().ok()), "内网IP");
+        assert_eq!(ip_location(None), "未知");
+        assert!(!is_internal_ip(&"8.8.8.8".parse().unwrap()));
+    }
+}
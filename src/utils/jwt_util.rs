@@ -1,5 +1,6 @@
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use crate::common::config_cache::{CONFIG_TOKEN_EXPIRE_SECONDS, SYS_CONFIG};
 use crate::common::error::AppError;
 use crate::common::error::AppError::JwtTokenError;
 use jsonwebtoken::{
@@ -29,8 +30,11 @@ pub struct JwtToken {
 impl JwtToken {
     pub fn new(id: i64, username: &str, permissions: Vec<String>) -> JwtToken {
         let now = SystemTime::now();
-        //过期时间
-        let m30 = Duration::from_secs(1800000);
+        //过期时间(参数配置sys.token.expireSeconds)
+        let expire = SYS_CONFIG
+            .get_i64(CONFIG_TOKEN_EXPIRE_SECONDS, 1800000)
+            .max(1);
+        let m30 = Duration::from_secs(expire as u64);
         let now = now.duration_since(UNIX_EPOCH).expect("获取系统时间失败");
 
         JwtToken {
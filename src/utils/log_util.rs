@@ -0,0 +1,123 @@
+use crate::common::request_context;
+use log::Record;
+use log4rs::config::{Deserialize, Deserializers};
+use log4rs::encode::json::JsonEncoder;
+use log4rs::encode::pattern::PatternEncoder;
+use log4rs::encode::{Encode, Write};
+
+//默认日志格式(请求ID通过{X(request_id)(-)}输出)
+const DEFAULT_PATTERN: &str =
+    "{d(%Y-%m-%d %H:%M:%S)} [{X(request_id)(-)}] [{f}:{L}] {h({l})} [{M}] - {m}{n}";
+
+/*
+ *带请求上下文的日志编码器(输出日志前把当前请求的上下文写入MDC,pattern和json编码器都可以读取)
+ *author：刘飞华
+ *date：2026/10/19 22:05:47
+ */
+#[derive(Debug)]
+pub struct RequestContextEncoder(Box<dyn Encode>);
+
+impl Encode for RequestContextEncoder {
+    fn encode(&self, w: &mut dyn Write, record: &Record) -> anyhow::Result<()> {
+        let context = match request_context::current() {
+            None => return self.0.encode(w, record),
+            Some(x) => x,
+        };
+
+        log_mdc::insert("request_id", context.request_id);
+        log_mdc::insert("method", context.method);
+        log_mdc::insert("uri", context.uri);
+        if let Some(x) = context.route {
+            log_mdc::insert("route", x);
+        }
+        if let Some(x) = context.user_id {
+            log_mdc::insert("user_id", x.to_string());
+        }
+        if let Some(x) = context.status {
+            log_mdc::insert("status", x.to_string());
+        }
+        if let Some(x) = context.latency_ms {
+            log_mdc::insert("latency_ms", x.to_string());
+        }
+        let result = self.0.encode(w, record);
+        log_mdc::clear();
+        result
+    }
+}
+
+/*
+ *request_pattern编码器配置
+ *author：刘飞华
+ *date：2026/10/19 22:05:47
+ */
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RequestPatternEncoderConfig {
+    pattern: Option<String>,
+}
+
+/*
+ *request_pattern编码器(文本格式,kind: request_pattern)
+ *author：刘飞华
+ *date：2026/10/19 22:05:47
+ */
+pub struct RequestPatternEncoderDeserializer;
+
+impl Deserialize for RequestPatternEncoderDeserializer {
+    type Trait = dyn Encode;
+    type Config = RequestPatternEncoderConfig;
+
+    fn deserialize(
+        &self,
+        config: RequestPatternEncoderConfig,
+        _: &Deserializers,
+    ) -> anyhow::Result<Box<dyn Encode>> {
+        let pattern = config.pattern.as_deref().unwrap_or(DEFAULT_PATTERN);
+        Ok(Box::new(RequestContextEncoder(Box::new(
+            PatternEncoder::new(pattern),
+        ))))
+    }
+}
+
+/*
+ *request_json编码器配置
+ *author：刘飞华
+ *date：2026/10/19 22:05:47
+ */
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RequestJsonEncoderConfig {}
+
+/*
+ *request_json编码器(每行一个JSON对象,请求上下文在mdc字段中,kind: request_json)
+ *author：刘飞华
+ *date：2026/10/19 22:05:47
+ */
+pub struct RequestJsonEncoderDeserializer;
+
+impl Deserialize for RequestJsonEncoderDeserializer {
+    type Trait = dyn Encode;
+    type Config = RequestJsonEncoderConfig;
+
+    fn deserialize(
+        &self,
+        _: RequestJsonEncoderConfig,
+        _: &Deserializers,
+    ) -> anyhow::Result<Box<dyn Encode>> {
+        Ok(Box::new(RequestContextEncoder(
+            Box::new(JsonEncoder::new()),
+        )))
+    }
+}
+
+/*
+ *日志配置反序列化器(在log4rs内置组件基础上增加request_pattern和request_json编码器)
+ *author：刘飞华
+ *date：2026/10/19 22:05:47
+ */
+pub fn log_deserializers() -> Deserializers {
+    let mut deserializers = Deserializers::default();
+    deserializers.insert("request_pattern", RequestPatternEncoderDeserializer);
+    deserializers.insert("request_json", RequestJsonEncoderDeserializer);
+    deserializers
+}
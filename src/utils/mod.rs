@@ -1,4 +1,11 @@
+pub mod avatar_util;
+pub mod cursor_util;
+pub mod excel_util;
+pub mod html_util;
+pub mod ip_util;
 pub mod jwt_util;
+pub mod log_util;
+pub mod sort_util;
 pub mod time_util;
 pub mod user_agent_util;
-
+pub mod validate_util;
@@ -0,0 +1,63 @@
+/*
+ *根据白名单生成排序语句(只允许白名单中的字段参与排序,防止sql注入)
+ *sort_field: 前端传入的排序字段(驼峰)
+ *sort_order: 前端传入的排序方式(asc/desc, ascend/descend)
+ *allowed: 允许排序的字段,(前端字段, 数据库字段)
+ *default: 默认排序语句
+ *author：刘飞华
+ *date：2026/10/19 10:12:36
+ */
+pub fn order_by(
+    sort_field: Option<&str>,
+    sort_order: Option<&str>,
+    allowed: &[(&str, &str)],
+    default: &str,
+) -> String {
+    let column = sort_field.and_then(|field| {
+        allowed
+            .iter()
+            .find(|(name, _)| *name == field)
+            .map(|(_, column)| *column)
+    });
+
+    match column {
+        None => default.to_string(),
+        Some(column) => {
+            let order = match sort_order.unwrap_or_default() {
+                "asc" | "ascend" => "asc",
+                _ => "desc",
+            };
+            format!("{} {}", column, order)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::sort_util::order_by;
+
+    #[test]
+    fn test_order_by() {
+        let allowed = [("loginName", "login_name"), ("loginTime", "login_time")];
+        let default = "login_time desc";
+
+        assert_eq!(
+            order_by(Some("loginName"), Some("ascend"), &allowed, default),
+            "login_name asc"
+        );
+        assert_eq!(
+            order_by(Some("loginTime"), None, &allowed, default),
+            "login_time desc"
+        );
+        assert_eq!(
+            order_by(
+                Some("id;drop table sys_user"),
+                Some("asc"),
+                &allowed,
+                default
+            ),
+            default
+        );
+        assert_eq!(order_by(None, Some("asc"), &allowed, default), default);
+    }
+}
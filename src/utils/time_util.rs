@@ -1,3 +1,5 @@
+use crate::common::error::AppResult;
+use crate::common::error_code::ErrorCode;
 use rbatis::rbdc::DateTime;
 
 /*
@@ -11,3 +13,52 @@ pub fn time_to_string(t: Option<DateTime>) -> String {
         Some(x) => x.format("YYYY-MM-DD hh:mm:ss"),
     }
 }
+
+/*
+ *字符串转时间(格式: YYYY-MM-DD hh:mm:ss)
+ *author：刘飞华
+ *date：2026/10/19 17:05:22
+ */
+pub fn string_to_time(s: &str) -> Option<DateTime> {
+    DateTime::parse("YYYY-MM-DD hh:mm:ss", s).ok()
+}
+
+/*
+ *校验时间范围(开始时间和结束时间都不为空时,按时间比较,开始时间不能大于结束时间;支持YYYY-MM-DD和YYYY-MM-DD hh:mm:ss)
+ *author：刘飞华
+ *date：2026/10/20 02:18:40
+ */
+pub fn check_time_range(begin_time: &str, end_time: &str) -> AppResult<()> {
+    let parse = |s: &str| -> AppResult<Option<DateTime>> {
+        if s.is_empty() {
+            return Ok(None);
+        }
+        string_to_time(s)
+            .or_else(|| DateTime::parse("YYYY-MM-DD", s).ok())
+            .map(Some)
+            .ok_or_else(|| ErrorCode::TimeFormatInvalid.with("time", s).into())
+    };
+    if let (Some(begin), Some(end)) = (parse(begin_time)?, parse(end_time)?) {
+        if begin > end {
+            return Err(ErrorCode::TimeRangeInvalid.into());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_time_range() {
+        assert!(check_time_range("", "").is_ok());
+        assert!(check_time_range("2026-10-01", "").is_ok());
+        assert!(check_time_range("2026-10-01", "2026-10-01 08:00:00").is_ok());
+        //按字符串比较时"2026-10-01 00:00:00" > "2026-10-01"
+        assert!(check_time_range("2026-10-01 00:00:00", "2026-10-01").is_ok());
+        assert!(check_time_range("2026-10-02", "2026-10-01 23:59:59").is_err());
+        assert!(check_time_range("2026-9-1", "2026-10-01").is_err());
+        assert!(check_time_range("abc", "").is_err());
+    }
+}
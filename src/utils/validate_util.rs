@@ -0,0 +1,42 @@
+use regex::Regex;
+use validator::{ValidateEmail, ValidationError};
+
+lazy_static! {
+    static ref MOBILE_REGEX: Regex = Regex::new(r"^1[3-9]\d{9}$").unwrap();
+}
+
+/*
+ *校验手机号码(11位,以1开头)
+ *author：刘飞华
+ *date：2026/10/19 23:18:42
+ */
+pub fn validate_mobile(mobile: &str) -> Result<(), ValidationError> {
+    if MOBILE_REGEX.is_match(mobile) {
+        return Ok(());
+    }
+    Err(ValidationError::new("mobile"))
+}
+
+/*
+ *校验邮箱(允许为空,不为空时必须是合法的邮箱)
+ *author：刘飞华
+ *date：2026/10/19 23:18:42
+ */
+pub fn validate_email(email: &str) -> Result<(), ValidationError> {
+    if email.is_empty() || email.validate_email() {
+        return Ok(());
+    }
+    Err(ValidationError::new("email"))
+}
+
+/*
+ *校验是否默认(只能是Y或N)
+ *author：刘飞华
+ *date：2026/10/19 23:18:42
+ */
+pub fn validate_yes_no(value: &str) -> Result<(), ValidationError> {
+    match value {
+        "Y" | "N" => Ok(()),
+        _ => Err(ValidationError::new("yes_no")),
+    }
+}
@@ -1,3 +1,4 @@
+pub mod monitor;
 pub mod other;
 
 pub mod system;
@@ -0,0 +1,27 @@
+// author：刘飞华
+// createTime：2026/10/19 21:16:32
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+/*
+健康检查响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthResp {
+    pub status: String,                             //整体状态(UP/DOWN)
+    pub checks: BTreeMap<String, DependencyHealth>, //各依赖的检查结果
+}
+
+/*
+依赖检查结果
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DependencyHealth {
+    pub status: String,        //状态(UP/DOWN)
+    pub latency_ms: u64,       //耗时(毫秒)
+    pub error: Option<String>, //错误信息
+}
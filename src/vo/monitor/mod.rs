@@ -0,0 +1,2 @@
+pub mod health_vo;
+pub mod server_vo;
@@ -0,0 +1,127 @@
+// author：刘飞华
+// createTime：2026/10/19 20:48:10
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/*
+服务监控响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerInfoResp {
+    pub cpu: CpuInfo,         //CPU
+    pub memory: MemoryInfo,   //内存
+    pub load: LoadInfo,       //系统负载
+    pub disks: Vec<DiskInfo>, //磁盘
+    pub host: HostInfo,       //主机
+    pub process: ProcessInfo, //当前进程
+    pub runtime: RuntimeInfo, //运行环境
+    pub db_pool: DbPoolInfo,  //数据库连接池
+}
+
+/*
+CPU信息
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuInfo {
+    pub brand: String,       //CPU型号
+    pub cpu_num: usize,      //逻辑核数
+    pub physical_num: usize, //物理核数
+    pub usage: f32,          //使用率(%)
+}
+
+/*
+内存信息(单位：字节)
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MemoryInfo {
+    pub total: u64,      //总内存
+    pub used: u64,       //已用内存
+    pub free: u64,       //可用内存
+    pub usage: f64,      //使用率(%)
+    pub swap_total: u64, //交换区总量
+    pub swap_used: u64,  //交换区已用
+}
+
+/*
+系统负载
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LoadInfo {
+    pub one: f64,     //1分钟平均负载
+    pub five: f64,    //5分钟平均负载
+    pub fifteen: f64, //15分钟平均负载
+}
+
+/*
+磁盘信息(单位：字节)
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DiskInfo {
+    pub name: String,        //磁盘名称
+    pub mount_point: String, //挂载点
+    pub file_system: String, //文件系统
+    pub total: u64,          //总大小
+    pub used: u64,           //已用大小
+    pub free: u64,           //可用大小
+    pub usage: f64,          //使用率(%)
+}
+
+/*
+主机信息
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HostInfo {
+    pub host_name: String,      //主机名
+    pub os_name: String,        //操作系统
+    pub os_version: String,     //系统版本
+    pub kernel_version: String, //内核版本
+    pub arch: String,           //系统架构
+    pub uptime: u64,            //系统运行时长(秒)
+}
+
+/*
+当前进程信息
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessInfo {
+    pub pid: u32,            //进程ID
+    pub start_time: String,  //启动时间
+    pub run_time: u64,       //运行时长(秒)
+    pub memory: u64,         //占用内存(字节)
+    pub virtual_memory: u64, //虚拟内存(字节)
+    pub cpu_usage: f32,      //CPU使用率(%)
+}
+
+/*
+运行环境
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeInfo {
+    pub app_version: String,    //应用版本
+    pub rust_version: String,   //rustc版本
+    pub rocket_version: String, //rocket版本
+}
+
+/*
+数据库连接池(rbdc_pool_fast)
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DbPoolInfo {
+    pub max_open: u64,    //最大连接数
+    pub connections: u64, //当前连接数
+    pub in_use: u64,      //使用中的连接数
+    pub idle: u64,        //空闲连接数
+    pub waits: u64,       //等待获取连接的数量
+    pub connecting: u64,  //正在建立的连接数
+    pub checking: u64,    //正在检查的连接数
+}
@@ -0,0 +1,100 @@
+// author：刘飞华
+// createTime：2026/10/19 19:20:14
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/*
+添加参数配置表请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AddConfigReq {
+    pub config_name: String,    //参数名称
+    pub config_key: String,     //参数键名
+    pub config_value: String,   //参数键值
+    pub value_type: String,     //值类型(string:字符串,int:整数,bool:布尔,json:JSON)
+    pub remark: Option<String>, //备注
+}
+
+/*
+删除参数配置表请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DeleteConfigReq {
+    pub ids: Vec<i64>,
+}
+
+/*
+更新参数配置表请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateConfigReq {
+    pub id: i64,                //参数主键
+    pub config_name: String,    //参数名称
+    pub config_key: String,     //参数键名
+    pub config_value: String,   //参数键值
+    pub value_type: String,     //值类型(string:字符串,int:整数,bool:布尔,json:JSON)
+    pub remark: Option<String>, //备注
+}
+
+/*
+查询参数配置表详情请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct QueryConfigDetailReq {
+    pub id: i64,
+}
+
+/*
+查询参数配置表详情响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryConfigDetailResp {
+    pub id: i64,              //参数主键
+    pub config_name: String,  //参数名称
+    pub config_key: String,   //参数键名
+    pub config_value: String, //参数键值
+    pub value_type: String,   //值类型(string:字符串,int:整数,bool:布尔,json:JSON)
+    pub is_builtin: String,   //是否内置（Y是 N否）
+    pub remark: String,       //备注
+    pub create_time: String,  //创建时间
+    pub update_time: String,  //修改时间
+}
+
+/*
+查询参数配置表列表请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryConfigListReq {
+    pub page_no: u64,
+    pub page_size: u64,
+    pub config_name: Option<String>, //参数名称
+    pub config_key: Option<String>,  //参数键名
+    pub value_type: Option<String>,  //值类型
+    pub keyword: Option<String>,     //关键字(模糊搜索)
+    pub begin_time: Option<String>,  //创建时间(开始)
+    pub end_time: Option<String>,    //创建时间(结束)
+    pub sort_field: Option<String>,  //排序字段
+    pub sort_order: Option<String>,  //排序方式(asc:升序,desc:降序)
+}
+
+/*
+查询参数配置表列表响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigListDataResp {
+    pub id: i64,              //参数主键
+    pub config_name: String,  //参数名称
+    pub config_key: String,   //参数键名
+    pub config_value: String, //参数键值
+    pub value_type: String,   //值类型(string:字符串,int:整数,bool:布尔,json:JSON)
+    pub is_builtin: String,   //是否内置（Y是 N否）
+    pub remark: String,       //备注
+    pub create_time: String,  //创建时间
+    pub update_time: String,  //修改时间
+}
@@ -1,27 +1,36 @@
 // author：刘飞华
 // createTime：2024/12/25 10:01:11
 
+use crate::utils::validate_util::validate_email;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
 
 /*
 添加部门表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct AddDeptReq {
-    pub parent_id: i64,    //父部门id
+    pub parent_id: i64, //父部门id
+    #[validate(length(min = 1, max = 30))]
     pub dept_name: String, //部门名称
-    pub sort: i32,         //显示顺序
-    pub leader: String,    //负责人
-    pub phone: String,     //联系电话
-    pub email: String,     //邮箱
-    pub status: i8,        //部状态（0：停用，1:正常）
+    #[validate(range(min = 0))]
+    pub sort: i32, //显示顺序
+    #[validate(length(max = 20))]
+    pub leader: String, //负责人
+    #[validate(length(max = 11))]
+    pub phone: String, //联系电话
+    #[validate(custom(function = "validate_email"), length(max = 50))]
+    pub email: String, //邮箱
+    #[validate(range(min = 0, max = 1))]
+    pub status: i8, //部状态（0：停用，1:正常）
 }
 
 /*
 删除部门表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct DeleteDeptReq {
     pub id: i64,
 }
@@ -29,32 +38,40 @@ pub struct DeleteDeptReq {
 /*
 更新部门表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateDeptReq {
-    pub id: i64,           //部门id
-    pub parent_id: i64,    //父部门id
+    pub id: i64,        //部门id
+    pub parent_id: i64, //父部门id
+    #[validate(length(min = 1, max = 30))]
     pub dept_name: String, //部门名称
-    pub sort: i32,         //显示顺序
-    pub leader: String,    //负责人
-    pub phone: String,     //联系电话
-    pub email: String,     //邮箱
-    pub status: i8,        //部状态（0：停用，1:正常）
+    #[validate(range(min = 0))]
+    pub sort: i32, //显示顺序
+    #[validate(length(max = 20))]
+    pub leader: String, //负责人
+    #[validate(length(max = 11))]
+    pub phone: String, //联系电话
+    #[validate(custom(function = "validate_email"), length(max = 50))]
+    pub email: String, //邮箱
+    #[validate(range(min = 0, max = 1))]
+    pub status: i8, //部状态（0：停用，1:正常）
 }
 
 /*
 更新部门表状态请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 pub struct UpdateDeptStatusReq {
+    #[validate(length(min = 1))]
     pub ids: Vec<i64>,
+    #[validate(range(min = 0, max = 1))]
     pub status: i8,
 }
 
 /*
 查询部门表详情请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct QueryDeptDetailReq {
     pub id: i64,
 }
@@ -62,7 +79,7 @@ pub struct QueryDeptDetailReq {
 /*
 查询部门表详情响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryDeptDetailResp {
     pub id: i64,             //部门id
@@ -101,7 +118,7 @@ impl QueryDeptDetailResp {
 /*
 查询部门表列表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryDeptListReq {
     pub dept_name: Option<String>, //部门名称
@@ -114,7 +131,7 @@ pub struct QueryDeptListReq {
 /*
 查询部门表列表响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DeptListDataResp {
     pub id: i64,             //部门id
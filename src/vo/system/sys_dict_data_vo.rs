@@ -1,29 +1,41 @@
 // author：刘飞华
 // createTime：2024/12/25 10:01:11
 
+use crate::utils::validate_util::validate_yes_no;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
 
 /*
 添加字典数据表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct AddDictDataReq {
-    pub dict_sort: i32,         //字典排序
-    pub dict_label: String,     //字典标签
-    pub dict_value: String,     //字典键值
-    pub dict_type: String,      //字典类型
-    pub css_class: String,      //样式属性（其他样式扩展）
-    pub list_class: String,     //表格回显样式
-    pub is_default: String,     //是否默认（Y是 N否）
-    pub status: i8,             //状态（0：停用，1:正常）
+    #[validate(range(min = 0))]
+    pub dict_sort: i32, //字典排序
+    #[validate(length(min = 1, max = 100))]
+    pub dict_label: String, //字典标签
+    #[validate(length(min = 1, max = 100))]
+    pub dict_value: String, //字典键值
+    #[validate(length(min = 1, max = 100))]
+    pub dict_type: String, //字典类型
+    #[validate(length(max = 100))]
+    pub css_class: String, //样式属性（其他样式扩展）
+    #[validate(length(max = 100))]
+    pub list_class: String, //表格回显样式
+    #[validate(custom(function = "validate_yes_no"))]
+    pub is_default: String, //是否默认（Y是 N否）
+    #[validate(range(min = 0, max = 1))]
+    pub status: i8, //状态（0：停用，1:正常）
+    #[validate(length(max = 500))]
     pub remark: Option<String>, //备注
 }
 
 /*
 删除字典数据表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct DeleteDictDataReq {
     pub ids: Vec<i64>,
 }
@@ -31,34 +43,45 @@ pub struct DeleteDictDataReq {
 /*
 更新字典数据表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateDictDataReq {
-    pub id: i64,                //字典编码
-    pub dict_sort: i32,         //字典排序
-    pub dict_label: String,     //字典标签
-    pub dict_value: String,     //字典键值
-    pub dict_type: String,      //字典类型
-    pub css_class: String,      //样式属性（其他样式扩展）
-    pub list_class: String,     //表格回显样式
-    pub is_default: String,     //是否默认（Y是 N否）
-    pub status: i8,             //状态（0：停用，1:正常）
+    pub id: i64, //字典编码
+    #[validate(range(min = 0))]
+    pub dict_sort: i32, //字典排序
+    #[validate(length(min = 1, max = 100))]
+    pub dict_label: String, //字典标签
+    #[validate(length(min = 1, max = 100))]
+    pub dict_value: String, //字典键值
+    #[validate(length(min = 1, max = 100))]
+    pub dict_type: String, //字典类型
+    #[validate(length(max = 100))]
+    pub css_class: String, //样式属性（其他样式扩展）
+    #[validate(length(max = 100))]
+    pub list_class: String, //表格回显样式
+    #[validate(custom(function = "validate_yes_no"))]
+    pub is_default: String, //是否默认（Y是 N否）
+    #[validate(range(min = 0, max = 1))]
+    pub status: i8, //状态（0：停用，1:正常）
+    #[validate(length(max = 500))]
     pub remark: Option<String>, //备注
 }
 
 /*
 更新字典数据表状态请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 pub struct UpdateDictDataStatusReq {
+    #[validate(length(min = 1))]
     pub ids: Vec<i64>,
+    #[validate(range(min = 0, max = 1))]
     pub status: i8,
 }
 
 /*
 查询字典数据表详情请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct QueryDictDataDetailReq {
     pub id: i64,
 }
@@ -66,7 +89,7 @@ pub struct QueryDictDataDetailReq {
 /*
 查询字典数据表详情响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryDictDataDetailResp {
     pub id: i64,             //字典编码
@@ -105,7 +128,7 @@ impl QueryDictDataDetailResp {
 /*
 查询字典数据表列表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryDictDataListReq {
     pub page_no: u64,
@@ -114,12 +137,17 @@ pub struct QueryDictDataListReq {
     pub dict_value: Option<String>, //字典键值
     pub dict_type: Option<String>,  //字典类型
     pub status: Option<i8>,         //状态（0：停用，1:正常）
+    pub keyword: Option<String>,    //关键字(模糊搜索)
+    pub begin_time: Option<String>, //创建时间(开始)
+    pub end_time: Option<String>,   //创建时间(结束)
+    pub sort_field: Option<String>, //排序字段
+    pub sort_order: Option<String>, //排序方式(asc:升序,desc:降序)
 }
 
 /*
 查询字典数据表列表响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DictDataListDataResp {
     pub id: i64,             //字典编码
@@ -140,3 +168,17 @@ impl DictDataListDataResp {
         Vec::new()
     }
 }
+
+/*
+根据字典类型查询字典数据响应参数(只返回已启用的数据)
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DictDataByTypeResp {
+    pub dict_sort: i32,     //字典排序
+    pub dict_label: String, //字典标签
+    pub dict_value: String, //字典键值
+    pub css_class: String,  //样式属性（其他样式扩展）
+    pub list_class: String, //表格回显样式
+    pub is_default: String, //是否默认（Y是 N否）
+}
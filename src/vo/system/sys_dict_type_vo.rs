@@ -1,24 +1,31 @@
 // author：刘飞华
 // createTime：2024/12/25 10:01:11
 
+use rocket::fs::TempFile;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
 
 /*
 添加字典类型表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct AddDictTypeReq {
-    pub dict_name: String,      //字典名称
-    pub dict_type: String,      //字典类型
-    pub status: i8,             //状态（0：停用，1:正常）
+    #[validate(length(min = 1, max = 100))]
+    pub dict_name: String, //字典名称
+    #[validate(length(min = 1, max = 100))]
+    pub dict_type: String, //字典类型
+    #[validate(range(min = 0, max = 1))]
+    pub status: i8, //状态（0：停用，1:正常）
+    #[validate(length(max = 500))]
     pub remark: Option<String>, //备注
 }
 
 /*
 删除字典类型表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct DeleteDictTypeReq {
     pub ids: Vec<i64>,
 }
@@ -26,29 +33,35 @@ pub struct DeleteDictTypeReq {
 /*
 更新字典类型表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateDictTypeReq {
-    pub id: i64,                //字典主键
-    pub dict_name: String,      //字典名称
-    pub dict_type: String,      //字典类型
-    pub status: i8,             //状态（0：停用，1:正常）
+    pub id: i64, //字典主键
+    #[validate(length(min = 1, max = 100))]
+    pub dict_name: String, //字典名称
+    #[validate(length(min = 1, max = 100))]
+    pub dict_type: String, //字典类型
+    #[validate(range(min = 0, max = 1))]
+    pub status: i8, //状态（0：停用，1:正常）
+    #[validate(length(max = 500))]
     pub remark: Option<String>, //备注
 }
 
 /*
 更新字典类型表状态请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 pub struct UpdateDictTypeStatusReq {
+    #[validate(length(min = 1))]
     pub ids: Vec<i64>,
+    #[validate(range(min = 0, max = 1))]
     pub status: i8,
 }
 
 /*
 查询字典类型表详情请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct QueryDictTypeDetailReq {
     pub id: i64,
 }
@@ -56,7 +69,7 @@ pub struct QueryDictTypeDetailReq {
 /*
 查询字典类型表详情响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryDictTypeDetailResp {
     pub id: i64,             //字典主键
@@ -85,20 +98,25 @@ impl QueryDictTypeDetailResp {
 /*
 查询字典类型表列表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryDictTypeListReq {
     pub page_no: u64,
     pub page_size: u64,
-    pub dict_name: Option<String>, //字典名称
-    pub dict_type: Option<String>, //字典类型
-    pub status: Option<i8>,        //状态（0：停用，1:正常）
+    pub dict_name: Option<String>,  //字典名称
+    pub dict_type: Option<String>,  //字典类型
+    pub status: Option<i8>,         //状态（0：停用，1:正常）
+    pub keyword: Option<String>,    //关键字(模糊搜索)
+    pub begin_time: Option<String>, //创建时间(开始)
+    pub end_time: Option<String>,   //创建时间(结束)
+    pub sort_field: Option<String>, //排序字段
+    pub sort_order: Option<String>, //排序方式(asc:升序,desc:降序)
 }
 
 /*
 查询字典类型表列表响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DictTypeListDataResp {
     pub id: i64,             //字典主键
@@ -114,3 +132,108 @@ impl DictTypeListDataResp {
         Vec::new()
     }
 }
+
+/*
+导出字典请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportDictTypeReq {
+    pub ids: Vec<i64>,          //字典主键(为空时导出全部)
+    pub format: Option<String>, //文件格式(json/yaml,默认json)
+}
+
+/*
+导入字典请求参数(multipart/form-data)
+*/
+#[derive(Debug, FromForm, ToSchema)]
+pub struct ImportDictTypeReq<'r> {
+    #[schema(value_type = String, format = Binary)]
+    pub file: TempFile<'r>, //导入文件(.json/.yaml/.yml)
+    pub strategy: Option<String>, //冲突策略(skip：跳过已存在,merge：合并,overwrite：覆盖,默认skip)
+    #[field(name = "dryRun")]
+    #[schema(rename = "dryRun")]
+    pub dry_run: Option<bool>, //只预览差异,不写入数据库
+}
+
+/*
+字典导入导出文件
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DictBundle {
+    pub version: i32,                    //文件版本
+    pub dict_types: Vec<DictTypeBundle>, //字典类型
+}
+
+/*
+字典导入导出文件-字典类型
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DictTypeBundle {
+    pub dict_name: String, //字典名称
+    pub dict_type: String, //字典类型
+    pub status: i8,        //状态（0：停用，1:正常）
+    #[serde(default)]
+    pub remark: String, //备注
+    #[serde(default)]
+    pub dict_data: Vec<DictDataBundle>, //字典数据
+}
+
+/*
+字典导入导出文件-字典数据
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DictDataBundle {
+    pub dict_sort: i32,     //字典排序
+    pub dict_label: String, //字典标签
+    pub dict_value: String, //字典键值
+    #[serde(default)]
+    pub css_class: String, //样式属性（其他样式扩展）
+    #[serde(default)]
+    pub list_class: String, //表格回显样式
+    pub is_default: String, //是否默认（Y是 N否）
+    pub status: i8,         //状态（0：停用，1:正常）
+    #[serde(default)]
+    pub remark: String, //备注
+}
+
+/*
+导入字典响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportDictTypeResp {
+    pub dry_run: bool,                     //是否只预览
+    pub strategy: String,                  //冲突策略
+    pub created: u64,                      //新增条数
+    pub updated: u64,                      //更新条数
+    pub deleted: u64,                      //删除条数
+    pub skipped: u64,                      //跳过条数(含冲突)
+    pub dict_types: Vec<DictTypeDiffResp>, //差异明细
+}
+
+/*
+导入字典差异-字典类型
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DictTypeDiffResp {
+    pub dict_type: String,                //字典类型
+    pub dict_name: String,                //字典名称
+    pub action: String,                   //操作(create/update/skip/unchanged)
+    pub dict_data: Vec<DictDataDiffResp>, //字典数据差异
+}
+
+/*
+导入字典差异-字典数据
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DictDataDiffResp {
+    pub dict_label: String, //字典标签
+    pub dict_value: String, //字典键值
+    pub action: String,     //操作(create/update/delete/skip/unchanged/conflict)
+}
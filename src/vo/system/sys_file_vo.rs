@@ -0,0 +1,67 @@
+// author：刘飞华
+// createTime：2026/10/19 18:26:40
+
+use rocket::fs::TempFile;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/*
+上传文件请求参数(multipart/form-data)
+*/
+#[derive(Debug, FromForm, ToSchema)]
+pub struct UploadFileReq<'r> {
+    #[schema(value_type = String, format = Binary)]
+    pub file: TempFile<'r>, //文件
+}
+
+/*
+上传文件响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadFileResp {
+    pub id: i64,              //文件ID
+    pub file_name: String,    //原始文件名
+    pub file_url: String,     //下载地址
+    pub file_size: i64,       //文件大小(字节)
+    pub content_type: String, //文件类型
+}
+
+/*
+删除文件请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DeleteFileReq {
+    pub ids: Vec<i64>,
+}
+
+/*
+查询文件列表请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryFileListReq {
+    pub page_no: u64,
+    pub page_size: u64,
+    pub file_name: Option<String>,    //原始文件名
+    pub file_ext: Option<String>,     //文件扩展名
+    pub storage_type: Option<String>, //存储类型(local:本地磁盘,s3:对象存储)
+}
+
+/*
+查询文件列表响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FileListDataResp {
+    pub id: i64,              //文件ID
+    pub file_name: String,    //原始文件名
+    pub file_url: String,     //下载地址
+    pub file_ext: String,     //文件扩展名
+    pub file_size: i64,       //文件大小(字节)
+    pub content_type: String, //文件类型
+    pub file_hash: String,    //文件内容sha256
+    pub storage_type: String, //存储类型(local:本地磁盘,s3:对象存储)
+    pub create_by: String,    //上传者
+    pub create_time: String,  //上传时间
+}
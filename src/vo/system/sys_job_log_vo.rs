@@ -0,0 +1,76 @@
+// author：刘飞华
+// createTime：2026/10/19 20:02:31
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/*
+删除定时任务执行日志表请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DeleteJobLogReq {
+    pub ids: Vec<i64>,
+}
+
+/*
+查询定时任务执行日志表详情请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct QueryJobLogDetailReq {
+    pub id: i64,
+}
+
+/*
+查询定时任务执行日志表详情响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryJobLogDetailResp {
+    pub id: i64,                //任务日志ID
+    pub job_id: i64,            //任务ID
+    pub job_name: String,       //任务名称
+    pub job_group: String,      //任务组名
+    pub invoke_target: String,  //任务处理器名称
+    pub job_params: String,     //任务参数
+    pub trigger_type: i8,       //触发方式（0：定时,1:手动）
+    pub status: i8,             //执行状态（0：失败,1:成功,2:跳过）
+    pub job_message: String,    //执行结果
+    pub exception_info: String, //错误信息
+    pub start_time: String,     //开始时间
+    pub end_time: String,       //结束时间
+    pub duration: i64,          //耗时(毫秒)
+}
+
+/*
+查询定时任务执行日志表列表请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryJobLogListReq {
+    pub page_no: u64,
+    pub page_size: u64,
+    pub job_id: Option<i64>,       //任务ID
+    pub job_name: Option<String>,  //任务名称
+    pub job_group: Option<String>, //任务组名
+    pub status: Option<i8>,        //执行状态（0：失败,1:成功,2:跳过）
+}
+
+/*
+查询定时任务执行日志表列表响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JobLogListDataResp {
+    pub id: i64,               //任务日志ID
+    pub job_id: i64,           //任务ID
+    pub job_name: String,      //任务名称
+    pub job_group: String,     //任务组名
+    pub invoke_target: String, //任务处理器名称
+    pub job_params: String,    //任务参数
+    pub trigger_type: i8,      //触发方式（0：定时,1:手动）
+    pub status: i8,            //执行状态（0：失败,1:成功,2:跳过）
+    pub job_message: String,   //执行结果
+    pub start_time: String,    //开始时间
+    pub end_time: String,      //结束时间
+    pub duration: i64,         //耗时(毫秒)
+}
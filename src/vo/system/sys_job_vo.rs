@@ -0,0 +1,127 @@
+// author：刘飞华
+// createTime：2026/10/19 20:02:31
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/*
+添加定时任务表请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AddJobReq {
+    pub job_name: String,           //任务名称
+    pub job_group: Option<String>,  //任务组名
+    pub invoke_target: String,      //任务处理器名称
+    pub job_params: Option<String>, //任务参数
+    pub cron_expression: String,    //cron表达式
+    pub concurrent: i8,             //是否允许并发执行（0：禁止,1:允许）
+    pub status: i8,                 //任务状态（0：暂停，1:正常）
+    pub remark: Option<String>,     //备注
+}
+
+/*
+删除定时任务表请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DeleteJobReq {
+    pub ids: Vec<i64>,
+}
+
+/*
+更新定时任务表请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateJobReq {
+    pub id: i64,                    //任务ID
+    pub job_name: String,           //任务名称
+    pub job_group: Option<String>,  //任务组名
+    pub invoke_target: String,      //任务处理器名称
+    pub job_params: Option<String>, //任务参数
+    pub cron_expression: String,    //cron表达式
+    pub concurrent: i8,             //是否允许并发执行（0：禁止,1:允许）
+    pub status: i8,                 //任务状态（0：暂停，1:正常）
+    pub remark: Option<String>,     //备注
+}
+
+/*
+更新定时任务表状态(暂停/恢复)请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
+pub struct UpdateJobStatusReq {
+    #[validate(length(min = 1))]
+    pub ids: Vec<i64>,
+    #[validate(range(min = 0, max = 1))]
+    pub status: i8,
+}
+
+/*
+立即执行一次定时任务请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RunJobReq {
+    pub id: i64,
+}
+
+/*
+查询定时任务表详情请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct QueryJobDetailReq {
+    pub id: i64,
+}
+
+/*
+查询定时任务表详情响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryJobDetailResp {
+    pub id: i64,                 //任务ID
+    pub job_name: String,        //任务名称
+    pub job_group: String,       //任务组名
+    pub invoke_target: String,   //任务处理器名称
+    pub job_params: String,      //任务参数
+    pub cron_expression: String, //cron表达式
+    pub concurrent: i8,          //是否允许并发执行（0：禁止,1:允许）
+    pub status: i8,              //任务状态（0：暂停，1:正常）
+    pub remark: String,          //备注
+    pub next_valid_time: String, //下次执行时间
+    pub create_time: String,     //创建时间
+    pub update_time: String,     //修改时间
+}
+
+/*
+查询定时任务表列表请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryJobListReq {
+    pub page_no: u64,
+    pub page_size: u64,
+    pub job_name: Option<String>,  //任务名称
+    pub job_group: Option<String>, //任务组名
+    pub status: Option<i8>,        //任务状态（0：暂停，1:正常）
+}
+
+/*
+查询定时任务表列表响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JobListDataResp {
+    pub id: i64,                 //任务ID
+    pub job_name: String,        //任务名称
+    pub job_group: String,       //任务组名
+    pub invoke_target: String,   //任务处理器名称
+    pub job_params: String,      //任务参数
+    pub cron_expression: String, //cron表达式
+    pub concurrent: i8,          //是否允许并发执行（0：禁止,1:允许）
+    pub status: i8,              //任务状态（0：暂停，1:正常）
+    pub remark: String,          //备注
+    pub next_valid_time: String, //下次执行时间
+    pub create_time: String,     //创建时间
+    pub update_time: String,     //修改时间
+}
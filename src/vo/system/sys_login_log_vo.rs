@@ -2,11 +2,12 @@
 // createTime：2024/12/25 10:01:11
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /*
 删除系统访问记录请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct DeleteLoginLogReq {
     pub ids: Vec<i64>,
 }
@@ -14,7 +15,7 @@ pub struct DeleteLoginLogReq {
 /*
 查询系统访问记录详情请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct QueryLoginLogDetailReq {
     pub id: i64,
 }
@@ -22,7 +23,7 @@ pub struct QueryLoginLogDetailReq {
 /*
 查询系统访问记录详情响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryLoginLogDetailResp {
     pub id: i64,                //访问ID
@@ -67,23 +68,30 @@ impl QueryLoginLogDetailResp {
 /*
 查询系统访问记录列表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryLoginLogListReq {
     pub page_no: u64,
     pub page_size: u64,
+    pub cursor: Option<String>,         //分页游标(第一页传空字符串)
+    pub with_total: Option<bool>,       //游标分页时是否查询总数
     pub login_name: Option<String>,     //登录账号
     pub ipaddr: Option<String>,         //登录IP地址
     pub login_location: Option<String>, //登录地点
     pub browser: Option<String>,        //浏览器类型
     pub os: Option<String>,             //操作系统
     pub status: Option<i8>,             //登录状态(0:失败,1:成功)
+    pub statuses: Option<Vec<i8>>,      //登录状态(多选)
+    pub begin_time: Option<String>,     //开始时间
+    pub end_time: Option<String>,       //结束时间
+    pub sort_field: Option<String>,     //排序字段
+    pub sort_order: Option<String>,     //排序方式(asc:升序,desc:降序)
 }
 
 /*
 查询系统访问记录列表响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct LoginLogListDataResp {
     pub id: i64,                //访问ID
@@ -107,3 +115,66 @@ impl LoginLogListDataResp {
         Vec::new()
     }
 }
+
+/*
+查询登录统计请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryLoginLogStatisticsReq {
+    pub begin_time: Option<String>, //开始时间(默认最近7天)
+    pub end_time: Option<String>,   //结束时间(默认当前时间)
+    pub top_n: Option<u64>,         //排行数量(默认10)
+}
+
+/*
+查询登录统计响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryLoginLogStatisticsResp {
+    pub begin_time: String,                           //开始时间
+    pub end_time: String,                             //结束时间
+    pub total_count: i64,                             //登录总次数
+    pub success_count: i64,                           //登录成功次数
+    pub fail_count: i64,                              //登录失败次数
+    pub daily_list: Vec<LoginDailyResp>,              //每天登录次数
+    pub top_fail_account_list: Vec<LoginGroupResp>,   //登录失败最多的账号
+    pub top_fail_ip_list: Vec<LoginGroupResp>,        //登录失败最多的IP
+    pub browser_list: Vec<LoginGroupResp>,            //浏览器分布
+    pub os_list: Vec<LoginGroupResp>,                 //操作系统分布
+    pub new_location_list: Vec<LoginNewLocationResp>, //异地登录
+}
+
+/*
+每天登录次数响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginDailyResp {
+    pub login_date: String, //登录日期
+    pub success_count: i64, //登录成功次数
+    pub fail_count: i64,    //登录失败次数
+}
+
+/*
+分组登录次数响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginGroupResp {
+    pub name: String, //分组名称
+    pub count: i64,   //登录次数
+}
+
+/*
+异地登录响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginNewLocationResp {
+    pub login_name: String,       //登录账号
+    pub login_location: String,   //登录地点
+    pub ipaddr: String,           //登录IP地址
+    pub first_login_time: String, //首次登录时间
+}
@@ -2,29 +2,40 @@
 // createTime：2024/12/12 14:41:44
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
 
 /*
 添加菜单信息请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct AddMenuReq {
-    pub menu_name: String,         //菜单名称
-    pub menu_type: i8,             //菜单类型(1：目录   2：菜单   3：按钮)
-    pub visible: i8,               //菜单状态（0:隐藏, 显示:1）
-    pub status: i8,                //状态(1:正常，0:禁用)
-    pub sort: i32,                 //排序
-    pub parent_id: Option<i64>,    //父ID
-    pub menu_url: Option<String>,  //路由路径
-    pub api_url: Option<String>,   //接口URL
+    #[validate(length(min = 1, max = 50))]
+    pub menu_name: String, //菜单名称
+    #[validate(range(min = 1, max = 3))]
+    pub menu_type: i8, //菜单类型(1：目录   2：菜单   3：按钮)
+    #[validate(range(min = 0, max = 1))]
+    pub visible: i8, //菜单状态（0:隐藏, 显示:1）
+    #[validate(range(min = 0, max = 1))]
+    pub status: i8, //状态(1:正常，0:禁用)
+    #[validate(range(min = 0))]
+    pub sort: i32, //排序
+    pub parent_id: Option<i64>, //父ID
+    #[validate(length(max = 255))]
+    pub menu_url: Option<String>, //路由路径
+    #[validate(length(max = 255))]
+    pub api_url: Option<String>, //接口URL
+    #[validate(length(max = 255))]
     pub menu_icon: Option<String>, //菜单图标
-    pub remark: Option<String>,    //备注
+    #[validate(length(max = 255))]
+    pub remark: Option<String>, //备注
 }
 
 /*
 删除菜单信息请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct DeleteMenuReq {
     pub ids: Vec<i64>,
 }
@@ -32,35 +43,46 @@ pub struct DeleteMenuReq {
 /*
 更新菜单信息请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateMenuReq {
-    pub id: i64,                   //主键
-    pub menu_name: String,         //菜单名称
-    pub menu_type: i8,             //菜单类型(1：目录   2：菜单   3：按钮)
-    pub visible: i8,               //菜单状态（0:隐藏, 显示:1）
-    pub status: i8,                //状态(1:正常，0:禁用)
-    pub sort: i32,                 //排序
-    pub parent_id: i64,            //父ID
-    pub menu_url: Option<String>,  //路由路径
-    pub api_url: Option<String>,   //接口URL
+    pub id: i64, //主键
+    #[validate(length(min = 1, max = 50))]
+    pub menu_name: String, //菜单名称
+    #[validate(range(min = 1, max = 3))]
+    pub menu_type: i8, //菜单类型(1：目录   2：菜单   3：按钮)
+    #[validate(range(min = 0, max = 1))]
+    pub visible: i8, //菜单状态（0:隐藏, 显示:1）
+    #[validate(range(min = 0, max = 1))]
+    pub status: i8, //状态(1:正常，0:禁用)
+    #[validate(range(min = 0))]
+    pub sort: i32, //排序
+    pub parent_id: i64, //父ID
+    #[validate(length(max = 255))]
+    pub menu_url: Option<String>, //路由路径
+    #[validate(length(max = 255))]
+    pub api_url: Option<String>, //接口URL
+    #[validate(length(max = 255))]
     pub menu_icon: Option<String>, //菜单图标
-    pub remark: Option<String>,    //备注
+    #[validate(length(max = 255))]
+    pub remark: Option<String>, //备注
 }
 
 /*
 更新菜单信息状态请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 pub struct UpdateMenuStatusReq {
+    #[validate(length(min = 1))]
     pub ids: Vec<i64>,
+    #[validate(range(min = 0, max = 1))]
     pub status: i8,
 }
 
 /*
 查询菜单信息详情请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct QueryMenuDetailReq {
     pub id: i64,
 }
@@ -68,7 +90,7 @@ pub struct QueryMenuDetailReq {
 /*
 查询菜单信息详情响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryMenuDetailResp {
     pub id: i64,             //主键
@@ -109,7 +131,7 @@ impl QueryMenuDetailResp {
 /*
 查询菜单信息列表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryMenuListReq {
     pub page_no: u64,
@@ -126,7 +148,7 @@ pub struct QueryMenuListReq {
 /*
 查询菜单信息列表响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct MenuListDataResp {
     pub id: i64,             //主键
@@ -153,7 +175,7 @@ impl MenuListDataResp {
 /*
 查询菜单信息列表响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct MenuListSimpleDataResp {
     pub id: i64,           //主键
@@ -2,24 +2,41 @@
 // createTime：2024/12/25 10:01:11
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /*
 添加通知公告表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AddNoticeReq {
-    pub notice_title: String,   //公告标题
-    pub notice_type: i8,        //公告类型（1:通知,2:公告）
-    pub notice_content: String, //公告内容
-    pub status: i8,             //公告状态（0:关闭,1:正常 ）
-    pub remark: Option<String>, //备注
+    pub notice_title: String,                          //公告标题
+    pub notice_type: i8,                               //公告类型（1:通知,2:公告）
+    pub notice_content: String,                        //公告内容
+    pub status: i8,                                    //公告状态（0:关闭,1:正常 ）
+    pub remark: Option<String>,                        //备注
+    pub target_type: Option<i8>, //通知对象类型（0:全部用户,1:部门,2:角色,3:用户）
+    pub target_ids: Option<Vec<i64>>, //通知对象ID(部门ID、角色ID或用户ID)
+    pub publish_at: Option<String>, //发布时间(为空时立即发布,晚于当前时间时定时发布)
+    pub expire_at: Option<String>, //过期时间(为空时不过期)
+    pub attachments: Option<Vec<NoticeAttachmentReq>>, //附件
+}
+
+/*
+通知公告附件请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NoticeAttachmentReq {
+    pub file_id: Option<i64>,      //文件ID(上传的文件,为空时使用外部链接)
+    pub file_name: Option<String>, //文件名称(为空时使用上传的文件名)
+    pub file_url: Option<String>,  //文件地址(外部链接)
 }
 
 /*
 删除通知公告表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct DeleteNoticeReq {
     pub ids: Vec<i64>,
 }
@@ -27,21 +44,26 @@ pub struct DeleteNoticeReq {
 /*
 更新通知公告表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateNoticeReq {
-    pub id: i64,                //公告ID
-    pub notice_title: String,   //公告标题
-    pub notice_type: i8,        //公告类型（1:通知,2:公告）
-    pub notice_content: String, //公告内容
-    pub status: i8,             //公告状态（0:关闭,1:正常 ）
-    pub remark: Option<String>, //备注
+    pub id: i64,                                       //公告ID
+    pub notice_title: String,                          //公告标题
+    pub notice_type: i8,                               //公告类型（1:通知,2:公告）
+    pub notice_content: String,                        //公告内容
+    pub status: i8,                                    //公告状态（0:关闭,1:正常 ）
+    pub remark: Option<String>,                        //备注
+    pub target_type: Option<i8>, //通知对象类型（0:全部用户,1:部门,2:角色,3:用户）
+    pub target_ids: Option<Vec<i64>>, //通知对象ID(部门ID、角色ID或用户ID)
+    pub publish_at: Option<String>, //发布时间(为空时立即发布,晚于当前时间时定时发布)
+    pub expire_at: Option<String>, //过期时间(为空时不过期)
+    pub attachments: Option<Vec<NoticeAttachmentReq>>, //附件
 }
 
 /*
 更新通知公告表状态请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UpdateNoticeStatusReq {
     pub ids: Vec<i64>,
     pub status: i8,
@@ -50,7 +72,7 @@ pub struct UpdateNoticeStatusReq {
 /*
 查询通知公告表详情请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct QueryNoticeDetailReq {
     pub id: i64,
 }
@@ -58,17 +80,22 @@ pub struct QueryNoticeDetailReq {
 /*
 查询通知公告表详情响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryNoticeDetailResp {
-    pub id: i64,                //公告ID
-    pub notice_title: String,   //公告标题
-    pub notice_type: i8,        //公告类型（1:通知,2:公告）
-    pub notice_content: String, //公告内容
-    pub status: i8,             //公告状态（0:关闭,1:正常 ）
-    pub remark: String,         //备注
-    pub create_time: String,    //创建时间
-    pub update_time: String,    //修改时间
+    pub id: i64,                                //公告ID
+    pub notice_title: String,                   //公告标题
+    pub notice_type: i8,                        //公告类型（1:通知,2:公告）
+    pub notice_content: String,                 //公告内容
+    pub status: i8,                             //公告状态（0:关闭,1:正常 ）
+    pub target_type: i8,                        //通知对象类型（0:全部用户,1:部门,2:角色,3:用户）
+    pub target_ids: Vec<i64>,                   //通知对象ID(部门ID、角色ID或用户ID)
+    pub publish_at: String,                     //发布时间
+    pub expire_at: String,                      //过期时间
+    pub attachments: Vec<NoticeAttachmentResp>, //附件
+    pub remark: String,                         //备注
+    pub create_time: String,                    //创建时间
+    pub update_time: String,                    //修改时间
 }
 
 impl QueryNoticeDetailResp {
@@ -79,6 +106,11 @@ impl QueryNoticeDetailResp {
             notice_type: 0,                 //公告类型（1:通知,2:公告）
             notice_content: "".to_string(), //公告内容
             status: 0,                      //公告状态（0:关闭,1:正常 ）
+            target_type: 0,                 //通知对象类型（0:全部用户,1:部门,2:角色,3:用户）
+            target_ids: Vec::new(),         //通知对象ID(部门ID、角色ID或用户ID)
+            publish_at: "".to_string(),     //发布时间
+            expire_at: "".to_string(),      //过期时间
+            attachments: Vec::new(),        //附件
             remark: "".to_string(),         //备注
             create_time: "".to_string(),    //创建时间
             update_time: "".to_string(),    //修改时间
@@ -86,10 +118,22 @@ impl QueryNoticeDetailResp {
     }
 }
 
+/*
+通知公告附件响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NoticeAttachmentResp {
+    pub id: i64,              //附件ID
+    pub file_id: Option<i64>, //文件ID
+    pub file_name: String,    //文件名称
+    pub file_url: String,     //文件地址(下载链接)
+}
+
 /*
 查询通知公告表列表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryNoticeListReq {
     pub page_no: u64,
@@ -97,12 +141,18 @@ pub struct QueryNoticeListReq {
     pub notice_title: Option<String>, //公告标题
     pub notice_type: Option<i8>,      //公告类型（1:通知,2:公告）
     pub status: Option<i8>,           //公告状态（0:关闭,1:正常 ）
+    pub publish_state: Option<i8>,    //发布状态（1:草稿,2:待发布,3:已发布,4:已过期）
+    pub keyword: Option<String>,      //关键字(模糊搜索)
+    pub begin_time: Option<String>,   //创建时间(开始)
+    pub end_time: Option<String>,     //创建时间(结束)
+    pub sort_field: Option<String>,   //排序字段
+    pub sort_order: Option<String>,   //排序方式(asc:升序,desc:降序)
 }
 
 /*
 查询通知公告表列表响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct NoticeListDataResp {
     pub id: i64,                //公告ID
@@ -110,6 +160,9 @@ pub struct NoticeListDataResp {
     pub notice_type: i8,        //公告类型（1:通知,2:公告）
     pub notice_content: String, //公告内容
     pub status: i8,             //公告状态（0:关闭,1:正常 ）
+    pub target_type: i8,        //通知对象类型（0:全部用户,1:部门,2:角色,3:用户）
+    pub publish_at: String,     //发布时间
+    pub expire_at: String,      //过期时间
     pub remark: String,         //备注
     pub create_time: String,    //创建时间
     pub update_time: String,    //修改时间
@@ -119,3 +172,58 @@ impl NoticeListDataResp {
         Vec::new()
     }
 }
+
+/*
+查询我的通知公告列表请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryMyNoticeListReq {
+    pub page_no: u64,
+    pub page_size: u64,
+    pub notice_type: Option<i8>, //公告类型（1:通知,2:公告）
+    pub read_flag: Option<i8>,   //阅读状态（0:未读,1:已读）
+}
+
+/*
+查询我的通知公告列表响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MyNoticeListDataResp {
+    pub id: i64,                //公告ID
+    pub notice_title: String,   //公告标题
+    pub notice_type: i8,        //公告类型（1:通知,2:公告）
+    pub notice_content: String, //公告内容
+    pub read_flag: i8,          //阅读状态（0:未读,1:已读）
+    pub delivery_time: String,  //送达时间
+    pub read_time: String,      //阅读时间
+}
+
+/*
+标记通知公告已读请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReadNoticeReq {
+    pub ids: Vec<i64>,
+}
+
+/*
+查询通知公告阅读统计请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct QueryNoticeReadStatisticsReq {
+    pub id: i64,
+}
+
+/*
+查询通知公告阅读统计响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryNoticeReadStatisticsResp {
+    pub id: i64,           //公告ID
+    pub total_count: i64,  //送达人数
+    pub read_count: i64,   //已读人数
+    pub unread_count: i64, //未读人数
+}
@@ -2,11 +2,12 @@
 // createTime：2024/12/25 10:01:11
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /*
 删除操作日志记录请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct DeleteOperateLogReq {
     pub ids: Vec<i64>,
 }
@@ -14,7 +15,7 @@ pub struct DeleteOperateLogReq {
 /*
 查询操作日志记录详情请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct QueryOperateLogDetailReq {
     pub id: i64,
 }
@@ -22,7 +23,7 @@ pub struct QueryOperateLogDetailReq {
 /*
 查询操作日志记录详情响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryOperateLogDetailResp {
     pub id: Option<i64>,                  //日志主键
@@ -71,11 +72,13 @@ impl QueryOperateLogDetailResp {
 /*
 查询操作日志记录列表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryOperateLogListReq {
     pub page_no: u64,
     pub page_size: u64,
+    pub cursor: Option<String>,           //分页游标(第一页传空字符串)
+    pub with_total: Option<bool>,         //游标分页时是否查询总数
     pub title: Option<String>,            //模块标题
     pub business_type: Option<i8>,        //业务类型（0其它 1新增 2修改 3删除）
     pub method: Option<String>,           //方法名称
@@ -87,12 +90,18 @@ pub struct QueryOperateLogListReq {
     pub operate_ip: Option<String>,       //主机地址
     pub operate_location: Option<String>, //操作地点
     pub status: Option<i8>,               //操作状态(0:异常,正常)
+    pub statuses: Option<Vec<i8>>,        //操作状态(多选)
+    pub business_types: Option<Vec<i8>>,  //业务类型(多选)
+    pub begin_time: Option<String>,       //开始时间
+    pub end_time: Option<String>,         //结束时间
+    pub sort_field: Option<String>,       //排序字段
+    pub sort_order: Option<String>,       //排序方式(asc:升序,desc:降序)
 }
 
 /*
 查询操作日志记录列表响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct OperateLogListDataResp {
     pub id: Option<i64>,                  //日志主键
@@ -2,24 +2,31 @@
 // createTime：2024/12/25 10:01:11
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
 
 /*
 添加岗位信息表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct AddPostReq {
-    pub post_code: String,      //岗位编码
-    pub post_name: String,      //岗位名称
-    pub sort: i32,              //显示顺序
-    pub status: i8,             //部状态（0：停用，1:正常）
+    #[validate(length(min = 1, max = 64))]
+    pub post_code: String, //岗位编码
+    #[validate(length(min = 1, max = 50))]
+    pub post_name: String, //岗位名称
+    #[validate(range(min = 0))]
+    pub sort: i32, //显示顺序
+    #[validate(range(min = 0, max = 1))]
+    pub status: i8, //部状态（0：停用，1:正常）
+    #[validate(length(max = 500))]
     pub remark: Option<String>, //备注
 }
 
 /*
 删除岗位信息表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct DeletePostReq {
     pub ids: Vec<i64>,
 }
@@ -27,30 +34,37 @@ pub struct DeletePostReq {
 /*
 更新岗位信息表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdatePostReq {
-    pub id: i64,                //岗位id
-    pub post_code: String,      //岗位编码
-    pub post_name: String,      //岗位名称
-    pub sort: i32,              //显示顺序
-    pub status: i8,             //部状态（0：停用，1:正常）
+    pub id: i64, //岗位id
+    #[validate(length(min = 1, max = 64))]
+    pub post_code: String, //岗位编码
+    #[validate(length(min = 1, max = 50))]
+    pub post_name: String, //岗位名称
+    #[validate(range(min = 0))]
+    pub sort: i32, //显示顺序
+    #[validate(range(min = 0, max = 1))]
+    pub status: i8, //部状态（0：停用，1:正常）
+    #[validate(length(max = 500))]
     pub remark: Option<String>, //备注
 }
 
 /*
 更新岗位信息表状态请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 pub struct UpdatePostStatusReq {
+    #[validate(length(min = 1))]
     pub ids: Vec<i64>,
+    #[validate(range(min = 0, max = 1))]
     pub status: i8,
 }
 
 /*
 查询岗位信息表详情请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct QueryPostDetailReq {
     pub id: i64,
 }
@@ -58,7 +72,7 @@ pub struct QueryPostDetailReq {
 /*
 查询岗位信息表详情响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryPostDetailResp {
     pub id: i64,             //岗位id
@@ -89,20 +103,25 @@ impl QueryPostDetailResp {
 /*
 查询岗位信息表列表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryPostListReq {
     pub page_no: u64,
     pub page_size: u64,
-    pub post_code: Option<String>, //岗位编码
-    pub post_name: Option<String>, //岗位名称
-    pub status: Option<i8>,        //部状态（0：停用，1:正常）
+    pub post_code: Option<String>,  //岗位编码
+    pub post_name: Option<String>,  //岗位名称
+    pub status: Option<i8>,         //部状态（0：停用，1:正常）
+    pub keyword: Option<String>,    //关键字(模糊搜索)
+    pub begin_time: Option<String>, //创建时间(开始)
+    pub end_time: Option<String>,   //创建时间(结束)
+    pub sort_field: Option<String>, //排序字段
+    pub sort_order: Option<String>, //排序方式(asc:升序,desc:降序)
 }
 
 /*
 查询岗位信息表列表响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PostListDataResp {
     pub id: i64,             //岗位id
@@ -2,24 +2,31 @@
 // createTime：2024/12/12 14:41:44
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
 
 /*
 添加角色信息请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct AddRoleReq {
-    pub role_name: String,      //名称
-    pub role_key: String,       //角色权限字符串
+    #[validate(length(min = 1, max = 50))]
+    pub role_name: String, //名称
+    #[validate(length(min = 1, max = 100))]
+    pub role_key: String, //角色权限字符串
+    #[validate(range(min = 1, max = 4))]
     pub data_scope: i8, //数据范围（1：全部数据权限 2：自定数据权限 3：本部门数据权限 4：本部门及以下数据权限）
-    pub status: i8,     //状态(1:正常，0:禁用)
+    #[validate(range(min = 0, max = 1))]
+    pub status: i8, //状态(1:正常，0:禁用)
+    #[validate(length(max = 255))]
     pub remark: Option<String>, //备注
 }
 
 /*
 删除角色信息请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct DeleteRoleReq {
     pub ids: Vec<i64>,
 }
@@ -27,30 +34,37 @@ pub struct DeleteRoleReq {
 /*
 更新角色信息请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateRoleReq {
-    pub id: i64,                //主键
-    pub role_name: String,      //名称
-    pub role_key: String,       //角色权限字符串
+    pub id: i64, //主键
+    #[validate(length(min = 1, max = 50))]
+    pub role_name: String, //名称
+    #[validate(length(min = 1, max = 100))]
+    pub role_key: String, //角色权限字符串
+    #[validate(range(min = 1, max = 4))]
     pub data_scope: i8, //数据范围（1：全部数据权限 2：自定数据权限 3：本部门数据权限 4：本部门及以下数据权限）
-    pub status: i8,     //状态(1:正常，0:禁用)
+    #[validate(range(min = 0, max = 1))]
+    pub status: i8, //状态(1:正常，0:禁用)
+    #[validate(length(max = 255))]
     pub remark: Option<String>, //备注
 }
 
 /*
 更新角色信息状态请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 pub struct UpdateRoleStatusReq {
+    #[validate(length(min = 1))]
     pub ids: Vec<i64>,
+    #[validate(range(min = 0, max = 1))]
     pub status: i8,
 }
 
 /*
 查询角色信息详情请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct QueryRoleDetailReq {
     pub id: i64,
 }
@@ -58,7 +72,7 @@ pub struct QueryRoleDetailReq {
 /*
 查询角色信息详情响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryRoleDetailResp {
     pub id: i64,              //主键
@@ -91,20 +105,25 @@ impl QueryRoleDetailResp {
 /*
 查询角色信息列表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryRoleListReq {
     pub page_no: u64,
     pub page_size: u64,
-    pub role_name: Option<String>, //名称
-    pub status_id: Option<i8>,     //状态(1:正常，0:禁用)
-    pub role_key: Option<String>,  //角色权限字符串
+    pub role_name: Option<String>,  //名称
+    pub status_id: Option<i8>,      //状态(1:正常，0:禁用)
+    pub role_key: Option<String>,   //角色权限字符串
+    pub keyword: Option<String>,    //关键字(模糊搜索)
+    pub begin_time: Option<String>, //创建时间(开始)
+    pub end_time: Option<String>,   //创建时间(结束)
+    pub sort_field: Option<String>, //排序字段
+    pub sort_order: Option<String>, //排序方式(asc:升序,desc:降序)
 }
 
 /*
 查询角色信息列表响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct RoleListDataResp {
     pub id: i64,              //主键
@@ -125,7 +144,7 @@ impl RoleListDataResp {
 /*
 查询角色菜单信息参数
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryRoleMenuReq {
     pub role_id: i64, //角色id
@@ -134,7 +153,7 @@ pub struct QueryRoleMenuReq {
 /*
 角色菜单信息参数
 */
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryRoleMenuData {
     pub menu_ids: Vec<i64>,           //菜单Ids
@@ -144,7 +163,7 @@ pub struct QueryRoleMenuData {
 /*
 菜单信息参数
 */
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct MenuDataList {
     pub id: i64,        //主键
@@ -159,7 +178,7 @@ pub struct MenuDataList {
 /*
 更新用户角色信息
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateRoleMenuReq {
     pub menu_ids: Vec<i64>,
@@ -169,11 +188,13 @@ pub struct UpdateRoleMenuReq {
 /*
 查询已分配用户角色列表
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AllocatedListReq {
     pub page_no: u64,
     pub page_size: u64,
+    pub cursor: Option<String>,   //分页游标(第一页传空字符串)
+    pub with_total: Option<bool>, //游标分页时是否查询总数
     pub role_id: i64,
     pub mobile: Option<String>,
     pub user_name: Option<String>,
@@ -182,11 +203,13 @@ pub struct AllocatedListReq {
 /*
 查询未分配用户角色列表
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UnallocatedListReq {
     pub page_no: u64,
     pub page_size: u64,
+    pub cursor: Option<String>,   //分页游标(第一页传空字符串)
+    pub with_total: Option<bool>, //游标分页时是否查询总数
     pub role_id: i64,
     pub mobile: Option<String>,
     pub user_name: Option<String>,
@@ -195,7 +218,7 @@ pub struct UnallocatedListReq {
 /*
 取消授权用户
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelAuthUserReq {
     pub user_id: i64,
@@ -205,7 +228,7 @@ pub struct CancelAuthUserReq {
 /*
 批量取消授权用户
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelAuthUserAllReq {
     pub user_ids: Vec<i64>,
@@ -215,7 +238,7 @@ pub struct CancelAuthUserAllReq {
 /*
 批量选择用户授权
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SelectAuthUserAllReq {
     pub user_ids: Vec<i64>,
@@ -1,30 +1,42 @@
 // author：刘飞华
 // createTime：2024/12/12 14:41:44
 
+use crate::utils::validate_util::{validate_email, validate_mobile};
 use crate::vo::system::sys_dept_vo::QueryDeptDetailResp;
+use rocket::fs::TempFile;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
 /*
 添加用户信息请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct AddUserReq {
-    pub mobile: String,         //手机
-    pub user_name: String,      //用户账号
-    pub nick_name: String,      //用户昵称
-    pub password: String,       //用户密码
-    pub email: String,          //用户邮箱
+    #[validate(custom(function = "validate_mobile"))]
+    pub mobile: String, //手机
+    #[validate(length(min = 1, max = 50))]
+    pub user_name: String, //用户账号
+    #[validate(length(min = 1, max = 30))]
+    pub nick_name: String, //用户昵称
+    #[validate(length(min = 6, max = 32))]
+    pub password: String, //用户密码
+    #[validate(custom(function = "validate_email"), length(max = 50))]
+    pub email: String, //用户邮箱
+    #[validate(length(max = 100))]
     pub avatar: Option<String>, //头像路径
-    pub status: i8,             //状态(1:正常，0:禁用)
-    pub dept_id: i64,           //部门ID
+    #[validate(range(min = 0, max = 1))]
+    pub status: i8, //状态(1:正常，0:禁用)
+    pub dept_id: i64, //部门ID
+    #[validate(length(max = 255))]
     pub remark: Option<String>, //备注
-    pub post_ids: Vec<i64>,     //岗位ids
+    pub post_ids: Vec<i64>, //岗位ids
 }
 
 /*
 删除用户信息请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct DeleteUserReq {
     pub ids: Vec<i64>,
 }
@@ -32,34 +44,43 @@ pub struct DeleteUserReq {
 /*
 更新用户信息请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateUserReq {
-    pub id: i64,                //主键
-    pub mobile: String,         //手机
-    pub user_name: String,      //用户账号
-    pub nick_name: String,      //用户昵称
-    pub email: String,          //用户邮箱
+    pub id: i64, //主键
+    #[validate(custom(function = "validate_mobile"))]
+    pub mobile: String, //手机
+    #[validate(length(min = 1, max = 50))]
+    pub user_name: String, //用户账号
+    #[validate(length(min = 1, max = 30))]
+    pub nick_name: String, //用户昵称
+    #[validate(custom(function = "validate_email"), length(max = 50))]
+    pub email: String, //用户邮箱
+    #[validate(length(max = 100))]
     pub avatar: Option<String>, //头像路径
-    pub status: i8,             //状态(1:正常，0:禁用)
-    pub dept_id: i64,           //部门ID
+    #[validate(range(min = 0, max = 1))]
+    pub status: i8, //状态(1:正常，0:禁用)
+    pub dept_id: i64, //部门ID
+    #[validate(length(max = 255))]
     pub remark: Option<String>, //备注
-    pub post_ids: Vec<i64>,     //岗位ids
+    pub post_ids: Vec<i64>, //岗位ids
 }
 
 /*
 更新用户信息状态请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 pub struct UpdateUserStatusReq {
+    #[validate(length(min = 1))]
     pub ids: Vec<i64>,
+    #[validate(range(min = 0, max = 1))]
     pub status: i8,
 }
 
 /*
 查询用户信息详情请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct QueryUserDetailReq {
     pub id: i64,
 }
@@ -67,7 +88,7 @@ pub struct QueryUserDetailReq {
 /*
 查询用户信息详情响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryUserDetailResp {
     pub id: i64,                        //主键
@@ -135,21 +156,26 @@ impl QueryUserDetailResp {
 /*
 查询用户信息列表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryUserListReq {
     pub page_no: u64,
     pub page_size: u64,
-    pub mobile: Option<String>,    //手机
-    pub user_name: Option<String>, //姓名
-    pub status: Option<i8>,        //状态(1:正常，0:禁用)
-    pub dept_id: Option<i64>,      //部门ID
+    pub mobile: Option<String>,     //手机
+    pub user_name: Option<String>,  //姓名
+    pub status: Option<i8>,         //状态(1:正常，0:禁用)
+    pub dept_id: Option<i64>,       //部门ID
+    pub keyword: Option<String>,    //关键字(模糊搜索)
+    pub begin_time: Option<String>, //创建时间(开始)
+    pub end_time: Option<String>,   //创建时间(结束)
+    pub sort_field: Option<String>, //排序字段
+    pub sort_order: Option<String>, //排序方式(asc:升序,desc:降序)
 }
 
 /*
 查询用户信息列表响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UserListDataResp {
     pub id: i64,                 //主键
@@ -179,7 +205,7 @@ impl UserListDataResp {
 /*
 登录请求参数
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct UserLoginReq {
     pub account: String,  //手机
     pub password: String, //密码
@@ -188,7 +214,7 @@ pub struct UserLoginReq {
 /*
 查询用户菜单响应参数
 */
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryUserMenuResp {
     pub sys_menu: Vec<MenuList>,
@@ -209,7 +235,7 @@ impl QueryUserMenuResp {
 /*
 用户菜单参数
 */
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Clone, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct MenuList {
     pub id: i64,
@@ -224,7 +250,7 @@ pub struct MenuList {
 /*
 查询用户关联角色请求参数
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryUserRoleReq {
     pub user_id: i64,
@@ -233,7 +259,7 @@ pub struct QueryUserRoleReq {
 /*
 用户关联角色响应参数
 */
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryUserRoleResp {
     pub sys_role_list: Vec<RoleList>,
@@ -243,7 +269,7 @@ pub struct QueryUserRoleResp {
 /*
 角色信息
 */
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct RoleList {
     pub id: i64,             //主键
@@ -260,7 +286,7 @@ pub struct RoleList {
 /*
 更新用户关联角色请求参数
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateUserRoleReq {
     pub user_id: i64,       //用户主键
@@ -270,18 +296,82 @@ pub struct UpdateUserRoleReq {
 /*
 重置密码
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 pub struct ResetUserPwdReq {
-    pub id: i64,          //用户主键
+    pub id: i64, //用户主键
+    #[validate(length(min = 6, max = 32))]
     pub password: String, //用户密码
 }
 
 /*
 重置密码
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateUserPwdReq {
-    pub pwd: String,    //用户密码
-    pub re_pwd: String, //用户密码
+    pub pwd: String, //原密码
+    #[validate(length(min = 6, max = 32))]
+    pub re_pwd: String, //新密码
+}
+
+/*
+上传我的头像请求参数(multipart/form-data)
+*/
+#[derive(Debug, FromForm, ToSchema)]
+pub struct UpdateMyAvatarReq<'r> {
+    #[schema(value_type = String, format = Binary)]
+    pub file: TempFile<'r>, //头像图片
+}
+
+/*
+上传我的头像响应参数
+*/
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateMyAvatarResp {
+    pub avatar: String,       //头像地址(256x256)
+    pub avatar_small: String, //小头像地址(64x64)
+}
+
+/*
+导入用户请求参数(multipart/form-data)
+*/
+#[derive(Debug, FromForm, ToSchema)]
+pub struct ImportUserReq<'r> {
+    #[schema(value_type = String, format = Binary)]
+    pub file: TempFile<'r>, //导入文件(.xlsx/.csv)
+    #[field(name = "updateSupport")]
+    #[schema(rename = "updateSupport")]
+    pub update_support: Option<bool>, //用户账号已存在时更新用户信息
+    #[field(name = "dryRun")]
+    #[schema(rename = "dryRun")]
+    pub dry_run: Option<bool>, //只校验,不写入数据库
+}
+
+/*
+导入用户响应参数
+*/
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportUserResp {
+    pub dry_run: bool,                    //是否只校验
+    pub total: u64,                       //数据行数
+    pub created: u64,                     //新增条数
+    pub updated: u64,                     //更新条数
+    pub failed: u64,                      //失败条数
+    pub errors: Vec<ImportUserErrorResp>, //错误明细
+    pub result_file: Option<String>,      //导入结果文件(base64,有错误时生成,密码列为空)
+    pub result_file_name: Option<String>, //导入结果文件名
+}
+
+/*
+导入用户错误明细
+*/
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportUserErrorResp {
+    pub row: u64,          //行号(和表格中的行号一致)
+    pub user_name: String, //用户账号
+    pub field: String,     //字段名称
+    pub message: String,   //错误信息
 }